@@ -0,0 +1,5 @@
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_utils::runner::main(&[advent_of_code_2018::day01::day()])
+}
@@ -5,8 +5,6 @@ use std::str::FromStr;
 
 #[allow(dead_code)]
 static TEST_INPUT: &str = include_str!("day-06.test");
-#[allow(dead_code)]
-static INPUT: &str = include_str!("day-06.input");
 
 const NEW: usize = usize::MAX;
 const TIE: usize = usize::MAX - 1;
@@ -35,7 +33,8 @@ fn print_map(map: &Array2<usize>) {
 }
 
 fn main() {
-    let points: Vec<_> = INPUT
+    let input = aoc_utils::input::from_command_line(2018, 6);
+    let points: Vec<_> = input
         .lines()
         .map(|line| {
             let coords: Vec<_> = line
@@ -0,0 +1,20 @@
+//! Draw the steps from 2018 day 7's input and their dependencies, in
+//! Graphviz's DOT language.
+//!
+//! The input is read as by `aoc_utils::input::from_command_line`. The drawing
+//! is written to the file named by the `AOC_DOT` environment variable, or to
+//! standard output if that isn't set:
+//!
+//! ```text
+//! cargo run --bin day-07-dot | dot -Tsvg > steps.svg
+//! ```
+
+fn main() -> anyhow::Result<()> {
+    let input = aoc_utils::input::from_command_line(2018, 7);
+    let dot = advent_of_code_2018::day07::draw_input(&input)?;
+    match aoc_utils::dot::requested() {
+        Some(path) => dot.save(&path)?,
+        None => print!("{dot}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,21 @@
+//! Draw a space-time diagram of the pots from 2018 day 12's input, up to the
+//! generation at which the pattern stops changing shape.
+//!
+//! The input is read as by `aoc_utils::input::from_command_line`. The diagram
+//! is printed as text, and also saved as an image to the file named by the
+//! `AOC_SPACETIME` environment variable, if that's set. A `.png` file needs
+//! the `render` feature; any other name gets the text:
+//!
+//! ```text
+//! AOC_SPACETIME=pots.png cargo run --features render --bin day-12-spacetime
+//! ```
+
+fn main() -> anyhow::Result<()> {
+    let input = aoc_utils::input::from_command_line(2018, 12);
+    let history = advent_of_code_2018::day12::history(&input)?;
+    print!("{history}");
+    if let Some(path) = aoc_utils::spacetime::requested() {
+        history.save(&path)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,15 @@
+//! Watch the carts from 2018 day 13's input move around their tracks, in the
+//! terminal.
+//!
+//! The input is read as by `aoc_utils::input::from_command_line`; see
+//! `aoc_utils::tui` for the viewer's keys. This needs the `view` feature:
+//!
+//! ```text
+//! cargo run --features view --bin day-13-view
+//! ```
+
+fn main() -> anyhow::Result<()> {
+    let input = aoc_utils::input::from_command_line(2018, 13);
+    aoc_utils::tui::view(advent_of_code_2018::day13::tracks(&input)?)?;
+    Ok(())
+}
@@ -0,0 +1,17 @@
+//! Watch the elves and goblins from 2018 day 15's input fight, in the
+//! terminal.
+//!
+//! The input is read as by `aoc_utils::input::from_command_line`; see
+//! `aoc_utils::tui` for the viewer's keys. This needs the `view` feature:
+//!
+//! ```text
+//! cargo run --features view --bin day-15-view
+//! ```
+
+use std::str::FromStr;
+
+fn main() -> anyhow::Result<()> {
+    let input = aoc_utils::input::from_command_line(2018, 15);
+    aoc_utils::tui::view(advent_of_code_2018::day15::Map::from_str(&input)?)?;
+    Ok(())
+}
@@ -0,0 +1,30 @@
+//! Record the lumber collection area from 2018 day 18's input changing,
+//! until well after it settles into a cycle.
+//!
+//! The input is read as by `aoc_utils::input::from_command_line`. The
+//! animation is saved to the file named by the `AOC_ANIMATE` environment
+//! variable, as a GIF or an animated PNG depending on its extension. This
+//! needs the `animate` feature:
+//!
+//! ```text
+//! AOC_ANIMATE=lumber.gif cargo run --features animate --bin day-18-animate
+//! ```
+
+use anyhow::anyhow;
+use aoc_utils::animate::{self, Recorder};
+use aoc_utils::render::Rgb;
+
+fn main() -> anyhow::Result<()> {
+    let path = animate::requested()
+        .ok_or_else(|| anyhow!("set AOC_ANIMATE to the name of the file to write"))?;
+    let input = aoc_utils::input::from_command_line(2018, 18);
+    let mut recorder = Recorder::new(|&ch: &char| match ch {
+        '|' => Rgb([20, 140, 20]),
+        '#' => Rgb([120, 80, 30]),
+        _ => Rgb([0, 0, 0]),
+    })
+    .delay(50);
+    advent_of_code_2018::day18::watch(&input, 600, |map| recorder.frame(map))?;
+    recorder.save(&path)?;
+    Ok(())
+}
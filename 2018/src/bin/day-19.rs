@@ -1,30 +1,5 @@
-use aoc_utils::machine::{assemble, State, Word};
+use std::process::ExitCode;
 
-mod input {
-    use aoc_utils::machine::AssemblyInsn;
-    include!("day-19-input.rsi");
-}
-
-fn main() {
-    let machine_code = assemble(input::PROGRAM);
-
-    let mut ip = 0;
-    let mut state = State::default();
-    state.0[0] = 1;
-    loop {
-        state.0[input::IP] = ip as Word;
-        let insn = &machine_code[ip];
-        println!("State: {state}    insn: {insn}");
-        insn.semantic.1.step(insn, &mut state);
-        if state.0[input::IP] < 0 {
-            break;
-        }
-        ip = state.0[input::IP] as usize;
-        ip += 1;
-        if machine_code.len() <= ip {
-            break;
-        }
-    }
-
-    println!("Final state: {state:?}");
+fn main() -> ExitCode {
+    aoc_utils::runner::main(&[advent_of_code_2018::day19::day()])
 }
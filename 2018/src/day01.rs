@@ -0,0 +1,52 @@
+use aoc_utils::solution::{Day, Solution};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<isize>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<isize>> {
+        Ok(input
+            .split_terminator(&[',', '\n'][..])
+            .map(|line| isize::from_str(line.trim()))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(changes: &Vec<isize>) -> isize {
+        changes.iter().sum()
+    }
+
+    fn part2(changes: &Vec<isize>) -> isize {
+        let frequencies = changes.iter().cycle().scan(0, |s, d| {
+            *s += d;
+            Some(*s)
+        });
+
+        let mut seen = HashSet::new();
+        seen.insert(0);
+        frequencies
+            .take(100_000_000)
+            .find(|&frequency| !seen.insert(frequency))
+            .expect("never found a repeating value")
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day01>(2018, 1)
+}
+
+#[test]
+fn test_day01() {
+    let changes = Day01::parse("+1, -2, +3, +1").unwrap();
+    assert_eq!(Day01::part1(&changes), 3);
+    assert_eq!(Day01::part2(&changes), 2);
+    assert_eq!(Day01::part2(&Day01::parse("+1, -1").unwrap()), 0);
+    assert_eq!(
+        Day01::part2(&Day01::parse("+3, +3, +4, -2, -4").unwrap()),
+        10
+    );
+}
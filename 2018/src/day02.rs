@@ -0,0 +1,80 @@
+use aoc_utils::solution::{Day, Solution};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+fn hist<T, I>(iter: I) -> HashMap<T, usize>
+where
+    I: IntoIterator<Item = T>,
+    T: Hash + Eq,
+{
+    let mut hist = HashMap::new();
+    for elt in iter {
+        *hist.entry(elt).or_insert(0) += 1;
+    }
+    hist
+}
+
+fn diffs(a: &str, b: &str) -> usize {
+    assert_eq!(a.len(), b.len());
+    a.chars()
+        .zip(b.chars())
+        .map(|(a, b)| if a == b { 0 } else { 1 })
+        .sum()
+}
+
+struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<String>> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(ids: &Vec<String>) -> usize {
+        let mut twos = 0;
+        let mut threes = 0;
+        for id in ids {
+            let h = hist(hist(id.chars()).values().cloned());
+            if h.contains_key(&2) {
+                twos += 1;
+            }
+            if h.contains_key(&3) {
+                threes += 1;
+            }
+        }
+        twos * threes
+    }
+
+    /// Return the letters common to the two ids that differ in only one
+    /// position.
+    fn part2(ids: &Vec<String>) -> String {
+        for i in 0..ids.len() {
+            for j in (i + 1)..ids.len() {
+                if diffs(&ids[i], &ids[j]) == 1 {
+                    return ids[i]
+                        .chars()
+                        .zip(ids[j].chars())
+                        .filter(|(a, b)| a == b)
+                        .map(|(a, _)| a)
+                        .collect();
+                }
+            }
+        }
+        panic!("no two ids differ in exactly one position");
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day02>(2018, 2)
+}
+
+#[test]
+fn test_day02() {
+    let ids = Day02::parse(include_str!("bin/day-02a.test")).unwrap();
+    assert_eq!(Day02::part1(&ids), 12);
+    let ids = Day02::parse("abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz\n").unwrap();
+    assert_eq!(Day02::part2(&ids), "fgij");
+}
@@ -0,0 +1,86 @@
+use aoc_utils::solution::{Day, Solution};
+use aoc_utils::AocParse;
+use std::str::FromStr;
+
+#[derive(AocParse, Debug)]
+#[aoc(template = "#{id} @ {left},{top}: {width}x{height}")]
+struct Claim {
+    id: usize,
+    top: usize,
+    left: usize,
+    width: usize,
+    height: usize,
+}
+
+impl Claim {
+    fn right(&self) -> usize {
+        self.left + self.width
+    }
+    fn bottom(&self) -> usize {
+        self.top + self.height
+    }
+
+    /// Return the indices of this claim's square inches in a fabric `width`
+    /// inches wide.
+    fn squares(&self, width: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.height).flat_map(move |r| {
+            (0..self.width).map(move |c| (self.top + r) * width + (self.left + c))
+        })
+    }
+}
+
+/// The claims, and how many claims cover each square inch of the fabric.
+struct Fabric {
+    claims: Vec<Claim>,
+    width: usize,
+    used: Vec<usize>,
+}
+
+struct Day03;
+
+impl Solution for Day03 {
+    type Input = Fabric;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Fabric> {
+        let claims: Vec<_> = input
+            .lines()
+            .map(Claim::from_str)
+            .collect::<Result<_, _>>()?;
+
+        let width = claims.iter().map(|c| c.right()).max().unwrap_or(0);
+        let height = claims.iter().map(|c| c.bottom()).max().unwrap_or(0);
+        let mut used = vec![0; width * height];
+        for claim in &claims {
+            for ix in claim.squares(width) {
+                used[ix] += 1;
+            }
+        }
+
+        Ok(Fabric {
+            claims,
+            width,
+            used,
+        })
+    }
+
+    /// Return the number of square inches covered by more than one claim.
+    fn part1(fabric: &Fabric) -> usize {
+        fabric.used.iter().filter(|c| **c > 1).count()
+    }
+
+    /// Return the id of the one claim that overlaps no other.
+    fn part2(fabric: &Fabric) -> usize {
+        fabric
+            .claims
+            .iter()
+            .find(|claim| claim.squares(fabric.width).all(|ix| fabric.used[ix] == 1))
+            .expect("every claim overlaps another")
+            .id
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day03>(2018, 3)
+}
@@ -0,0 +1,74 @@
+use aoc_utils::solution::{Day, Solution};
+
+fn reacts(left: char, right: char) -> bool {
+    left.is_ascii_alphabetic()
+        && right.is_ascii_alphabetic()
+        && (left as i32 - right as i32).abs() == 32
+}
+
+fn reduce(input: &str) -> String {
+    let input: Vec<_> = input.chars().collect();
+    let mut output = Vec::new();
+
+    for ch in input {
+        if !output.is_empty() && reacts(output[output.len() - 1], ch) {
+            output.pop();
+        } else {
+            output.push(ch);
+        }
+    }
+
+    output.iter().collect::<String>()
+}
+
+#[test]
+fn test_reduce() {
+    assert_eq!(&reduce("dabAcCaCBAcCcaDA"), "dabCBAcaDA");
+    assert_eq!(&reduce("aA"), "");
+    assert_eq!(&reduce("abBA"), "");
+    assert_eq!(&reduce("abAB"), "abAB");
+    assert_eq!(&reduce("aabAAB"), "aabAAB");
+}
+
+struct Day05;
+
+impl Solution for Day05 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<String> {
+        Ok(input.trim().to_owned())
+    }
+
+    fn part1(polymer: &String) -> usize {
+        reduce(polymer).len()
+    }
+
+    /// Return the length of the shortest polymer we can produce by removing
+    /// all units of a single type before reacting.
+    fn part2(polymer: &String) -> usize {
+        (b'a'..=b'z')
+            .map(|unit| {
+                let unit = unit as char;
+                let purified: String = polymer
+                    .chars()
+                    .filter(|c| c.to_ascii_lowercase() != unit)
+                    .collect();
+                reduce(&purified).len()
+            })
+            .min()
+            .unwrap()
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day05>(2018, 5)
+}
+
+#[test]
+fn test_day05() {
+    let polymer = Day05::parse("dabAcCaCBAcCcaDA\n").unwrap();
+    assert_eq!(Day05::part1(&polymer), 10);
+    assert_eq!(Day05::part2(&polymer), 4);
+}
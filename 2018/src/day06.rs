@@ -0,0 +1,115 @@
+use aoc_utils::ndarray;
+use aoc_utils::solution::{Day, Solution};
+use aoc_utils::{cartesian_product, edge_indexes2, IteratorExt};
+use ndarray::{azip, Array2, Axis};
+use std::str::FromStr;
+
+const TIE: usize = usize::MAX;
+
+fn manhattan(a: &(usize, usize), b: &(usize, usize)) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+#[allow(dead_code)]
+fn print_map(map: &Array2<usize>) {
+    for r in 0..map.len_of(Axis(0)) {
+        for c in 0..map.len_of(Axis(1)) {
+            print!("{}", {
+                let point = map[[r, c]];
+                if point == TIE {
+                    '.'
+                } else {
+                    (b'a' + (point as u8) % 26) as char
+                }
+            });
+        }
+        println!();
+    }
+}
+
+/// The coordinates, as `(row, column)` pairs, and the size of the grid that
+/// holds them all.
+struct Coordinates {
+    points: Vec<(usize, usize)>,
+    height: usize,
+    width: usize,
+}
+
+struct Day06;
+
+impl Solution for Day06 {
+    type Input = Coordinates;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Coordinates> {
+        let points: Vec<_> = input
+            .lines()
+            .map(|line| {
+                let (x, y) = line
+                    .split_once(',')
+                    .ok_or_else(|| anyhow::anyhow!("bad coordinates: {:?}", line))?;
+                Ok((usize::from_str(y.trim())?, usize::from_str(x.trim())?))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        let height = points.iter().map(|(r, _c)| *r).max().unwrap_or(0) + 1;
+        let width = points.iter().map(|(_r, c)| *c).max().unwrap_or(0) + 1;
+        Ok(Coordinates {
+            points,
+            height,
+            width,
+        })
+    }
+
+    /// Return the size of the largest finite area closest to one coordinate.
+    fn part1(coords: &Coordinates) -> usize {
+        let points = &coords.points;
+        let map = Array2::from_shape_fn((coords.height, coords.width), |m| {
+            points
+                .iter()
+                .enumerate()
+                .unique_min_by_key(|(_i, p)| manhattan(&m, p))
+                .map(|(i, _p)| i)
+                .unwrap_or(TIE)
+        });
+
+        let mut infinite = vec![false; points.len()];
+        for e in edge_indexes2(&map) {
+            if map[e] != TIE {
+                infinite[map[e]] = true;
+            }
+        }
+
+        let mut areas = vec![0; points.len()];
+        azip!((&owner in &map) {
+            if owner != TIE && !infinite[owner] {
+                areas[owner] += 1;
+            }
+        });
+
+        areas.into_iter().max().unwrap_or(0)
+    }
+
+    /// Return the number of grid points whose total distance to all the
+    /// coordinates is less than 10000.
+    fn part2(coords: &Coordinates) -> usize {
+        close_area(coords, 10000)
+    }
+}
+
+fn close_area(coords: &Coordinates, limit: usize) -> usize {
+    cartesian_product(0..coords.height, 0..coords.width)
+        .filter(|m| coords.points.iter().map(|p| manhattan(m, p)).sum::<usize>() < limit)
+        .count()
+}
+
+pub fn day() -> Day {
+    Day::new::<Day06>(2018, 6)
+}
+
+#[test]
+fn test_close_area() {
+    let coords = Day06::parse(include_str!("bin/day-06.test")).unwrap();
+    assert_eq!(close_area(&coords, 32), 16);
+}
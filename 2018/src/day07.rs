@@ -0,0 +1,214 @@
+use aoc_utils::dot::Dot;
+use aoc_utils::solution::{Day, Solution};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Eq, PartialEq)]
+struct Event<T> {
+    time: usize,
+    value: T,
+}
+
+#[derive(Default)]
+struct TimedQueue<T>(Vec<Event<T>>); // sorted by decreasing time
+
+impl<T> TimedQueue<T> {
+    fn push(&mut self, event: Event<T>) {
+        let insert_at = (0..self.0.len())
+            .find(|&i| self.0[i].time < event.time)
+            .unwrap_or(self.0.len());
+        self.0.insert(insert_at, event)
+    }
+
+    fn pop(&mut self) -> Option<Event<T>> {
+        self.0.pop()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[test]
+fn test_queue() {
+    let mut q = TimedQueue::default();
+    q.push(Event {
+        time: 10,
+        value: 'a',
+    });
+    q.push(Event {
+        time: 5,
+        value: 'b',
+    });
+    q.push(Event {
+        time: 15,
+        value: 'c',
+    });
+    q.push(Event {
+        time: 10,
+        value: 'd',
+    });
+
+    assert_eq!(
+        q.pop(),
+        Some(Event {
+            time: 5,
+            value: 'b'
+        })
+    );
+    assert_eq!(
+        q.pop(),
+        Some(Event {
+            time: 10,
+            value: 'd'
+        })
+    );
+    assert_eq!(
+        q.pop(),
+        Some(Event {
+            time: 10,
+            value: 'a'
+        })
+    );
+    assert_eq!(
+        q.pop(),
+        Some(Event {
+            time: 15,
+            value: 'c'
+        })
+    );
+}
+
+/// Parse the puzzle input as a list of `(pre, post)` pairs, each meaning that
+/// step `pre` must be finished before step `post` can begin.
+fn parse_deps(input: &str) -> anyhow::Result<Vec<(char, char)>> {
+    input
+        .lines()
+        .map(|line| {
+            let (pre, post) = aoc_utils::scan!(
+                line,
+                "Step _ must be finished before step _ can begin.",
+                char,
+                char
+            )?;
+            Ok((pre, post))
+        })
+        .collect()
+}
+
+/// Draw the dependencies as a graph, with arrows pointing from each step to
+/// the steps that must wait for it.
+fn draw(deps: &[(char, char)]) -> Dot<char> {
+    let mut dot = Dot::directed();
+    dot.edges(deps.iter().copied());
+    for &(pre, post) in deps {
+        dot.node(pre).label(pre);
+        dot.node(post).label(post);
+    }
+    dot
+}
+
+/// Parse `input` and draw its dependencies.
+///
+/// The `day-07-dot` binary uses this to save the drawing.
+pub fn draw_input(input: &str) -> anyhow::Result<Dot<char>> {
+    Ok(draw(&parse_deps(input)?))
+}
+
+/// Carry out the steps with `total_workers` workers, where each step takes
+/// `base_time` seconds plus its position in the alphabet.
+///
+/// Return the order in which the steps were finished, and the time at which
+/// the last one was done.
+fn schedule(deps: &[(char, char)], total_workers: usize, base_time: usize) -> (String, usize) {
+    let task_time = |task: char| base_time + 1 + (task as usize - 'A' as usize);
+
+    let mut posts: HashMap<char, Vec<char>> = HashMap::new();
+    let mut blockers: BTreeMap<char, usize> = BTreeMap::new();
+    for (pre, post) in deps {
+        posts.entry(*post).or_default();
+        posts.entry(*pre).or_default().push(*post);
+        blockers.entry(*pre).or_insert(0);
+        *blockers.entry(*post).or_insert(0) += 1;
+    }
+
+    let mut sequence = String::new();
+    let mut now = 0;
+    let mut busy_workers = 0;
+    let mut pending = TimedQueue::default();
+    loop {
+        // There ought to be an entry in `pending` for each busy worker.
+        assert_eq!(busy_workers, pending.len());
+
+        // Start as many workers as we can.
+        while busy_workers < total_workers {
+            if let Some((&next, _)) = blockers.iter().find(|&(_i, &n)| n == 0) {
+                blockers.remove(&next);
+                busy_workers += 1;
+                pending.push(Event {
+                    time: now + task_time(next),
+                    value: next,
+                });
+            } else {
+                break;
+            }
+        }
+
+        // Still.
+        assert_eq!(busy_workers, pending.len());
+
+        // If no workers are busy, then all tasks must be complete.
+        if busy_workers == 0 {
+            break;
+        }
+
+        // We know we're running everything we can, so now we have to wait for
+        // something to be finished. Since some workers are busy, there must be
+        // something in the queue.
+        let finished = pending.pop().unwrap();
+        busy_workers -= 1;
+        now = finished.time;
+
+        // Record the task we just finished.
+        sequence.push(finished.value);
+
+        // Remove the completed task as a blocker for anything else.
+        for post in &posts[&finished.value] {
+            *blockers.get_mut(post).unwrap() -= 1;
+        }
+    }
+
+    assert!(blockers.is_empty(), "dependencies form a cycle");
+    (sequence, now)
+}
+
+struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<(char, char)>;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<(char, char)>> {
+        parse_deps(input)
+    }
+
+    /// Return the order in which a single worker would complete the steps.
+    fn part1(deps: &Vec<(char, char)>) -> String {
+        schedule(deps, 1, 0).0
+    }
+
+    /// Return how long five workers take to complete all the steps.
+    fn part2(deps: &Vec<(char, char)>) -> usize {
+        schedule(deps, 5, 60).1
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day07>(2018, 7)
+}
+
+#[test]
+fn test_schedule() {
+    let deps = parse_deps(include_str!("bin/day-07.test")).unwrap();
+    assert_eq!(schedule(&deps, 2, 0), ("CABFDE".to_string(), 15));
+}
@@ -0,0 +1,121 @@
+use aoc_utils::solution::{Day, Solution};
+use std::str::FromStr;
+
+trait Sink {
+    type Node: std::fmt::Debug;
+    fn new_node(&mut self) -> Self::Node;
+    fn add_child(&mut self, parent: &mut Self::Node, child: Self::Node);
+    fn add_metadata(&mut self, node: &mut Self::Node, metadata: usize);
+}
+
+/// Read a tree from `source`, building it with `sink`.
+fn build<S: Sink, I: Iterator<Item = usize>>(sink: &mut S, source: &mut I) -> S::Node {
+    let child_count = source.next().expect("expected child count");
+    let meta_count = source.next().expect("expected metadata count");
+
+    let mut node = sink.new_node();
+
+    for _ in 0..child_count {
+        let child = build(sink, source);
+        sink.add_child(&mut node, child);
+    }
+
+    for _ in 0..meta_count {
+        let meta = source.next().expect("expected metadata");
+        sink.add_metadata(&mut node, meta);
+    }
+
+    node
+}
+
+struct SumMetadata {
+    next_name: u32,
+    meta_total: usize,
+}
+
+impl SumMetadata {
+    fn new() -> SumMetadata {
+        SumMetadata {
+            next_name: 'A' as u32,
+            meta_total: 0,
+        }
+    }
+}
+
+impl Sink for SumMetadata {
+    type Node = char;
+
+    fn new_node(&mut self) -> Self::Node {
+        let name = self.next_name;
+        self.next_name += 1;
+        std::char::from_u32(name).expect("ran out of unicode characters")
+    }
+
+    fn add_child(&mut self, _parent: &mut Self::Node, _child: Self::Node) {}
+
+    fn add_metadata(&mut self, _node: &mut Self::Node, metadata: usize) {
+        self.meta_total += metadata;
+    }
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    children: Vec<Node>,
+    value: usize,
+}
+
+struct FancyNodes;
+impl Sink for FancyNodes {
+    type Node = Node;
+
+    fn new_node(&mut self) -> Self::Node {
+        Node::default()
+    }
+
+    fn add_child(&mut self, parent: &mut Self::Node, child: Self::Node) {
+        parent.children.push(child);
+    }
+
+    fn add_metadata(&mut self, node: &mut Self::Node, metadata: usize) {
+        node.value += if node.children.is_empty() {
+            metadata
+        } else if metadata < 1 {
+            0
+        } else if metadata <= node.children.len() {
+            node.children[metadata - 1].value
+        } else {
+            0
+        }
+    }
+}
+
+struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<usize>> {
+        Ok(input
+            .split_whitespace()
+            .map(usize::from_str)
+            .collect::<Result<_, _>>()?)
+    }
+
+    /// Return the sum of all the nodes' metadata.
+    fn part1(numbers: &Vec<usize>) -> usize {
+        let mut summer = SumMetadata::new();
+        build(&mut summer, &mut numbers.iter().cloned());
+        summer.meta_total
+    }
+
+    /// Return the value of the root node.
+    fn part2(numbers: &Vec<usize>) -> usize {
+        build(&mut FancyNodes, &mut numbers.iter().cloned()).value
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day08>(2018, 8)
+}
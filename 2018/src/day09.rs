@@ -0,0 +1,72 @@
+use aoc_utils::ring::Ring;
+use aoc_utils::solution::{Day, Solution};
+use aoc_utils::tracing::info;
+
+/// Play an `p`-player, `n`-marble game (not counting the 'zero' marble), and
+/// return a vector of the scores of each player.
+fn play(p: usize, n: usize) -> Vec<usize> {
+    let mut scores = vec![0; p];
+    let mut circle = Ring::new(0);
+    for i in 1..=n {
+        let player = (i + p - 1) % p;
+        if i % 23 == 0 {
+            scores[player] += i;
+            circle.rotate_backward(7);
+            let (front, remainder) = circle.pop_front();
+            scores[player] += front;
+            circle = remainder.unwrap(); // circle should never become empty
+        } else {
+            circle.rotate_forward(2);
+            circle.insert_at_front(i);
+        }
+        if i % 100_000 == 0 {
+            info!("{} of {} marbles placed", i, n);
+        }
+    }
+    scores
+}
+
+fn high_score(players: usize, last_marble: usize) -> usize {
+    play(players, last_marble).into_iter().max().unwrap_or(0)
+}
+
+#[test]
+fn test_play() {
+    assert_eq!(play(9, 25), [0, 0, 0, 0, 32, 0, 0, 0, 0]);
+    assert_eq!(high_score(10, 1618), 8317);
+    assert_eq!(high_score(13, 7999), 146373);
+    assert_eq!(high_score(17, 1104), 2764);
+    assert_eq!(high_score(21, 6111), 54718);
+    assert_eq!(high_score(30, 5807), 37305);
+}
+
+struct Day09;
+
+impl Solution for Day09 {
+    /// The number of players, and the value of the last marble.
+    type Input = (usize, usize);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<(usize, usize)> {
+        Ok(aoc_utils::scan!(
+            input.trim(),
+            "_ players; last marble is worth _ points",
+            usize,
+            usize
+        )?)
+    }
+
+    fn part1(&(players, last_marble): &(usize, usize)) -> usize {
+        high_score(players, last_marble)
+    }
+
+    /// Return the high score if the last marble were worth 100 times as much.
+    fn part2(&(players, last_marble): &(usize, usize)) -> usize {
+        high_score(players, last_marble * 100)
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day09>(2018, 9)
+}
@@ -0,0 +1,89 @@
+use aoc_utils::ocr;
+use aoc_utils::scan;
+use aoc_utils::solution::{Day, Solution};
+use std::ops::Range;
+
+#[derive(Clone, Debug)]
+pub struct Light {
+    position: (i32, i32),
+    velocity: (i32, i32),
+}
+
+fn step(lights: &mut [Light], scale: i32) {
+    for light in lights {
+        light.position.0 += scale * light.velocity.0;
+        light.position.1 += scale * light.velocity.1;
+    }
+}
+
+fn bounds(lights: &[Light]) -> (Range<i32>, Range<i32>) {
+    (
+        Range {
+            start: lights.iter().map(|l| l.position.0).min().unwrap(),
+            end: lights.iter().map(|l| l.position.0).max().unwrap() + 1,
+        },
+        Range {
+            start: lights.iter().map(|l| l.position.1).min().unwrap(),
+            end: lights.iter().map(|l| l.position.1).max().unwrap() + 1,
+        },
+    )
+}
+
+fn area(lights: &[Light]) -> usize {
+    let (h, v) = bounds(lights);
+    (h.end - h.start) as usize * (v.end - v.start) as usize
+}
+
+/// Run the lights forward until they are as close together as they will get,
+/// and return them at that point, along with the number of seconds it took.
+fn converge(lights: &[Light]) -> (Vec<Light>, usize) {
+    let mut lights = lights.to_vec();
+    let mut a = area(&lights);
+    for secs in 0.. {
+        step(&mut lights, 1);
+        let new_area = area(&lights);
+        if new_area > a {
+            step(&mut lights, -1);
+            return (lights, secs);
+        }
+        a = new_area;
+    }
+    unreachable!()
+}
+
+struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Light>;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Light>> {
+        input
+            .lines()
+            .map(|line| {
+                let (px, py, vx, vy) =
+                    scan!(line, "position=<_,_> velocity=<_,_>", i32, i32, i32, i32)?;
+                Ok(Light {
+                    position: (px, py),
+                    velocity: (vx, vy),
+                })
+            })
+            .collect()
+    }
+
+    /// Return the message the lights spell out when they converge.
+    fn part1(lights: &Vec<Light>) -> String {
+        let (lights, _) = converge(lights);
+        ocr::from_points(lights.iter().map(|l| l.position))
+            .unwrap_or_else(|err| panic!("couldn't read the message: {}", err))
+    }
+
+    fn part2(lights: &Vec<Light>) -> usize {
+        converge(lights).1
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day10>(2018, 10)
+}
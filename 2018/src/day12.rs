@@ -0,0 +1,197 @@
+use anyhow::{anyhow, bail, Result};
+use aoc_utils::solution::{Day, Solution};
+use aoc_utils::spacetime::SpaceTime;
+use std::iter::repeat_n;
+
+type Rule = [bool; 32];
+
+#[derive(Clone, Debug)]
+struct State {
+    origin: isize,
+    pots: Vec<bool>,
+}
+
+fn pot_to_bool(pot: char) -> Result<bool> {
+    match pot {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => bail!("unexpected pot character: {:?}", pot),
+    }
+}
+
+fn parse_rule_line(line: &str) -> Result<(usize, bool)> {
+    if line.len() != 10 || &line[5..9] != " => " {
+        bail!("bad rule: {:?}", line);
+    }
+    let mut index = 0;
+    for pot in line.chars().take(5) {
+        index <<= 1;
+        index |= pot_to_bool(pot)? as usize;
+    }
+    Ok((index, pot_to_bool(line[9..].chars().next().unwrap())?))
+}
+
+#[test]
+fn test_parse_rule_line() {
+    assert_eq!(parse_rule_line("..... => #").unwrap(), (0b00000, true));
+    assert_eq!(parse_rule_line("..... => .").unwrap(), (0b00000, false));
+    assert_eq!(parse_rule_line("##### => #").unwrap(), (0b11111, true));
+    assert_eq!(parse_rule_line("##### => .").unwrap(), (0b11111, false));
+    assert_eq!(parse_rule_line(".#### => #").unwrap(), (0b01111, true));
+    assert_eq!(parse_rule_line("###.. => #").unwrap(), (0b11100, true));
+}
+
+impl State {
+    fn new() -> State {
+        State {
+            origin: 0,
+            pots: Vec::new(),
+        }
+    }
+
+    fn from_input(line: &str) -> Result<State> {
+        let pots = line
+            .strip_prefix("initial state: ")
+            .ok_or_else(|| anyhow!("bad initial state: {:?}", line))?;
+        let pots = pots.chars().map(pot_to_bool).collect::<Result<_>>()?;
+        Ok(State { origin: 0, pots })
+    }
+
+    fn clear(&mut self) {
+        self.pots.clear();
+        self.origin = 0;
+    }
+
+    fn step(&mut self, rule: &Rule, next: &mut State) {
+        // We don't support rules that allow plants to spurt up out of nowhere.
+        assert!(!rule[0]);
+
+        next.pots.clear();
+        next.origin = self.origin - 2;
+        let mut neighborhood: usize = 0;
+        for pot in self.pots.iter().cloned().chain(repeat_n(false, 4)) {
+            neighborhood <<= 1;
+            neighborhood |= pot as usize;
+            neighborhood &= 0b11111;
+            next.pots.push(rule[neighborhood]);
+        }
+
+        if let Some(start) = next.pots.iter().position(|pot| *pot) {
+            let end = next.pots.iter().rposition(|pot| *pot).unwrap() + 1;
+            next.pots.copy_within(start..end, 0);
+            next.pots.truncate(end - start);
+            next.origin += start as isize;
+        } else {
+            next.clear();
+        }
+    }
+
+    fn count_and_sum(&self) -> (usize, usize) {
+        self.pots
+            .iter()
+            .cloned()
+            .enumerate()
+            .filter(|(_, p)| *p)
+            .fold((0, 0), |s, i| (s.0 + 1, s.1 + i.0))
+    }
+
+    fn code(&self) -> isize {
+        let (count, sum) = self.count_and_sum();
+        (self.origin * count as isize) + sum as isize
+    }
+}
+
+/// The initial state of the pots, and the rule for the next generation.
+struct Pots {
+    initial: State,
+    rule: Rule,
+}
+
+fn parse_pots(input: &str) -> Result<Pots> {
+    let mut lines = input.lines();
+    let initial = State::from_input(lines.next().unwrap_or_default())?;
+    let mut rule = [false; 32];
+    for line in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (index, value) = parse_rule_line(line)?;
+        rule[index] = value;
+    }
+    Ok(Pots { initial, rule })
+}
+
+/// Run the pots until the pattern stops changing shape, calling `record` on
+/// each generation.
+///
+/// Return the number of steps taken, and the last two generations.
+fn converge(pots: &Pots, mut record: impl FnMut(&State)) -> (isize, State, State) {
+    let mut state = pots.initial.clone();
+    let mut next = State::new();
+    let mut steps = 0;
+    record(&state);
+    for _ in 0..500 {
+        state.step(&pots.rule, &mut next);
+        steps += 1;
+        if state.pots == next.pots {
+            break;
+        }
+        std::mem::swap(&mut state, &mut next);
+        record(&state);
+    }
+
+    assert!(state.pots == next.pots, "Didn't converge!");
+    (steps, state, next)
+}
+
+/// Parse `input` and record the pots' generations until the pattern stops
+/// changing shape.
+///
+/// The `day-12-spacetime` binary uses this to draw the diagram.
+pub fn history(input: &str) -> Result<SpaceTime> {
+    let mut history = SpaceTime::new();
+    converge(&parse_pots(input)?, |state| {
+        history.push(state.origin as i64, &state.pots)
+    });
+    Ok(history)
+}
+
+struct Day12;
+
+impl Solution for Day12 {
+    type Input = Pots;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Pots> {
+        parse_pots(input)
+    }
+
+    /// Return the sum of the numbers of the pots with plants after twenty
+    /// generations.
+    fn part1(pots: &Pots) -> isize {
+        let mut state = pots.initial.clone();
+        let mut next = State::new();
+        for _ in 0..20 {
+            state.step(&pots.rule, &mut next);
+            std::mem::swap(&mut state, &mut next);
+        }
+        state.code()
+    }
+
+    /// Return the sum after fifty billion generations.
+    fn part2(pots: &Pots) -> isize {
+        let (steps, state, mut next) = converge(pots, |_| ());
+
+        // Since we know the state has converged, the only state left to adjust
+        // per step is the origin. That adjustment, we can project into the
+        // future.
+        let remaining = 50_000_000_000 - steps;
+        next.origin += remaining * (next.origin - state.origin);
+        next.code()
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day12>(2018, 12)
+}
@@ -0,0 +1,297 @@
+use anyhow::{bail, Result};
+use aoc_utils::ndarray::Array2;
+use aoc_utils::solution::{Day, Solution};
+#[cfg(feature = "view")]
+use aoc_utils::tui::{Color, Glyph, Simulation};
+use std::cmp::max;
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Clone, Copy, Debug)]
+enum Legend {
+    Space,
+    Horizontal,
+    Vertical,
+    Intersection,
+    LeftTurn,  // if going up
+    RightTurn, // if going up
+}
+
+impl Legend {
+    fn from_char(ch: char) -> Result<(Legend, Option<Direction>)> {
+        Ok(match ch {
+            ' ' => (Legend::Space, None),
+            '/' => (Legend::RightTurn, None),
+            '\\' => (Legend::LeftTurn, None),
+            '-' => (Legend::Horizontal, None),
+            '|' => (Legend::Vertical, None),
+            '+' => (Legend::Intersection, None),
+            '^' => (Legend::Vertical, Some((-1, 0))),
+            'v' => (Legend::Vertical, Some((1, 0))),
+            '<' => (Legend::Horizontal, Some((0, -1))),
+            '>' => (Legend::Horizontal, Some((0, 1))),
+            _ => bail!("Unexpected map character: {:?}", ch),
+        })
+    }
+
+    fn symbol(&self) -> char {
+        match self {
+            Legend::Space => ' ',
+            Legend::Horizontal => '-',
+            Legend::Vertical => '|',
+            Legend::Intersection => '+',
+            Legend::LeftTurn => '\\',
+            Legend::RightTurn => '/',
+        }
+    }
+}
+
+impl fmt::Display for Legend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+type Map = Array2<Legend>;
+type Position = (usize, usize);
+type Direction = (isize, isize);
+
+fn go(pos: Position, dir: Direction) -> Position {
+    (
+        (pos.0 as isize + dir.0) as usize,
+        (pos.1 as isize + dir.1) as usize,
+    )
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Choice {
+    Left,
+    Straight,
+    Right,
+}
+
+impl Choice {
+    fn next(self) -> Choice {
+        match self {
+            Choice::Left => Choice::Straight,
+            Choice::Straight => Choice::Right,
+            Choice::Right => Choice::Left,
+        }
+    }
+
+    fn apply(&self, dir: Direction) -> Direction {
+        match self {
+            Choice::Left => (-dir.1, dir.0),
+            Choice::Straight => dir,
+            Choice::Right => (dir.1, -dir.0),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Cart {
+    position: Position,
+    direction: Direction,
+    next: Choice,
+}
+
+impl Cart {
+    fn step(&mut self, map: &Map) {
+        match map[self.position] {
+            Legend::Horizontal => {
+                assert_eq!(self.direction.0, 0);
+            }
+            Legend::Vertical => {
+                assert_eq!(self.direction.1, 0);
+            }
+            Legend::Intersection => {
+                self.direction = self.next.apply(self.direction);
+                self.next = self.next.next();
+            }
+            Legend::LeftTurn => {
+                self.direction = (self.direction.1, self.direction.0);
+            }
+            Legend::RightTurn => {
+                self.direction = (-self.direction.1, -self.direction.0);
+            }
+            Legend::Space => {
+                panic!("Cart went off into space?");
+            }
+        }
+        self.position = go(self.position, self.direction);
+    }
+
+    #[cfg(feature = "view")]
+    fn legend(&self) -> char {
+        match self.direction {
+            (0, 1) => '>',
+            (-1, 0) => '^',
+            (0, -1) => '<',
+            (1, 0) => 'v',
+            _ => panic!("weird cart direction: {:?}", self.direction),
+        }
+    }
+}
+
+fn parse_map(input: &str) -> Result<(Vec<Cart>, Map)> {
+    let (width, height) = input
+        .lines()
+        .fold((0, 0), |acc, line| (max(acc.0, line.len()), acc.1 + 1));
+
+    let mut carts = Vec::new();
+    let mut map = Array2::from_elem((height, width), Legend::Space);
+    for (row, line) in input.lines().enumerate() {
+        for (col, ch) in line.chars().enumerate() {
+            let (legend, cart) = Legend::from_char(ch)?;
+            map[[row, col]] = legend;
+            if let Some(direction) = cart {
+                carts.push(Cart {
+                    position: (row, col),
+                    direction,
+                    next: Choice::Left,
+                });
+            }
+        }
+    }
+
+    Ok((carts, map))
+}
+
+fn tick(carts: &mut Vec<Cart>, map: &Map) -> Vec<Cart> {
+    // Put the carts in the order in which we'll move them.
+    carts.sort_by_key(|c| c.position);
+
+    let mut collisions = Vec::new();
+    let mut i = 0;
+    while i < carts.len() {
+        carts[i].step(map);
+        // Check for collisions after each cart moves, to prevent
+        // face-to-face carts from moving through each other.
+        if let Some((j, _)) = carts
+            .iter()
+            .enumerate()
+            .find(|(j, c)| *j != i && c.position == carts[i].position)
+        {
+            if i < j {
+                collisions.extend(carts.drain(j..j + 1));
+                collisions.extend(carts.drain(i..i + 1));
+            } else {
+                collisions.extend(carts.drain(i..i + 1));
+                collisions.extend(carts.drain(j..j + 1));
+                i -= 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    collisions
+}
+
+/// The state of the tracks, for watching in the terminal.
+#[derive(Clone)]
+pub struct Tracks {
+    carts: Vec<Cart>,
+    map: Rc<Map>,
+    #[cfg(feature = "view")]
+    crashes: Vec<Position>,
+}
+
+#[cfg(feature = "view")]
+impl Simulation for Tracks {
+    fn size(&self) -> (usize, usize) {
+        self.map.dim()
+    }
+
+    fn cell(&self, p: Position) -> Glyph {
+        if let Some(cart) = self.carts.iter().find(|c| c.position == p) {
+            Glyph::new(cart.legend(), Color::Yellow)
+        } else if self.crashes.contains(&p) {
+            Glyph::new('X', Color::Red)
+        } else {
+            self.map[p].symbol().into()
+        }
+    }
+
+    fn describe(&self, p: Position) -> String {
+        if let Some(cart) = self.carts.iter().find(|c| c.position == p) {
+            format!(
+                "cart heading {}, turning {:?} next",
+                cart.legend(),
+                cart.next
+            )
+        } else if self.crashes.contains(&p) {
+            "crash site".to_string()
+        } else {
+            format!("{:?}", self.map[p])
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        let collisions = tick(&mut self.carts, &self.map);
+        self.crashes.extend(collisions.iter().map(|c| c.position));
+        self.carts.len() > 1
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "{} carts, {} crashes",
+            self.carts.len(),
+            self.crashes.len() / 2
+        )
+    }
+}
+
+/// Parse `input` into tracks with carts on them.
+///
+/// The `day-13-view` binary uses this to show the carts moving.
+pub fn tracks(input: &str) -> Result<Tracks> {
+    let (carts, map) = parse_map(input)?;
+    Ok(Tracks {
+        carts,
+        map: Rc::new(map),
+        #[cfg(feature = "view")]
+        crashes: vec![],
+    })
+}
+
+/// Format `position` as the puzzle wants it: `X,Y`.
+fn x_y((row, col): Position) -> String {
+    format!("{col},{row}")
+}
+
+struct Day13;
+
+impl Solution for Day13 {
+    type Input = Tracks;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Tracks> {
+        tracks(input)
+    }
+
+    /// Return the location of the first crash.
+    fn part1(tracks: &Tracks) -> String {
+        let mut carts = tracks.carts.clone();
+        loop {
+            let collisions = tick(&mut carts, &tracks.map);
+            if let Some(cart) = collisions.first() {
+                return x_y(cart.position);
+            }
+        }
+    }
+
+    /// Return the location of the last cart left after all the others crash.
+    fn part2(tracks: &Tracks) -> String {
+        let mut carts = tracks.carts.clone();
+        while carts.len() > 1 {
+            tick(&mut carts, &tracks.map);
+        }
+        x_y(carts.first().expect("no carts survived").position)
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day13>(2018, 13)
+}
@@ -0,0 +1,110 @@
+use aoc_utils::solution::{Day, Solution};
+
+/// The elves' scoreboard, and the two elves' current recipes.
+struct Scoreboard {
+    recipes: Vec<usize>,
+    a: usize,
+    b: usize,
+}
+
+impl Scoreboard {
+    fn new() -> Scoreboard {
+        Scoreboard {
+            recipes: vec![3, 7],
+            a: 0,
+            b: 1,
+        }
+    }
+
+    /// Combine the elves' current recipes, add the new recipes' scores to
+    /// `recipes`, and move the elves along.
+    fn step(&mut self) {
+        let recipes = &mut self.recipes;
+        let sum = recipes[self.a] + recipes[self.b];
+        if sum >= 10 {
+            recipes.push(1);
+            recipes.push(sum - 10);
+        } else {
+            recipes.push(sum);
+        }
+        self.a = (self.a + recipes[self.a] + 1) % recipes.len();
+        self.b = (self.b + recipes[self.b] + 1) % recipes.len();
+    }
+}
+
+/// Return the scores of the ten recipes after the first `count`.
+fn ten_after(count: usize) -> String {
+    let mut board = Scoreboard::new();
+    while board.recipes.len() < count + 10 {
+        board.step();
+    }
+    board.recipes[count..count + 10]
+        .iter()
+        .map(|score| score.to_string())
+        .collect()
+}
+
+/// Return the number of recipes to the left of the first appearance of
+/// `digits` on the scoreboard.
+fn recipes_before(digits: &[usize]) -> usize {
+    let len = digits.len();
+    let modulus = usize::pow(10, len as u32);
+    let target = digits.iter().fold(0, |n, d| n * 10 + d);
+
+    let mut board = Scoreboard::new();
+    let mut tail = 37;
+    let mut checked = 2;
+    loop {
+        board.step();
+        // A step adds one or two recipes; check the tail after each.
+        while checked < board.recipes.len() {
+            tail = (tail * 10 + board.recipes[checked]) % modulus;
+            checked += 1;
+            if checked >= len && tail == target {
+                return checked - len;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_scoreboard() {
+    assert_eq!(ten_after(9), "5158916779");
+    assert_eq!(ten_after(2018), "5941429882");
+    assert_eq!(recipes_before(&[5, 1, 5, 8, 9]), 9);
+    assert_eq!(recipes_before(&[0, 1, 2, 4, 5]), 5);
+    assert_eq!(recipes_before(&[5, 9, 4, 1, 4]), 2018);
+}
+
+struct Day14;
+
+impl Solution for Day14 {
+    /// The puzzle input's digits.
+    type Input = Vec<usize>;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<usize>> {
+        input
+            .trim()
+            .chars()
+            .map(|ch| {
+                ch.to_digit(10)
+                    .map(|d| d as usize)
+                    .ok_or_else(|| anyhow::anyhow!("expected a number of recipes: {:?}", input))
+            })
+            .collect()
+    }
+
+    fn part1(digits: &Vec<usize>) -> String {
+        ten_after(digits.iter().fold(0, |n, d| n * 10 + d))
+    }
+
+    fn part2(digits: &Vec<usize>) -> usize {
+        recipes_before(digits)
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day14>(2018, 14)
+}
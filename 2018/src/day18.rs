@@ -0,0 +1,184 @@
+use aoc_utils::ndarray::{Array2, Axis};
+use aoc_utils::solution::{Day, Solution};
+use aoc_utils::{cartesian_product, parse_map};
+
+type Point = (usize, usize);
+
+fn neighborhood(p: Point, bounds: Point) -> impl Iterator<Item = Point> + Clone {
+    cartesian_product(-1..=1, -1..=1).filter_map(move |(dx, dy)| {
+        if dx == 0 && dy == 0 {
+            return None;
+        }
+        let x = p.0 as isize + dx;
+        let y = p.1 as isize + dy;
+        if x < 0 || y < 0 {
+            return None;
+        }
+        let x = x as usize;
+        let y = y as usize;
+
+        if x >= bounds.0 || y >= bounds.1 {
+            return None;
+        }
+
+        Some((x, y))
+    })
+}
+
+#[derive(Debug, Default)]
+struct Census {
+    open: usize,
+    wooded: usize,
+    lumberyard: usize,
+}
+
+impl Census {
+    fn count(&mut self, ch: char) {
+        match ch {
+            '.' => self.open += 1,
+            '|' => self.wooded += 1,
+            '#' => self.lumberyard += 1,
+            _ => panic!("unexpected character {:?} in map", ch),
+        }
+    }
+}
+
+fn neighborhood_census(map: &Array2<char>, p: Point) -> Census {
+    let mut c = Census::default();
+    for pt in neighborhood(p, map.dim()) {
+        c.count(map[pt]);
+    }
+    c
+}
+
+fn map_census(map: &Array2<char>) -> Census {
+    let mut c = Census::default();
+    for row in 0..map.len_of(Axis(0)) {
+        for col in 0..map.len_of(Axis(1)) {
+            c.count(map[[row, col]]);
+        }
+    }
+    c
+}
+
+fn step(prev: &Array2<char>, next: &mut Array2<char>) {
+    assert_eq!(prev.dim(), next.dim());
+
+    for row in 0..prev.len_of(Axis(0)) {
+        for col in 0..prev.len_of(Axis(1)) {
+            let p = (row, col);
+            next[p] = match (prev[p], neighborhood_census(prev, p)) {
+                ('.', Census { wooded, .. }) if wooded >= 3 => '|',
+                ('|', Census { lumberyard, .. }) if lumberyard >= 3 => '#',
+                (
+                    '#',
+                    Census {
+                        lumberyard, wooded, ..
+                    },
+                ) if lumberyard == 0 || wooded == 0 => '.',
+                _ => prev[p],
+            };
+        }
+    }
+}
+
+fn step_in_place(now: &mut Array2<char>, other: &mut Array2<char>) {
+    step(now, other);
+    std::mem::swap(now, other);
+}
+
+/// Return the resource value of `map` after `minutes` minutes.
+fn resource_value(map: &Array2<char>, minutes: usize) -> usize {
+    let mut map = map.clone();
+    let mut temp = map.clone();
+    for _ in 0..minutes {
+        step_in_place(&mut map, &mut temp);
+    }
+    let census = map_census(&map);
+    census.wooded * census.lumberyard
+}
+
+/// Return the resource value of `map` after a billion minutes, by finding
+/// the cycle the landscape settles into.
+fn long_term_resource_value(map: &Array2<char>) -> usize {
+    let mut map = map.clone();
+    let mut temp = map.clone();
+
+    let mut slow_map = map.clone();
+    let mut slow_map_temp = map.clone();
+
+    let mut slow_gen = 0;
+    loop {
+        step_in_place(&mut map, &mut temp);
+        step_in_place(&mut map, &mut temp);
+
+        step_in_place(&mut slow_map, &mut slow_map_temp);
+
+        slow_gen += 1;
+
+        if map == slow_map {
+            break;
+        }
+    }
+
+    // `slow_map` is now part of a cycle; find its period.
+    let mut period = 0;
+    loop {
+        step_in_place(&mut map, &mut temp);
+        period += 1;
+
+        if map == slow_map {
+            break;
+        }
+    }
+
+    let additional = 1_000_000_000 - slow_gen;
+    resource_value(&slow_map, additional % period)
+}
+
+/// Parse `input`, and pass the map to `frame` initially and after each of
+/// the first `minutes` minutes.
+///
+/// The `day-18-animate` binary uses this to record an animation.
+pub fn watch(
+    input: &str,
+    minutes: usize,
+    mut frame: impl FnMut(&Array2<char>),
+) -> anyhow::Result<()> {
+    let mut map = Day18::parse(input)?;
+    let mut temp = map.clone();
+    frame(&map);
+    for _ in 0..minutes {
+        step_in_place(&mut map, &mut temp);
+        frame(&map);
+    }
+    Ok(())
+}
+
+struct Day18;
+
+impl Solution for Day18 {
+    type Input = Array2<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Array2<char>> {
+        let map = parse_map(input.lines(), '.');
+        if let Some(ch) = map.iter().find(|ch| !".|#".contains(**ch)) {
+            anyhow::bail!("unexpected character {:?} in map", ch);
+        }
+        Ok(map)
+    }
+
+    fn part1(map: &Array2<char>) -> usize {
+        resource_value(map, 10)
+    }
+
+    fn part2(map: &Array2<char>) -> usize {
+        long_term_resource_value(map)
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day18>(2018, 18)
+}
@@ -0,0 +1,70 @@
+//! The program computes the sum of the divisors of a number it builds in a
+//! register. Rather than run its quadratic search, run the setup code until
+//! the program jumps back to the search loop at instruction 1, take the
+//! largest register as the number, and add up its divisors directly.
+
+use aoc_utils::machine::{assemble, parse_program, AssemblyInsn, State, Word};
+use aoc_utils::solution::{Day, Solution};
+
+/// Run `program` from instruction 0 with register 0 set to `r0`, until it
+/// is about to execute instruction 1. Return the machine's state at that
+/// point.
+fn setup(ip_register: usize, program: &[AssemblyInsn], r0: Word) -> State {
+    let machine_code = assemble(program);
+    let mut ip = 0;
+    let mut state = State::default();
+    state.0[0] = r0;
+    loop {
+        state.0[ip_register] = ip as Word;
+        let insn = &machine_code[ip];
+        insn.semantic.1.step(insn, &mut state);
+        ip = state.0[ip_register] as usize + 1;
+        if ip == 1 {
+            return state;
+        }
+    }
+}
+
+fn sum_of_divisors(n: Word) -> Word {
+    (1..)
+        .take_while(|d| d * d <= n)
+        .filter(|d| n % d == 0)
+        .map(|d| if d * d == n { d } else { d + n / d })
+        .sum()
+}
+
+fn run(ip_register: usize, program: &[AssemblyInsn], r0: Word) -> Word {
+    let state = setup(ip_register, program, r0);
+    sum_of_divisors(*state.0.iter().max().unwrap())
+}
+
+struct Day19;
+
+impl Solution for Day19 {
+    type Input = (usize, Vec<AssemblyInsn>);
+    type Answer1 = Word;
+    type Answer2 = Word;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_program(input)?)
+    }
+
+    fn part1((ip_register, program): &Self::Input) -> Word {
+        run(*ip_register, program, 0)
+    }
+
+    fn part2((ip_register, program): &Self::Input) -> Word {
+        run(*ip_register, program, 1)
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day19>(2018, 19)
+}
+
+#[test]
+fn test_sum_of_divisors() {
+    assert_eq!(sum_of_divisors(875), 1248);
+    assert_eq!(sum_of_divisors(1), 1);
+    assert_eq!(sum_of_divisors(36), 91);
+}
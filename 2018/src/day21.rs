@@ -0,0 +1,85 @@
+//! The program loops, computing a hash of the previous value in a register,
+//! until that register equals register 0. Rather than simulate it, take the
+//! hash's seed and multiplier from the program and compute the values it
+//! compares with register 0 directly.
+
+use anyhow::anyhow;
+use aoc_utils::machine::{parse_program, AssemblyInsn, Word};
+use aoc_utils::solution::{Day, Solution};
+use std::collections::HashSet;
+
+/// The constants in the program's hash function.
+struct Hash {
+    seed: Word,
+    multiplier: Word,
+}
+
+impl Hash {
+    /// Return the values the program compares with register 0, in order,
+    /// forever.
+    fn values(&self) -> impl Iterator<Item = Word> + '_ {
+        let mut r3: Word = 0;
+        std::iter::from_fn(move || {
+            let mut r1 = r3 | 0x10000;
+            r3 = self.seed;
+            loop {
+                r3 = (((r3 + (r1 & 0xff)) & 0xffffff) * self.multiplier) & 0xffffff;
+                if r1 < 256 {
+                    break;
+                }
+                r1 /= 256;
+            }
+            Some(r3)
+        })
+    }
+}
+
+fn find_constants(program: &[AssemblyInsn]) -> anyhow::Result<Hash> {
+    let seed = program
+        .windows(2)
+        .find(|pair| pair[0].mnemonic == "bori" && pair[0].b == 0x10000)
+        .filter(|pair| pair[1].mnemonic == "seti")
+        .map(|pair| pair[1].a)
+        .ok_or_else(|| anyhow!("couldn't find hash seed"))?;
+    let multiplier = program
+        .iter()
+        .find(|insn| insn.mnemonic == "muli")
+        .map(|insn| insn.b)
+        .ok_or_else(|| anyhow!("couldn't find hash multiplier"))?;
+    Ok(Hash { seed, multiplier })
+}
+
+struct Day21;
+
+impl Solution for Day21 {
+    type Input = Hash;
+    type Answer1 = Word;
+    type Answer2 = Word;
+
+    fn parse(input: &str) -> anyhow::Result<Hash> {
+        let (_ip_register, program) = parse_program(input)?;
+        find_constants(&program)
+    }
+
+    /// Return the value for register 0 that halts the program soonest.
+    fn part1(hash: &Hash) -> Word {
+        hash.values().next().unwrap()
+    }
+
+    /// Return the value for register 0 that halts the program latest.
+    fn part2(hash: &Hash) -> Word {
+        let mut seen = HashSet::new();
+        let mut last = 0;
+        for value in hash.values() {
+            if !seen.insert(value) {
+                break;
+            }
+            last = value;
+        }
+        last
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day21>(2018, 21)
+}
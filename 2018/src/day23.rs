@@ -1,5 +1,6 @@
+use aoc_utils::solution::{Day, Solution};
+use aoc_utils::{scan, Manhattan};
 use std::cmp::{max, min};
-use std::iter::FromIterator;
 
 type Point = (isize, isize, isize);
 
@@ -9,11 +10,17 @@ struct Nanobot {
     radius: isize,
 }
 
-#[allow(dead_code)]
-static TEST_INPUT: &[Nanobot] = &include!("day-23b.test");
-
-#[allow(dead_code)]
-static INPUT: &[Nanobot] = &include!("day-23.input");
+fn parse_nanobots(text: &str) -> Result<Vec<Nanobot>, aoc_utils::parse::Error> {
+    text.lines()
+        .map(|line| {
+            let (x, y, z, radius) = scan!(line, "pos=<_,_,_>, r=_", isize, isize, isize, isize)?;
+            Ok(Nanobot {
+                pos: (x, y, z),
+                radius,
+            })
+        })
+        .collect()
+}
 
 /// Pairwise product of two `Point`s.
 fn mul(a: Point, b: Point) -> Point {
@@ -36,7 +43,9 @@ fn dot(a: Point, b: Point) -> isize {
 /// Each face of an axis-aligned octahedron is a plane whose points `p` satisfy
 /// the equation
 ///
-///     dot(orthant(i), p) == b
+/// ```text
+/// dot(orthant(i), p) == b
+/// ```
 ///
 /// where `i` identifies a particular face, and `b` is a function of how far
 /// that face is from the origin. The orthant determines the orientation of the
@@ -65,7 +74,9 @@ fn orthant(orthant: usize) -> Point {
 /// coefficients (1 or -1) on the coordinates. Each face has its own value for
 /// `b`. In Rust, the plane would be the set of `Point`s `p` such that:
 ///
-///     dot(orthant(i), p) == b
+/// ```text
+/// dot(orthant(i), p) == b
+/// ```
 ///
 /// where `i` is a number from `0` to `7` representing a particular choice for
 /// the three signs: the `2²`, `2¹`, and `2⁰` bits represent the coefficients
@@ -307,13 +318,25 @@ fn test_octasection() {
     assert_eq!(OctaSection::from_center_radius((-10,-5,0), 5).shortest_distance_to_origin(), 10);
 }
 
-#[allow(unused_variables)]
-fn main() {
-    let bots = Vec::from_iter(
-        INPUT
-            .iter()
-            .map(|bot| OctaSection::from_center_radius(bot.pos, bot.radius)),
-    );
+/// Return the number of nanobots in range of the strongest one.
+fn in_range_of_strongest(nanobots: &[Nanobot]) -> usize {
+    let strongest = nanobots.iter().max_by_key(|n| n.radius).unwrap();
+    nanobots
+        .iter()
+        .filter(|nanobot| strongest.pos.manhattan(nanobot.pos) <= strongest.radius)
+        .count()
+}
+
+/// Return the distance from the origin to the closest point in range of as
+/// many nanobots as possible.
+///
+/// This doesn't search exhaustively: it sets aside the nanobots that overlap
+/// the fewest others, one at a time, until the rest have a point in common.
+fn closest_best_point(nanobots: &[Nanobot]) -> isize {
+    let bots: Vec<_> = nanobots
+        .iter()
+        .map(|bot| OctaSection::from_center_radius(bot.pos, bot.radius))
+        .collect();
 
     let enclosure = bots
         .iter()
@@ -331,26 +354,39 @@ fn main() {
             (i, overlaps)
         })
         .collect();
-    index_overlaps.sort_by_key(|(i, overlaps)| *overlaps);
+    index_overlaps.sort_by_key(|(_i, overlaps)| *overlaps);
 
-    for &(i, overlaps) in &index_overlaps {
-        println!("bot {i} overlaps {overlaps} other bots");
+    (0..index_overlaps.len())
+        .map(|start| {
+            index_overlaps[start..]
+                .iter()
+                .fold(enclosure.clone(), |int, &(i, _)| int.intersection(&bots[i]))
+        })
+        .find(|intersection| !intersection.is_empty())
+        .unwrap_or(enclosure)
+        .shortest_distance_to_origin()
+}
+
+struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<Nanobot>;
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Nanobot>> {
+        Ok(parse_nanobots(input)?)
     }
 
-    const START: usize = 30;
-    let intersection = index_overlaps[START..]
-        .iter()
-        .fold(enclosure.clone(), |int, &(i, _)| int.intersection(&bots[i]));
+    fn part1(nanobots: &Vec<Nanobot>) -> usize {
+        in_range_of_strongest(nanobots)
+    }
 
-    if intersection.is_empty() {
-        println!("Bots from {START} onwards have no intersection.");
-    } else {
-        println!("Bots from {START} onwards have a non-empty intersection.");
+    fn part2(nanobots: &Vec<Nanobot>) -> isize {
+        closest_best_point(nanobots)
     }
+}
 
-    println!("Intersection: {intersection:?}");
-    println!(
-        "Shortest distance from origin to point in intersection: {}",
-        intersection.shortest_distance_to_origin()
-    );
+pub fn day() -> Day {
+    Day::new::<Day23>(2018, 23)
 }
@@ -0,0 +1,241 @@
+use anyhow::{anyhow, bail, Result};
+use aoc_utils::scan;
+use aoc_utils::solution::{Day, Solution};
+use std::cmp::min;
+use std::collections::{BTreeSet, HashSet};
+use std::iter::FromIterator;
+
+#[derive(Clone, Debug)]
+struct Group {
+    units: usize,
+    hp_per_unit: usize,
+    immunities: HashSet<String>,
+    weaknesses: HashSet<String>,
+    damage_type: String,
+    damage: usize,
+    initiative: usize,
+}
+
+impl Group {
+    fn effective_power(&self) -> usize {
+        self.units * self.damage
+    }
+
+    fn damage(&self, other: &Group) -> usize {
+        if other.immunities.contains(&self.damage_type) {
+            0
+        } else if other.weaknesses.contains(&self.damage_type) {
+            self.effective_power() * 2
+        } else {
+            self.effective_power()
+        }
+    }
+}
+
+fn parse_group(line: &str) -> Result<Group> {
+    let (units, hp_per_unit, rest) = scan!(
+        line,
+        "_ units each with _ hit points _",
+        usize,
+        usize,
+        String
+    )?;
+    let (modifiers, attack) = match rest.strip_prefix('(') {
+        Some(rest) => rest
+            .split_once(") ")
+            .ok_or_else(|| anyhow!("unclosed parenthesis: {line:?}"))?,
+        None => ("", rest.as_str()),
+    };
+    let (damage, damage_type, initiative) = scan!(
+        attack,
+        "with an attack that does _ _ damage at initiative _",
+        usize,
+        String,
+        usize
+    )?;
+
+    let mut immunities = HashSet::new();
+    let mut weaknesses = HashSet::new();
+    for modifier in modifiers.split("; ").filter(|m| !m.is_empty()) {
+        let (set, types) = if let Some(types) = modifier.strip_prefix("immune to ") {
+            (&mut immunities, types)
+        } else if let Some(types) = modifier.strip_prefix("weak to ") {
+            (&mut weaknesses, types)
+        } else {
+            bail!("unrecognized immunity or weakness: {modifier:?}");
+        };
+        set.extend(types.split(", ").map(str::to_string));
+    }
+
+    Ok(Group {
+        units,
+        hp_per_unit,
+        immunities,
+        weaknesses,
+        damage_type,
+        damage,
+        initiative,
+    })
+}
+
+/// Parse the two teams' groups.
+fn parse_teams(text: &str) -> Result<[Vec<Group>; 2]> {
+    let (immune_system, infection) = text
+        .split_once("\n\n")
+        .ok_or_else(|| anyhow!("expected two teams, separated by a blank line"))?;
+    Ok([parse_team(immune_system, 0)?, parse_team(infection, 1)?])
+}
+
+fn parse_team(text: &str, team: usize) -> Result<Vec<Group>> {
+    let mut lines = text.lines();
+    let heading = lines.next().unwrap_or_default();
+    if heading != format!("{}:", team_name(team)) {
+        bail!("expected {:?} team, found {:?}", team_name(team), heading);
+    }
+    lines.map(parse_group).collect()
+}
+
+fn team_name(i: usize) -> &'static str {
+    match i {
+        0 => "Immune System",
+        1 => "Infection",
+        _ => panic!("bad team number {}", i),
+    }
+}
+
+fn total_units(team: &[Group]) -> usize {
+    team.iter().map(|g| g.units).sum::<usize>()
+}
+
+/// Fight a battle with the immune system's attacks boosted by `boost`, and
+/// return the winning team's number and how many units it has left. The
+/// infection wins a stalemate.
+fn battle(original_teams: &[Vec<Group>; 2], boost: usize) -> (usize, usize) {
+    let mut teams = original_teams.clone();
+    for group in &mut teams[0] {
+        group.damage += boost;
+    }
+
+    loop {
+        if teams[0].is_empty() {
+            return (1, total_units(&teams[1]));
+        }
+        if teams[1].is_empty() {
+            return (0, total_units(&teams[0]));
+        }
+
+        let mut targetting_order: Vec<(usize, usize)> = (0..=1)
+            .flat_map(|team| (0..teams[team].len()).map(move |i| (team, i)))
+            .collect();
+
+        targetting_order.sort_by_key(|&(team, i)| {
+            let group = &teams[team][i];
+            (group.effective_power(), group.initiative)
+        });
+        targetting_order.reverse();
+
+        let mut unchosen: [BTreeSet<usize>; 2] = [
+            BTreeSet::from_iter(0..teams[0].len()),
+            BTreeSet::from_iter(0..teams[1].len()),
+        ];
+
+        let mut targets: Vec<(usize, usize, usize)> = targetting_order
+            .into_iter()
+            .map(|(team, i)| {
+                let attacker = &teams[team][i];
+                let enemy = 1 - team;
+                let best_target = unchosen[enemy]
+                    .iter()
+                    .cloned()
+                    .filter(|&group_ix| {
+                        let enemy_group = &teams[enemy][group_ix];
+                        attacker.damage(enemy_group) > 0
+                    })
+                    .max_by_key(|&group_ix| {
+                        let enemy_group = &teams[enemy][group_ix];
+                        (
+                            attacker.damage(enemy_group),
+                            enemy_group.effective_power(),
+                            enemy_group.initiative,
+                        )
+                    });
+                if let Some(group_ix) = best_target {
+                    unchosen[enemy].remove(&group_ix);
+                }
+                (team, i, best_target)
+            })
+            .filter_map(|(team, i, target)| target.map(|target| (team, i, target)))
+            .collect();
+
+        targets.sort_by_key(|&(team, i, _)| teams[team][i].initiative);
+        targets.reverse();
+
+        let mut any_killed = false;
+        for &(team, i, target) in &targets {
+            let attacker = &teams[team][i];
+            if attacker.units == 0 {
+                // This group has been defeated, and does not attack.
+                continue;
+            }
+
+            let target_group = &teams[1 - team][target];
+            let damage = attacker.damage(target_group);
+            let killed = min(target_group.units, damage / target_group.hp_per_unit);
+            if killed > 0 {
+                any_killed = true;
+            }
+            teams[1 - team][target].units -= killed;
+        }
+
+        if !any_killed {
+            // Stalemate.
+            return (1, total_units(&teams[1]));
+        }
+
+        for team in teams.iter_mut() {
+            team.retain(|group| group.units > 0);
+        }
+    }
+}
+
+/// Find the smallest boost with which the immune system wins, and return
+/// the number of units it has left.
+fn smallest_winning_boost(original_teams: &[Vec<Group>; 2]) -> usize {
+    let mut range = 0..5000;
+    assert!(battle(original_teams, range.end).0 == 0);
+
+    while range.end - range.start > 1 {
+        let boost = range.start + ((range.end - range.start) / 2) - 1;
+        if battle(original_teams, boost).0 == 0 {
+            range.end = boost + 1;
+        } else {
+            range.start = boost + 1;
+        }
+    }
+    battle(original_teams, range.start).1
+}
+
+struct Day24;
+
+impl Solution for Day24 {
+    type Input = [Vec<Group>; 2];
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<[Vec<Group>; 2]> {
+        parse_teams(input)
+    }
+
+    /// Return the number of units the winning army has left.
+    fn part1(teams: &[Vec<Group>; 2]) -> usize {
+        battle(teams, 0).1
+    }
+
+    fn part2(teams: &[Vec<Group>; 2]) -> usize {
+        smallest_winning_boost(teams)
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day24>(2018, 24)
+}
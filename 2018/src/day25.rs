@@ -0,0 +1,138 @@
+use aoc_utils::solution::{Day, Solution};
+use aoc_utils::{scan, Manhattan};
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::mem::replace;
+
+type Point = (i32, i32, i32, i32);
+
+fn count_constellations(points: &[Point]) -> usize {
+    let mut parent: Vec<usize> = (0..points.len()).collect();
+
+    for new in 0..points.len() {
+        for friend in 0..new {
+            if points[new].manhattan(points[friend]) > 3 {
+                continue;
+            }
+
+            let mut end = friend;
+            while parent[end] != end {
+                end = parent[end];
+            }
+            parent[end] = new;
+        }
+    }
+
+    let mut constellations = HashSet::new();
+    for point in 0..points.len() {
+        let mut end = point;
+        while parent[end] != end {
+            end = parent[end];
+        }
+
+        let mut retrace = point;
+        while parent[retrace] != retrace {
+            retrace = replace(&mut parent[retrace], end);
+        }
+        assert_eq!(retrace, end);
+
+        constellations.insert(end);
+    }
+
+    constellations.len()
+}
+
+fn parse_points(text: &str) -> Result<Vec<Point>, aoc_utils::parse::Error> {
+    text.lines()
+        .map(|line| scan!(line, "_,_,_,_", i32, i32, i32, i32))
+        .collect()
+}
+
+struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Point>;
+    type Answer1 = usize;
+    type Answer2 = Infallible;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Point>> {
+        Ok(parse_points(input)?)
+    }
+
+    fn part1(points: &Vec<Point>) -> usize {
+        count_constellations(points)
+    }
+
+    fn part2(_points: &Vec<Point>) -> Infallible {
+        unreachable!("day 25 has no part two")
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day25>(2018, 25).without_part2()
+}
+
+#[test]
+fn test_count_constellations() {
+    assert_eq!(
+        count_constellations(&[
+            (0, 0, 0, 0),
+            (3, 0, 0, 0),
+            (0, 3, 0, 0),
+            (0, 0, 3, 0),
+            (0, 0, 0, 3),
+            (0, 0, 0, 6),
+            (9, 0, 0, 0),
+            (12, 0, 0, 0),
+        ]),
+        2
+    );
+
+    assert_eq!(
+        count_constellations(&[
+            (-1, 2, 2, 0),
+            (0, 0, 2, -2),
+            (0, 0, 0, -2),
+            (-1, 2, 0, 0),
+            (-2, -2, -2, 2),
+            (3, 0, 2, -1),
+            (-1, 3, 2, 2),
+            (-1, 0, -1, 0),
+            (0, 2, 1, -2),
+            (3, 0, 0, 0),
+        ]),
+        4
+    );
+
+    assert_eq!(
+        count_constellations(&[
+            (1, -1, 0, 1),
+            (2, 0, -1, 0),
+            (3, 2, -1, 0),
+            (0, 0, 3, 1),
+            (0, 0, -1, -1),
+            (2, 3, -2, 0),
+            (-2, 2, 0, 0),
+            (2, -2, 0, -1),
+            (1, -1, 0, -1),
+            (3, 2, 0, 2),
+        ]),
+        3
+    );
+
+    assert_eq!(
+        count_constellations(&[
+            (1, -1, -1, -2),
+            (-2, -2, 0, 1),
+            (0, 2, 1, 3),
+            (-2, 3, -2, 1),
+            (0, 2, 3, -2),
+            (-1, -1, 1, -2),
+            (0, -2, -1, 0),
+            (-2, 2, 3, -1),
+            (1, 2, 2, 0),
+            (-1, -2, 0, -2),
+        ]),
+        8
+    );
+}
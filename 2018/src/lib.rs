@@ -0,0 +1,68 @@
+//! Solutions that implement `aoc_utils::solution::Solution`, so that the
+//! workspace's runner can run them. Each program in `src/bin` runs one of
+//! them; the others there draw or animate a puzzle.
+
+use aoc_utils::solution::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub fn solutions() -> Vec<Day> {
+    vec![
+        day01::day(),
+        day02::day(),
+        day03::day(),
+        day04::day(),
+        day05::day(),
+        day06::day(),
+        day07::day(),
+        day08::day(),
+        day09::day(),
+        day10::day(),
+        day11::day(),
+        day12::day(),
+        day13::day(),
+        day14::day(),
+        day15::day(),
+        day16::day(),
+        day17::day(),
+        day18::day(),
+        day19::day(),
+        day20::day(),
+        day21::day(),
+        day22::day(),
+        day23::day(),
+        day24::day(),
+        day25::day(),
+    ]
+}
+
+/// Check `solutions()` against each sample in `src/bin` that has a
+/// `.expected` file; see `aoc_utils::samples`.
+#[cfg(test)]
+mod samples {
+    aoc_utils::sample_tests!();
+}
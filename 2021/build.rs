@@ -0,0 +1,3 @@
+fn main() {
+    aoc_samples::write_tests("src/sample").unwrap();
+}
@@ -1,1019 +0,0 @@
-#![allow(dead_code)]
-
-pub static SAMPLE_1: &[u32] = &[
-    0b00100,
-    0b11110,
-    0b10110,
-    0b10111,
-    0b10101,
-    0b01111,
-    0b00111,
-    0b11100,
-    0b10000,
-    0b11001,
-    0b00010,
-    0b01010,
-];
-
-pub static INPUT_1: &[u32] = &[
-    0b001000010101,
-    0b010010111110,
-    0b001010110111,
-    0b001001011101,
-    0b001001010011,
-    0b001111100111,
-    0b100000000101,
-    0b010001011011,
-    0b000101011010,
-    0b101100010011,
-    0b111011101101,
-    0b101100100110,
-    0b000101111011,
-    0b101111000011,
-    0b001100000101,
-    0b010011111000,
-    0b011001001111,
-    0b101100100011,
-    0b000110010011,
-    0b011010111101,
-    0b010011101010,
-    0b111101111000,
-    0b110111011110,
-    0b101111100010,
-    0b101000100011,
-    0b011000011001,
-    0b111101011101,
-    0b010001101111,
-    0b000010010010,
-    0b101100010110,
-    0b001001010010,
-    0b010000000011,
-    0b110000000000,
-    0b101111101000,
-    0b001111010110,
-    0b010011101011,
-    0b100001011111,
-    0b111001110100,
-    0b101000010000,
-    0b110101001011,
-    0b010000110101,
-    0b001110000110,
-    0b011000000010,
-    0b101110010001,
-    0b101010000010,
-    0b011101100110,
-    0b011111011100,
-    0b000000111001,
-    0b010110001010,
-    0b010001111010,
-    0b110111100110,
-    0b101011000100,
-    0b101100010101,
-    0b000011111101,
-    0b101011111001,
-    0b011000001001,
-    0b000001001001,
-    0b100100101010,
-    0b101110001011,
-    0b111101110000,
-    0b111101101100,
-    0b010110100110,
-    0b011110001110,
-    0b001110011100,
-    0b100101010010,
-    0b100000001111,
-    0b000010111111,
-    0b101000011100,
-    0b010100001000,
-    0b010001010010,
-    0b101100111111,
-    0b010101010101,
-    0b010011000111,
-    0b111010010101,
-    0b111010111111,
-    0b100001011110,
-    0b100111110111,
-    0b010010111001,
-    0b110100100110,
-    0b111011110110,
-    0b111110011111,
-    0b011011111101,
-    0b100011011100,
-    0b000100101110,
-    0b000110111110,
-    0b111110010111,
-    0b101100110110,
-    0b010011010111,
-    0b101101001110,
-    0b010100010010,
-    0b111101010111,
-    0b111000100001,
-    0b100011010000,
-    0b111001110110,
-    0b100100010100,
-    0b011010001101,
-    0b000110010111,
-    0b001010101001,
-    0b011000010011,
-    0b010100011100,
-    0b110001111101,
-    0b111001110000,
-    0b111100110101,
-    0b111000101111,
-    0b000010011111,
-    0b011100110110,
-    0b010111101000,
-    0b011001110011,
-    0b100101110101,
-    0b011001010110,
-    0b010101100110,
-    0b000111001000,
-    0b101110001111,
-    0b110100000110,
-    0b000111011111,
-    0b010101111101,
-    0b111010111011,
-    0b100001101111,
-    0b101110000110,
-    0b111111111010,
-    0b110111000010,
-    0b100010101111,
-    0b100111010010,
-    0b010000001110,
-    0b100110000011,
-    0b110101011100,
-    0b010100100100,
-    0b001100011100,
-    0b001100010000,
-    0b100000101010,
-    0b101111010100,
-    0b001100001000,
-    0b111100110100,
-    0b011101111010,
-    0b001011000010,
-    0b110000010101,
-    0b001011001111,
-    0b000111011100,
-    0b101101101110,
-    0b110001011111,
-    0b111111011101,
-    0b100001001000,
-    0b010001110110,
-    0b000011101010,
-    0b100011101000,
-    0b000110111100,
-    0b011100111100,
-    0b101100101000,
-    0b111110001100,
-    0b100010010100,
-    0b011011000011,
-    0b010001101101,
-    0b001110011010,
-    0b011111101111,
-    0b000010001110,
-    0b010100101111,
-    0b010010011010,
-    0b000010010000,
-    0b101000001000,
-    0b011010010111,
-    0b110111111110,
-    0b111101001000,
-    0b101100110111,
-    0b001101000000,
-    0b011010011001,
-    0b111000100000,
-    0b000000010101,
-    0b110000011011,
-    0b111100101000,
-    0b000111010110,
-    0b110000000010,
-    0b111000100011,
-    0b110110001100,
-    0b011110000000,
-    0b111011011111,
-    0b101001000001,
-    0b000010010110,
-    0b011010101101,
-    0b110001100111,
-    0b100101110011,
-    0b000110001011,
-    0b000110011111,
-    0b001100100010,
-    0b001011000111,
-    0b001000010110,
-    0b001010011000,
-    0b011001101110,
-    0b001010000100,
-    0b011010100111,
-    0b111010100110,
-    0b010100000010,
-    0b010011100100,
-    0b011101010110,
-    0b100101000000,
-    0b111100010110,
-    0b001101101011,
-    0b001100000111,
-    0b001011010001,
-    0b011101110010,
-    0b000011111000,
-    0b000000000111,
-    0b111111110011,
-    0b000101111000,
-    0b110001010111,
-    0b010001010001,
-    0b001011000000,
-    0b101110111011,
-    0b101111100001,
-    0b010110000010,
-    0b011011101000,
-    0b001100110101,
-    0b101111101011,
-    0b100001010111,
-    0b010110110110,
-    0b011101000101,
-    0b110001110111,
-    0b010101001101,
-    0b100111010011,
-    0b010011110110,
-    0b010101110001,
-    0b011011110011,
-    0b110011101001,
-    0b010001111011,
-    0b110110000000,
-    0b100001000110,
-    0b011000101111,
-    0b001100110000,
-    0b001110010100,
-    0b111011100100,
-    0b000011100010,
-    0b101010001101,
-    0b101011111111,
-    0b100001111110,
-    0b011011100011,
-    0b010011111011,
-    0b000101001000,
-    0b111101010110,
-    0b111101000111,
-    0b111111100001,
-    0b100100100000,
-    0b010000111110,
-    0b110110000101,
-    0b001110011011,
-    0b010100101101,
-    0b010100101110,
-    0b100011010100,
-    0b001001110101,
-    0b011011111000,
-    0b111111001101,
-    0b011001110101,
-    0b011001111111,
-    0b010000010100,
-    0b010110001001,
-    0b100101000010,
-    0b111101111001,
-    0b010111111010,
-    0b110011111000,
-    0b000100111000,
-    0b110101100101,
-    0b010100001101,
-    0b000111011110,
-    0b011110000111,
-    0b011000001101,
-    0b110011000010,
-    0b010010100001,
-    0b000001101100,
-    0b010000011100,
-    0b100010001111,
-    0b111010010100,
-    0b001101111000,
-    0b100111001111,
-    0b111011101100,
-    0b010011111110,
-    0b111010001111,
-    0b100111110110,
-    0b100101110000,
-    0b001001100110,
-    0b001010011001,
-    0b010100110000,
-    0b100011101101,
-    0b100000001101,
-    0b110010000111,
-    0b101001011001,
-    0b101010010111,
-    0b101011101110,
-    0b011111101011,
-    0b110100110110,
-    0b000101010000,
-    0b011101100011,
-    0b100011100101,
-    0b000001000111,
-    0b011011110010,
-    0b010001001010,
-    0b001001101100,
-    0b100101011100,
-    0b111110100100,
-    0b101101111000,
-    0b101000101110,
-    0b110101011000,
-    0b101001111100,
-    0b011001111000,
-    0b110110101100,
-    0b100010000010,
-    0b100110100000,
-    0b011010010110,
-    0b100011100000,
-    0b111100000000,
-    0b001111111110,
-    0b010000010010,
-    0b100110000100,
-    0b100000011010,
-    0b010010011111,
-    0b011001110001,
-    0b001110100010,
-    0b101011100000,
-    0b011011011101,
-    0b011100010011,
-    0b011000111011,
-    0b111010010111,
-    0b000011011101,
-    0b000100000001,
-    0b110000001011,
-    0b111000011111,
-    0b111110111111,
-    0b011111110111,
-    0b000011000010,
-    0b100111011101,
-    0b010001010100,
-    0b111100100101,
-    0b111110100010,
-    0b010101110100,
-    0b001111111100,
-    0b011000100010,
-    0b011010111011,
-    0b100010111110,
-    0b011100000010,
-    0b011111111110,
-    0b110001001111,
-    0b011001000111,
-    0b101000000110,
-    0b010010001100,
-    0b001100010111,
-    0b111110101010,
-    0b110011011100,
-    0b110011011010,
-    0b110010011101,
-    0b101001111111,
-    0b111001111001,
-    0b101101100101,
-    0b100100111010,
-    0b010110101100,
-    0b100100100100,
-    0b110110110001,
-    0b000010001101,
-    0b101001001100,
-    0b010101010001,
-    0b111001001000,
-    0b001000110110,
-    0b101100000101,
-    0b010010100111,
-    0b101001010010,
-    0b011000001110,
-    0b101101101000,
-    0b110011110000,
-    0b111110011100,
-    0b010000011111,
-    0b001001111111,
-    0b001110110111,
-    0b100110100110,
-    0b011101100010,
-    0b101101010011,
-    0b001010101110,
-    0b101001100111,
-    0b110110101101,
-    0b101100000000,
-    0b100110101001,
-    0b100110101010,
-    0b100010110101,
-    0b101101010100,
-    0b001110111111,
-    0b101110101110,
-    0b111011001110,
-    0b011000110110,
-    0b000011111010,
-    0b011111100100,
-    0b111001100110,
-    0b010110010101,
-    0b001101011001,
-    0b011010010101,
-    0b110100101001,
-    0b010100100111,
-    0b111001100111,
-    0b111110101011,
-    0b000100110101,
-    0b010000100011,
-    0b000011100110,
-    0b010111101111,
-    0b010111001111,
-    0b001011110000,
-    0b110110010111,
-    0b010000100000,
-    0b011011010000,
-    0b011101100100,
-    0b111110010110,
-    0b000110100000,
-    0b111010101011,
-    0b100000110001,
-    0b001001111001,
-    0b000001010111,
-    0b011011100111,
-    0b010100010100,
-    0b011101101101,
-    0b000000101110,
-    0b100110111011,
-    0b001110011001,
-    0b000111001101,
-    0b011010011111,
-    0b100011111010,
-    0b010110110111,
-    0b000101111111,
-    0b100000001110,
-    0b010100000001,
-    0b010111100010,
-    0b100000110101,
-    0b000110100100,
-    0b100111100111,
-    0b101011010111,
-    0b100100110110,
-    0b111111000010,
-    0b101110101011,
-    0b111100000110,
-    0b010111110001,
-    0b100111000100,
-    0b111001011101,
-    0b000011010011,
-    0b101111010110,
-    0b011000101100,
-    0b111000000011,
-    0b111101101001,
-    0b101010111000,
-    0b010010110001,
-    0b010110101001,
-    0b100110001000,
-    0b011100111001,
-    0b010111011100,
-    0b001101110100,
-    0b010100100010,
-    0b111111111100,
-    0b010011000110,
-    0b000110100111,
-    0b011011011011,
-    0b111111011000,
-    0b000100010001,
-    0b011100001011,
-    0b100110010110,
-    0b010011110000,
-    0b010001111110,
-    0b000000011100,
-    0b111000001110,
-    0b000111000101,
-    0b011100011011,
-    0b000111101101,
-    0b010101111010,
-    0b100001111111,
-    0b011101101100,
-    0b000100000100,
-    0b100010000110,
-    0b101111110100,
-    0b011100010000,
-    0b110011100110,
-    0b011011000111,
-    0b111111000101,
-    0b101010001001,
-    0b010000111100,
-    0b111001101001,
-    0b000011100011,
-    0b101000011101,
-    0b000000101000,
-    0b111011110010,
-    0b001010111100,
-    0b101000100001,
-    0b011000001100,
-    0b001011111101,
-    0b101110100101,
-    0b010001110001,
-    0b000110101100,
-    0b001000010011,
-    0b011001011110,
-    0b011011101001,
-    0b000010111101,
-    0b011001111010,
-    0b101011000000,
-    0b100101011011,
-    0b100100010101,
-    0b011010000010,
-    0b111100110000,
-    0b010101000001,
-    0b101111100000,
-    0b100110100100,
-    0b010000101111,
-    0b010001100100,
-    0b000100011010,
-    0b110001011100,
-    0b100111100011,
-    0b101101101100,
-    0b101111011001,
-    0b010100010011,
-    0b001010001010,
-    0b011100011111,
-    0b110010111000,
-    0b011100111000,
-    0b100101000101,
-    0b110111110000,
-    0b110111010110,
-    0b111000111100,
-    0b110000011110,
-    0b110111100011,
-    0b010000010000,
-    0b101110111100,
-    0b100111001001,
-    0b010011011111,
-    0b100010110010,
-    0b001011010110,
-    0b110011000100,
-    0b111011001000,
-    0b101011100010,
-    0b001110001001,
-    0b010111001000,
-    0b110100110101,
-    0b101110101111,
-    0b111001111011,
-    0b010000011000,
-    0b110100000100,
-    0b100100110000,
-    0b010000100110,
-    0b100011001101,
-    0b011001100110,
-    0b010110000001,
-    0b111110001111,
-    0b000011111111,
-    0b110000000110,
-    0b000111010111,
-    0b010011111100,
-    0b001001000101,
-    0b100101100110,
-    0b000101000010,
-    0b101011100001,
-    0b100111010110,
-    0b111100101001,
-    0b000011110001,
-    0b101001100101,
-    0b110010000011,
-    0b001110100001,
-    0b110101101100,
-    0b111100110010,
-    0b100111100100,
-    0b011110011100,
-    0b011000011111,
-    0b000100100100,
-    0b011011100001,
-    0b100001011001,
-    0b010111111000,
-    0b001000011110,
-    0b111010010001,
-    0b111111001100,
-    0b011000110101,
-    0b001010100110,
-    0b110010000001,
-    0b011111000101,
-    0b001110000011,
-    0b110001110110,
-    0b000000011010,
-    0b000010000000,
-    0b001101011101,
-    0b110110011101,
-    0b000011011100,
-    0b110011000011,
-    0b100111010111,
-    0b100111000111,
-    0b110010110001,
-    0b100011110101,
-    0b101010101000,
-    0b111000001101,
-    0b111010100100,
-    0b110001000000,
-    0b011111001101,
-    0b001110010011,
-    0b110111011101,
-    0b110111101000,
-    0b100000111111,
-    0b101110111000,
-    0b000111111101,
-    0b101001111101,
-    0b100000110011,
-    0b011100011110,
-    0b111100110110,
-    0b111100001101,
-    0b101011011101,
-    0b001000101010,
-    0b011100100101,
-    0b100001001001,
-    0b100110011101,
-    0b110111110111,
-    0b101101100000,
-    0b001010100000,
-    0b010000111001,
-    0b000100110111,
-    0b000101100000,
-    0b110000010001,
-    0b101111111000,
-    0b110001011110,
-    0b001110010111,
-    0b110100100011,
-    0b111111101101,
-    0b000001100101,
-    0b100100110001,
-    0b011100001001,
-    0b111011000111,
-    0b100001001100,
-    0b000001111000,
-    0b011011111011,
-    0b001111100110,
-    0b001111111000,
-    0b000111110000,
-    0b001001000010,
-    0b110101000001,
-    0b001001110011,
-    0b110110010010,
-    0b010001000001,
-    0b001100011110,
-    0b001011100001,
-    0b000011110100,
-    0b100000100011,
-    0b110011100011,
-    0b110111100000,
-    0b010011110100,
-    0b110000001100,
-    0b011111111001,
-    0b110001001110,
-    0b110011001001,
-    0b001010101000,
-    0b000111110100,
-    0b100001000101,
-    0b111000110111,
-    0b010010001011,
-    0b000111011101,
-    0b010110001110,
-    0b011010001010,
-    0b010000001011,
-    0b011111110100,
-    0b111000010100,
-    0b001000011000,
-    0b101001111010,
-    0b110000001111,
-    0b010100101010,
-    0b000100011001,
-    0b100011110111,
-    0b001111101011,
-    0b000010101010,
-    0b010111110000,
-    0b111111000000,
-    0b001100000110,
-    0b111000111010,
-    0b010100100110,
-    0b001000010001,
-    0b000001111110,
-    0b000010111010,
-    0b110010011100,
-    0b001110000100,
-    0b010000110000,
-    0b011101111100,
-    0b011110101001,
-    0b111100010100,
-    0b101011111100,
-    0b001001001101,
-    0b000111110101,
-    0b010101110000,
-    0b010100001011,
-    0b010100011101,
-    0b011100111101,
-    0b010010000011,
-    0b010010111100,
-    0b100001100011,
-    0b010111010000,
-    0b010100110001,
-    0b100100010111,
-    0b010100011000,
-    0b111011111000,
-    0b110011010010,
-    0b100010100001,
-    0b111110111100,
-    0b000110101001,
-    0b110100001010,
-    0b001101100000,
-    0b111110001110,
-    0b011010100101,
-    0b001110111100,
-    0b101001100100,
-    0b000110110110,
-    0b000111011000,
-    0b000000010111,
-    0b110101011010,
-    0b110100010011,
-    0b101011010100,
-    0b101100010100,
-    0b110001110011,
-    0b111100100010,
-    0b011000001111,
-    0b010000111011,
-    0b011010100000,
-    0b010000000111,
-    0b000000001111,
-    0b000101000000,
-    0b110011100111,
-    0b000011111100,
-    0b011010001100,
-    0b000111000100,
-    0b101110011100,
-    0b011011011111,
-    0b110000100000,
-    0b101001000111,
-    0b101111111010,
-    0b111011011001,
-    0b111100010011,
-    0b100011000001,
-    0b011101100000,
-    0b111101001001,
-    0b101110101010,
-    0b001100101111,
-    0b001101001001,
-    0b010101001110,
-    0b101100110010,
-    0b000101111001,
-    0b101010101101,
-    0b111001010000,
-    0b001011111000,
-    0b000111110001,
-    0b100010001010,
-    0b000101100100,
-    0b010100111100,
-    0b101110001101,
-    0b100010010011,
-    0b000011010010,
-    0b100011110011,
-    0b110001101000,
-    0b011101011001,
-    0b100101010101,
-    0b010111011001,
-    0b110001010001,
-    0b011111011001,
-    0b010111000000,
-    0b011010110010,
-    0b110110100110,
-    0b001011011001,
-    0b100101111001,
-    0b000100100011,
-    0b000111001001,
-    0b000010000001,
-    0b100111000101,
-    0b010110111110,
-    0b111010101100,
-    0b110010100011,
-    0b101010001000,
-    0b111110100001,
-    0b100000101111,
-    0b110001111110,
-    0b010001101110,
-    0b010001001111,
-    0b101111000001,
-    0b101101010010,
-    0b001101001111,
-    0b100111011001,
-    0b010111101101,
-    0b111111001111,
-    0b110110111000,
-    0b011000000011,
-    0b000100111101,
-    0b001000000100,
-    0b101000100010,
-    0b011101001100,
-    0b100001001011,
-    0b100011100011,
-    0b001110110000,
-    0b110100001111,
-    0b111001111100,
-    0b001010001011,
-    0b100000000111,
-    0b101101110011,
-    0b010110011101,
-    0b111101011110,
-    0b100100100111,
-    0b011001001101,
-    0b010110110100,
-    0b000000111110,
-    0b001101110000,
-    0b101110000100,
-    0b100010111000,
-    0b110101010011,
-    0b101101001001,
-    0b100010000011,
-    0b011000000100,
-    0b001101010110,
-    0b010100011001,
-    0b100011101001,
-    0b011001010010,
-    0b010001100111,
-    0b111100011110,
-    0b010101110111,
-    0b101100110101,
-    0b100101011111,
-    0b100101101100,
-    0b101000001110,
-    0b011110110011,
-    0b011011001100,
-    0b101000111101,
-    0b001011110111,
-    0b100000010101,
-    0b111001111101,
-    0b111011010001,
-    0b111101001111,
-    0b010010011110,
-    0b000110010001,
-    0b111000000110,
-    0b110010101010,
-    0b001110110001,
-    0b100111011000,
-    0b111101001010,
-    0b011100101110,
-    0b001100100000,
-    0b100110010101,
-    0b011110111111,
-    0b111110110101,
-    0b000000111100,
-    0b101010011001,
-    0b100011110001,
-    0b001011011100,
-    0b101111100110,
-    0b001010100010,
-    0b001111000110,
-    0b100100101110,
-    0b101011001100,
-    0b010111100110,
-    0b110100100100,
-    0b100111001000,
-    0b010001011110,
-    0b110100111101,
-    0b101011110000,
-    0b110011111001,
-    0b000001100010,
-    0b011010101001,
-    0b010001111001,
-    0b001110100000,
-    0b101111011011,
-    0b010010100100,
-    0b100000001010,
-    0b010110011111,
-    0b010010100101,
-    0b100000010001,
-    0b111110111000,
-    0b000101110100,
-    0b010100000101,
-    0b101101010001,
-    0b001000111110,
-    0b011111101101,
-    0b110011010101,
-    0b110101111110,
-    0b011110101110,
-    0b110111101011,
-    0b111111111000,
-    0b011100001000,
-    0b011000000101,
-    0b011011110111,
-    0b011000001010,
-    0b001011010011,
-    0b101001010110,
-    0b111000110011,
-    0b111110011011,
-    0b110111011000,
-    0b101101011110,
-    0b000110010000,
-    0b111101101101,
-    0b001100011101,
-    0b110110010101,
-    0b100011011010,
-    0b010011001000,
-    0b100010111010,
-    0b111110010001,
-    0b001001011010,
-    0b010101001011,
-    0b100110001101,
-    0b000100101000,
-    0b001101111011,
-    0b110110010100,
-    0b011010000101,
-    0b011000010000,
-    0b110101110011,
-    0b100110110001,
-    0b000111100111,
-    0b000001010101,
-    0b001011110100,
-    0b010101000101,
-    0b110110010000,
-    0b101010111110,
-    0b111010011100,
-    0b010000001010,
-    0b001001100111,
-    0b000000100001,
-    0b110100001110,
-    0b100110011111,
-    0b111011110000,
-    0b000001110010,
-    0b111101111101,
-    0b010101100001,
-    0b100100000101,
-    0b100000010010,
-    0b100000001100,
-    0b011000011010,
-    0b000010010111,
-    0b110010100111,
-    0b101010000111,
-    0b001010010011,
-    0b110101001000,
-    0b101100000110,
-    0b001110101100,
-    0b001101110001,
-    0b101100001010,
-    0b111111110100,
-    0b010101010100,
-    0b101110111111,
-    0b000001111010,
-    0b110110000111,
-    0b000100000000,
-    0b011000010100,
-    0b011100011010,
-    0b100011111100,
-    0b001011011010,
-    0b111101101011,
-    0b011101000100,
-    0b011111010011,
-    0b111011010010,
-    0b110000001110,
-    0b010101010011,
-    0b100010110111,
-    0b111111000011,
-    0b001110001000,
-    0b110001000010,
-    0b001010111101,
-    0b000000101010,
-    0b100011101111,
-    0b100001000001,
-    0b000000110010,
-    0b101100011011,
-    0b011001000101,
-    0b111010110101,
-    0b010100111111,
-    0b100011111101,
-    0b010010110111,
-    0b110100000101,
-    0b101000100100,
-    0b010100111001,
-    0b100001000111,
-    0b111101000010,
-    0b000100000111,
-    0b100000011101,
-    0b101101001101,
-    0b000111101000,
-    0b011010010001,
-    0b001101011010,
-    0b110001011011,
-    0b000000011111,
-    0b111001000100,
-    0b100111101110,
-    0b110010010010,
-    0b101010101111,
-    0b000100001100,
-    0b000111000000,
-    0b001001000100,
-    0b010101110011,
-    0b001101111111,
-    0b111001010001,
-    0b100110101101,
-    0b100010011100,
-    0b010101010010,
-    0b011010101011,
-    0b000011110011,
-    0b101001001000,
-    0b101111100100,
-    0b011011001110,
-    0b001110010001,
-    0b110011001100,
-    0b011000111001,
-    0b110111000111,
-    0b100100001101,
-    0b100101111100,
-    0b101110111101,
-    0b001011111010,
-    0b100110010011,
-    0b100111111011,
-    0b000010010011,
-    0b100111101100,
-];
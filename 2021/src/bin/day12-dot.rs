@@ -0,0 +1,19 @@
+//! Draw the cave system from 2021 day 12's input, in Graphviz's DOT language.
+//!
+//! The input is read as by `aoc_utils::input::from_command_line`. The drawing
+//! is written to the file named by the `AOC_DOT` environment variable, or to
+//! standard output if that isn't set:
+//!
+//! ```text
+//! cargo run --bin day12-dot | dot -Tsvg > caves.svg
+//! ```
+
+fn main() -> anyhow::Result<()> {
+    let input = aoc_utils::input::from_command_line(2021, 12);
+    let dot = aoc2021::day12::draw_input(&input)?;
+    match aoc_utils::dot::requested() {
+        Some(path) => dot.save(&path)?,
+        None => print!("{dot}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,20 @@
+//! Show how much of the map 2021 day 15's part 2 search explored.
+//!
+//! The input is read as by `aoc_utils::input::from_command_line`. The heatmap
+//! is saved as an image to the file named by the `AOC_HEATMAP` environment
+//! variable, or drawn on standard output if that isn't set. This needs the
+//! `render` feature:
+//!
+//! ```text
+//! AOC_HEATMAP=search.png cargo run --features render --bin day15-heatmap
+//! ```
+
+fn main() -> anyhow::Result<()> {
+    let input = aoc_utils::input::from_command_line(2021, 15);
+    let heatmap = aoc2021::day15::heatmap(&input)?;
+    match aoc_utils::explore::requested() {
+        Some(path) => heatmap.save(&path)?,
+        None => print!("{heatmap}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+use std::num::ParseIntError;
+
+#[aoc_generator(day1)]
+fn input_generator(input: &str) -> Result<Vec<i32>, ParseIntError> {
+    input.lines().map(str::parse).collect()
+}
+
+#[aoc(day1, part1)]
+fn part1(depths: &Vec<i32>) -> usize {
+    depths.windows(2).filter(|w| w[1] > w[0]).count()
+}
+
+#[aoc(day1, part2)]
+fn part2(depths: &Vec<i32>) -> usize {
+    let mut increases = 0;
+    let mut window_sums = depths.windows(3).map(|w| w.iter().sum());
+    let first: i32 = window_sums.next().unwrap();
+    window_sums.fold(first, |prev, next| {
+        if next > prev {
+            increases += 1;
+        }
+        next
+    });
+    increases
+}
+
+solution! {
+    Day01 = day(2021, 1);
+    parse -> Vec<i32> = input_generator?;
+    part1 -> usize = part1;
+    part2 -> usize = part2;
+}
@@ -0,0 +1,47 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+pub const fn up(n: i32) -> (i32, i32) { ( -n, 0 ) }
+pub const fn down(n: i32) -> (i32, i32) { ( n, 0 ) }
+pub const fn forward(n: i32) -> (i32, i32) { ( 0, n ) }
+
+fn parse_command(line: &str) -> anyhow::Result<(i32, i32)> {
+    let (direction, n) = line.split_once(' ')
+        .ok_or_else(|| anyhow::anyhow!("missing space in command: {:?}", line))?;
+    let n = n.parse()?;
+    Ok(match direction {
+        "up" => up(n),
+        "down" => down(n),
+        "forward" => forward(n),
+        _ => anyhow::bail!("unrecognized direction: {:?}", direction),
+    })
+}
+
+#[aoc_generator(day2)]
+fn input_generator(input: &str) -> anyhow::Result<Vec<(i32, i32)>> {
+    input.lines().map(parse_command).collect()
+}
+
+#[aoc(day2, part1)]
+fn part1(commands: &Vec<(i32, i32)>) -> i32 {
+    let (v, h) = commands.iter().fold((0,0), |a, d| (a.0 + d.0, a.1 + d.1));
+    v * h
+}
+
+#[aoc(day2, part2)]
+fn part2(commands: &Vec<(i32, i32)>) -> i32 {
+    let mut pos = (0, 0);
+    let mut aim = 0;
+    for &(aim_change, forward) in commands {
+        aim += aim_change;
+        pos.0 += aim * forward;
+        pos.1 += forward;
+    }
+    pos.0 * pos.1
+}
+
+solution! {
+    Day02 = day(2021, 2);
+    parse -> Vec<(i32, i32)> = input_generator?;
+    part1 -> i32 = part1;
+    part2 -> i32 = part2;
+}
@@ -1,10 +1,12 @@
-#![allow(unused_variables)]
-
-mod input;
-
-fn main() {
-    part1(input::SAMPLE_1);
-    part2(input::INPUT_1);
+use aoc_runner_derive::{aoc, aoc_generator};
+use std::num::ParseIntError;
+
+#[aoc_generator(day3)]
+fn input_generator(input: &str) -> Result<Vec<u32>, ParseIntError> {
+    input
+        .lines()
+        .map(|line| u32::from_str_radix(line, 2))
+        .collect()
 }
 
 fn bit_counts(input: &[u32]) -> (usize, [usize; 32]) {
@@ -38,14 +40,13 @@ fn gamma_epsilon(width: usize, ones: &[usize], len: usize) -> (u32, u32) {
     (gamma, epsilon)
 }
 
-fn part1(input: &[u32]) {
+#[aoc(day3, part1)]
+fn part1(input: &Vec<u32>) -> u32 {
     let (width, ones) = bit_counts(input);
     let (gamma, epsilon) = gamma_epsilon(width, &ones, input.len());
-
-    println!("gamma = {:#b}, epsilon = {:#b}, product = {}", gamma, epsilon, gamma * epsilon);
+    gamma * epsilon
 }
 
-
 fn refine(sorted: &[u32], majority: bool, width: usize) -> u32 {
     let mut range = 0..sorted.len();
     for bit in (0..width).rev() {
@@ -74,16 +75,20 @@ fn refine(sorted: &[u32], majority: bool, width: usize) -> u32 {
     panic!("duplicate numbers?");
 }
 
-fn part2(input: &[u32]) {
+#[aoc(day3, part2)]
+fn part2(input: &Vec<u32>) -> u32 {
     let mut input = input.to_vec();
     input.sort();
     let width = 32 - input.last().unwrap().leading_zeros() as usize;
 
     let oxygen = refine(&input, true, width);
-    println!("oxygen = {}", oxygen);
-
     let co2 = refine(&input, false, width);
-    println!("co2 = {}", co2);
+    oxygen * co2
+}
 
-    println!("life support rating: {}", oxygen * co2);
+solution! {
+    Day03 = day(2021, 3);
+    parse -> Vec<u32> = input_generator?;
+    part1 -> u32 = part1;
+    part2 -> u32 = part2;
 }
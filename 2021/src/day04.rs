@@ -0,0 +1,195 @@
+use aoc_runner_derive::{aoc, aoc_generator};
+
+pub type Board<T> = [[T; 5]; 5];
+
+pub struct Input {
+    pub draws: Vec<u32>,
+    pub boards: Vec<Board<u32>>,
+}
+
+#[aoc_generator(day4)]
+fn parse_input(text: &str) -> anyhow::Result<Input> {
+    let mut sections = text.split("\n\n");
+    let draws = sections
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<_, _>>()?;
+
+    let boards = sections
+        .map(|section| {
+            let numbers = section
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<Vec<u32>, _>>()?;
+            if numbers.len() != 25 {
+                anyhow::bail!("bingo board doesn't have 25 numbers: {:?}", section);
+            }
+            let mut board = Board::<u32>::default();
+            for (i, n) in numbers.into_iter().enumerate() {
+                board[i / 5][i % 5] = n;
+            }
+            Ok(board)
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    Ok(Input { draws, boards })
+}
+
+#[aoc(day4, part1)]
+fn part1(input: &Input) -> u32 {
+    let mut marks: Vec<Board<bool>> = input
+        .boards
+        .iter()
+        .map(|_| Board::<bool>::default())
+        .collect();
+
+    for &n in &input.draws {
+        let mut best = 0;
+        for (board, marks) in input.boards.iter().zip(marks.iter_mut()) {
+            let score = mark(n, board, marks);
+            if score > best {
+                best = score;
+            }
+        }
+
+        if best > 0 {
+            return best;
+        }
+    }
+
+    panic!("no board wins");
+}
+
+#[aoc(day4, part2)]
+fn part2(input: &Input) -> u32 {
+    let mut marks = vec![Board::<bool>::default(); input.boards.len()];
+    let mut scores = vec![0; input.boards.len()];
+
+    let mut last = 0;
+    for &n in &input.draws {
+        for ((board, marks), score) in input.boards.iter().zip(marks.iter_mut()).zip(scores.iter_mut()) {
+            if *score == 0 {
+                *score = mark(n, board, marks);
+                if *score > 0 {
+                    last = *score;
+                }
+            }
+        }
+    }
+
+    last
+}
+
+fn mark(n: u32, board: &Board<u32>, marks: &mut Board<bool>) -> u32 {
+    let mut marked_any = false;
+    for row in 0..5 {
+        for col in 0..5 {
+            if board[row][col] == n {
+                marks[row][col] = true;
+                marked_any = true;
+            }
+        }
+    }
+
+    if marked_any {
+        for row in 0..5 {
+            for col in 0..5 {
+                if (0..5).all(|j| marks[row][j]) || (0..5).all(|i| marks[i][col]) {
+                    let mut sum = 0;
+                    for row in 0..5 {
+                        for col in 0..5 {
+                            if !marks[row][col] {
+                                sum += board[row][col];
+                            }
+                        }
+                    }
+                    return sum * n;
+                }
+            }
+        }
+    }
+
+    0
+}
+
+solution! {
+    Day04 = day(2021, 4);
+    parse -> Input = parse_input?;
+    part1 -> u32 = part1;
+    part2 -> u32 = part2;
+}
+
+#[test]
+fn perry_parse() -> anyhow::Result<()> {
+    use std::io;
+    use std::io::prelude::*;
+
+    let input = r#"
+76 82  2 92 53
+74 33  8 89  3
+80 27 72 26 91
+30 83  7 16  4
+20 56 48  5 13
+
+0  82  2 92 53
+74 33  8 89  3
+80 27 72 26 91
+30 83  7 16  4
+20 56 48  5 13
+"#;
+
+    let reader = io::BufReader::new(input.as_bytes());
+    let lines: Vec<String> = reader.lines().collect::<Result<_, _>>()?;
+    let mut iter = lines.iter();
+
+    // skip blank line
+    iter.next().ok_or(anyhow::anyhow!("no blank line?"))?;
+
+    // Use `by_ref` so we can use iter again after we're done
+    // parsing this board.
+    let board: Vec<Vec<u32>> = iter
+        .by_ref()
+        .take(5)
+        .map(|line| {
+            line.split_whitespace()
+                .map(str::parse)
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    // skip blank line
+    iter.next().ok_or(anyhow::anyhow!("no blank line?"))?;
+
+    let board2: Vec<Vec<u32>> = iter
+        .by_ref()
+        .take(5)
+        .map(|line| {
+            line.split_whitespace()
+                .map(str::parse)
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    assert_eq!(board,
+               vec![
+                   vec![76, 82,  2, 92, 53],
+                   vec![74, 33,  8, 89,  3],
+                   vec![80, 27, 72, 26, 91],
+                   vec![30, 83,  7, 16,  4],
+                   vec![20, 56, 48,  5, 13],
+               ]);
+
+    assert_eq!(board2,
+               vec![
+                   vec![0,  82,  2, 92, 53],
+                   vec![74, 33,  8, 89,  3],
+                   vec![80, 27, 72, 26, 91],
+                   vec![30, 83,  7, 16,  4],
+                   vec![20, 56, 48,  5, 13],
+               ]);
+
+    Ok(())
+}
@@ -485,3 +485,10 @@ fn test_day5_part2_sample() {
     let lines = sample_input();
     assert_eq!(sparse_all_lines(&lines), 12);
 }
+
+solution! {
+    Day05 = day(2021, 5);
+    parse -> Vec<Line> = input_generator?;
+    part1 -> i32 = part1_sparse;
+    part2 -> i32 = part2;
+}
@@ -76,3 +76,10 @@ fn day6_part2(cohorts: &Cohorts) -> usize {
     series(*cohorts).nth(256).map(pop).unwrap()
 }
 
+
+solution! {
+    Day06 = day(2021, 6);
+    parse -> Cohorts = input_generator?;
+    part1 -> usize = day6_part1;
+    part2 -> usize = day6_part2;
+}
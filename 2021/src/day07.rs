@@ -64,3 +64,10 @@ fn part2(crabs: &Vec<i32>) -> i32 {
     part2_mean(crabs)
 }
 
+
+solution! {
+    Day07 = day(2021, 7);
+    parse -> Vec<i32> = part1_input?;
+    part1 -> i32 = part1;
+    part2 -> i32 = part2;
+}
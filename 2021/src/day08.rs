@@ -179,3 +179,10 @@ fn part2(input: &Vec<Entry>) -> usize {
 fn test_part2() {
     assert_eq!(part2(&sample2()), 61229);
 }
+
+solution! {
+    Day08 = day(2021, 8);
+    parse -> Vec<Entry> = part1_input?;
+    part1 -> usize = part1;
+    part2 -> usize = part2;
+}
@@ -115,3 +115,10 @@ fn part2(input: &Array2<i32>) -> usize {
 fn test_part2() {
     assert_eq!(part2(&sample()), 1134);
 }
+
+solution! {
+    Day09 = day(2021, 9);
+    parse -> Array2<i32> = generate;
+    part1 -> i32 = part1;
+    part2 -> usize = part2;
+}
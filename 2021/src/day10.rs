@@ -121,3 +121,10 @@ fn part2(input: &Vec<String>) -> usize {
 fn test_part2() {
     assert_eq!(part2(&sample()), 288957);
 }
+
+solution! {
+    Day10 = day(2021, 10);
+    parse -> Vec<String> = generate;
+    part1 -> usize = part1;
+    part2 -> usize = part2;
+}
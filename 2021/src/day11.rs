@@ -112,3 +112,10 @@ fn part2(input: &Array2<u32>) -> usize {
     let mut input = input.clone();
     (1..1000).find(|_| step(&mut input) == 100).unwrap()
 }
+
+solution! {
+    Day11 = day(2021, 11);
+    parse -> Array2<u32> = generate;
+    part1 -> usize = part1;
+    part2 -> usize = part2;
+}
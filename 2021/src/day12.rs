@@ -1,4 +1,5 @@
 use aoc_runner_derive::{aoc, aoc_generator};
+use aoc_utils::dot::Dot;
 use anyhow::{anyhow, Result};
 use std::collections::HashMap;
 
@@ -144,3 +145,42 @@ fn test_part2() {
     assert_eq!(part2(&bigger_sample()), 103);
     assert_eq!(part2(&even_larger_sample()), 3509);
 }
+
+/// Draw the cave system, with big caves as boxes.
+fn draw(graph: &Graph) -> Dot<usize> {
+    let mut dot = Dot::undirected();
+    for (id, node) in graph.iter().enumerate() {
+        let attributes = dot.node(id).label(&node.name);
+        if node.big {
+            attributes.set("shape", "box");
+        }
+    }
+    for (id, node) in graph.iter().enumerate() {
+        for &to in &node.out {
+            dot.edge(id, to);
+        }
+    }
+    dot
+}
+
+/// Parse `input` and draw its cave system.
+///
+/// The `day12-dot` binary uses this to save the drawing.
+pub fn draw_input(input: &str) -> Result<Dot<usize>> {
+    Ok(draw(&generate(crate::trim_input(input))?))
+}
+
+#[test]
+fn test_draw() {
+    let text = draw(&small_sample()).to_string();
+    assert!(text.contains("  n0 [label=\"start\"];\n"));
+    assert!(text.contains("  n2 [label=\"A\", shape=\"box\"];\n"));
+    assert_eq!(text.matches(" -- ").count(), 7);
+}
+
+solution! {
+    Day12 = day(2021, 12);
+    parse -> Graph = generate?;
+    part1 -> usize = part1;
+    part2 -> usize = part2;
+}
@@ -1,8 +1,8 @@
 use aoc_runner_derive::{aoc, aoc_generator};
-use anyhow::{anyhow, Result};
-use std::cmp;
+use aoc_utils::ocr;
+use aoc_utils::parse::{Cursor, Parser, Sections, literal, signed};
+use anyhow::{Context, Result};
 use std::collections::HashSet;
-use std::str::FromStr;
 
 #[derive(Debug, PartialEq)]
 struct Instructions {
@@ -36,28 +36,19 @@ impl Fold {
 #[aoc_generator(day13, part1, jimb)]
 #[aoc_generator(day13, part2, jimb)]
 fn generate(input: &str) -> Result<Instructions> {
-    let mut lines = input.lines();
-    let points: Vec<_> = lines
-        .by_ref()
-        .take_while(|l| !l.trim().is_empty())
-        .map(|l| {
-            let (x,y) = l.split_once(',')
-                .ok_or_else(|| anyhow!("dot is missing ',' separator: {:?}", l))?;
-            Ok((i32::from_str(x.trim())?, i32::from_str(y.trim())?))
-        })
-        .collect::<Result<_>>()?;
-
-    let folds: Vec<_> = lines
-        .map(|l| {
-            if let Some(("", rest)) = l.split_once("fold along x=") {
-                Ok(Fold::Left(i32::from_str(rest.trim())?))
-            } else if let Some(("", rest)) = l.split_once("fold along y=") {
-                Ok(Fold::Up(i32::from_str(rest.trim())?))
-            } else {
-                Err(anyhow!("Bad fold instruction: {:?}", l))
-            }
-        })
-        .collect::<Result<_>>()?;
+    let mut sections = Sections::new(input);
+    let points = sections.parse_next_lines(|cursor: &mut Cursor| {
+        let x = signed().parse(cursor)?;
+        literal(",").parse(cursor)?;
+        Ok((x, signed().parse(cursor)?))
+    }).context("bad dot")?;
+    let folds = sections.parse_next_lines(|cursor: &mut Cursor| {
+        let fold = literal("fold along x=").map(|()| Fold::Left as fn(i32) -> Fold)
+            .or(literal("fold along y=").map(|()| Fold::Up as fn(i32) -> Fold))
+            .parse(cursor)?;
+        Ok(fold(signed().parse(cursor)?))
+    }).context("bad fold instruction")?;
+    sections.finish()?;
 
     Ok(Instructions { points, folds })
 }
@@ -109,19 +100,18 @@ fn test_part1() {
 }
 
 #[aoc(day13, part2, jimb)]
-fn part2(input: &Instructions) -> &'static str {
+fn part2(input: &Instructions) -> String {
     let folded = input.points
         .iter()
         .map(|&pt| input.folds.iter().fold(pt, |pt, fold| fold.apply(pt)))
         .collect::<HashSet<(i32, i32)>>();
 
-    let extent = folded.iter().fold((0,0), |(w, h), &(x, y)| (cmp::max(x, w), cmp::max(y, h)));
-    for y in 0 ..= extent.1 {
-        for x in 0 ..= extent.0 {
-            print!("{} ", if folded.contains(&(x, y)) { "#" } else { " " });
-        }
-        println!();
-    }
+    ocr::from_points(folded).unwrap_or_else(|err| panic!("couldn't read the code: {}", err))
+}
 
-    "see output"
+solution! {
+    Day13 = day(2021, 13);
+    parse -> Instructions = generate?;
+    part1 -> usize = part1;
+    part2 -> String = part2;
 }
@@ -238,3 +238,10 @@ fn part2(input: &Problem) -> usize {
     most - least
 }
 
+
+solution! {
+    Day14 = day(2021, 14);
+    parse -> Problem = generate?;
+    part1 -> usize = part1;
+    part2 -> usize = part2;
+}
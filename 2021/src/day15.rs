@@ -1,6 +1,7 @@
 use aoc_runner_derive::{aoc, aoc_generator};
 use anyhow::{anyhow, Result};
-use crate::astar_weighted::astar_weighted;
+use aoc_utils::astar_weighted::{astar_weighted, Edge};
+use aoc_utils::explore::{self, Heatmap, Measure};
 use crate::{compass, around};
 use ndarray::Array2;
 #[cfg(test)]
@@ -64,15 +65,19 @@ fn test_part1() {
                40);
 }
 
-#[aoc(day15, part2, jimb)]
-fn part2(input: &Array2<u32>) -> usize {
+/// Return a search of the full map for part 2, which is `input` tiled five
+/// times in each direction, and the position of the map's bottom right
+/// corner.
+fn full_map_search(input: &Array2<u32>)
+                   -> ([usize; 2], impl Iterator<Item = Edge<[usize; 2], usize>> + '_)
+{
     let tile_size = input.dim();
     let size = (tile_size.0 * 5, tile_size.1 * 5);
     let end = [size.0 - 1, size.1 - 1];
 
-    let mut search = astar_weighted([0,0], |&p: &[usize; 2]| {
+    let search = astar_weighted([0,0], move |&p: &[usize; 2]| {
         around(p, size, compass())
-            .map(|n| {
+            .map(move |n| {
                 let reduced = [n[0] % tile_size.0, n[1] % tile_size.1];
                 let offset = n[0] / tile_size.0 + n[1] / tile_size.1;
                 let weight = ((input[reduced] as usize + offset) - 1) % 9 + 1;
@@ -80,13 +85,39 @@ fn part2(input: &Array2<u32>) -> usize {
             })
     });
 
+    (end, search)
+}
+
+#[aoc(day15, part2, jimb)]
+fn part2(input: &Array2<u32>) -> usize {
+    let (end, mut search) = full_map_search(input);
     search.find(|edge| edge.to == end)
         .expect("Didn't find any path to end")
         .path_weight
 }
 
+/// Parse `input`, and show the order in which part 2's search reached each
+/// position, to see how much of the map it had to explore.
+///
+/// The `day15-heatmap` binary uses this to save the heatmap.
+pub fn heatmap(input: &str) -> Result<Heatmap> {
+    let input = generate(crate::trim_input(input))?;
+    let (end, search) = full_map_search(&input);
+    let mut search = explore::record(search);
+    search.find(|edge| edge.to == end)
+        .ok_or_else(|| anyhow!("Didn't find any path to end"))?;
+    Ok(search.heatmap(|&[row, col]| (row, col), Measure::Order))
+}
+
 #[test]
 fn test_part2() {
     assert_eq!(part2(&generate(include_str!("sample/day15")).unwrap()),
                315);
 }
+
+solution! {
+    Day15 = day(2021, 15);
+    parse -> Array2<u32> = generate?;
+    part1 -> usize = part1;
+    part2 -> usize = part2;
+}
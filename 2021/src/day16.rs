@@ -438,3 +438,18 @@ fn part2_faster(input: &Words) -> u64 {
     let mut words = input.clone();
     evaluate_faster(&mut Vec::with_capacity(1000), &mut words)
 }
+
+solution! {
+    Day16 = day(2021, 16);
+    parse -> Words = generate?;
+    part1 -> u64 = part1;
+    part2 -> u64 = part2;
+}
+
+solution! {
+    /// The faster implementation, for benchmarking against `day`.
+    Day16Faster = faster(2021, 16, "jimb_faster");
+    parse -> Words = generate?;
+    part1 -> u64 = part1_faster;
+    part2 -> u64 = part2_faster;
+}
@@ -90,3 +90,10 @@ fn test_part2() {
     assert_eq!(part2(&Target { x: 20..=30, y: -10 ..= -5 }),
                112);
 }
+
+solution! {
+    Day17 = day(2021, 17);
+    parse -> Target = generator;
+    part1 -> i32 = part1;
+    part2 -> usize = part2;
+}
@@ -343,3 +343,10 @@ fn part2(input: &Vec<Num>) -> u64 {
         .max()
         .unwrap()
 }
+
+solution! {
+    Day18 = day(2021, 18);
+    parse -> Vec<Num> = generator?;
+    part1 -> u64 = part1;
+    part2 -> u64 = part2;
+}
@@ -507,3 +507,10 @@ fn part1(input: &Vec<String>) -> usize {
     let sum = sum_list(input).unwrap();
     sum.magnitude()
 }
+
+solution! {
+    /// This implementation, for benchmarking against `day18::day`.
+    Day18Heap = day(2021, 18, "jimb_heap");
+    parse -> Vec<String> = generator;
+    part1 -> usize = part1;
+}
@@ -297,3 +297,11 @@ fn part2(input: &Vec<String>) -> u64 {
         .max()
         .unwrap()
 }
+
+solution! {
+    /// This implementation, for benchmarking against `day18::day`.
+    Day18Sed = day(2021, 18, "jimb_sed");
+    parse -> Vec<String> = generator;
+    part1 -> u64 = part1;
+    part2 -> u64 = part2;
+}
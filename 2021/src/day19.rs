@@ -533,3 +533,10 @@ fn part2(problem: &Problem) -> i32 {
 fn test_part2() {
     assert_eq!(part2(&sample()), 3621);
 }
+
+solution! {
+    Day19 = day(2021, 19);
+    parse -> Problem = generator?;
+    part1 -> usize = part1;
+    part2 -> i32 = part2;
+}
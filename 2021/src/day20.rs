@@ -248,3 +248,10 @@ fn part2((rule, state): &(Rule, State)) -> usize {
 
     state.inside.iter().filter(|&&pixel| pixel).count()
 }
+
+solution! {
+    Day20 = day(2021, 20);
+    parse -> (Rule, State) = generator?;
+    part1 -> usize = part1;
+    part2 -> usize = part2;
+}
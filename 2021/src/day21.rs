@@ -212,3 +212,10 @@ impl cmp::PartialOrd for Enqueued {
         Some(self.cmp(other))
     }
 }
+
+solution! {
+    Day21 = day(2021, 21);
+    parse -> (Pos, Pos) = generator?;
+    part1 -> u32 = part1;
+    part2 -> u64 = part2;
+}
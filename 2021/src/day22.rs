@@ -241,3 +241,10 @@ fn test_part1() {
 fn part2(input: &Vec<Command>) -> i64 {
     volume(&input)
 }
+
+solution! {
+    Day22 = day(2021, 22);
+    parse -> Vec<Command> = generator?;
+    part1 -> i64 = part1;
+    part2 -> i64 = part2;
+}
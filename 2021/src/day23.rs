@@ -1,5 +1,6 @@
 #![allow(unused_imports, dead_code)]
 use aoc_runner_derive::{aoc, aoc_generator};
+use crate::day23_part2;
 use anyhow::{anyhow, bail, Result};
 use std::{cmp, fmt, iter};
 use crate::astar_weighted::astar_weighted;
@@ -331,3 +332,16 @@ fn test_pod_moves() {
     assert_eq!(pod_moves(&map, 2, pos.pos[2]), vec![8, 9, 11, 13, 17, 18]);
     assert_eq!(pod_moves(&map, 6, pos.pos[6]), vec![7, 17, 18]);
 }
+
+/// Part two folds the diagram out and needs its own representation, so the
+/// runner's input holds both.
+fn generate_both(input: &str) -> Result<(Positions, day23_part2::Positions)> {
+    Ok((generator(input)?, day23_part2::generator(input)?))
+}
+
+solution! {
+    Day23 = day(2021, 23);
+    parse -> (Positions, day23_part2::Positions) = generate_both?;
+    part1 -> usize = |input| part1(&input.0);
+    part2 -> usize = |input| day23_part2::part2(&input.1);
+}
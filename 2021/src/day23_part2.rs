@@ -17,7 +17,7 @@ use crate::astar_weighted::astar_weighted;
 // amphipod can go in either spot). So amphipods 0 and 1 are Amber.
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
-struct Positions {
+pub(crate) struct Positions {
     pos: [u8; PODS],
 }
 
@@ -30,7 +30,7 @@ struct Map {
 }
 
 #[aoc_generator(day23, part2, jimb)]
-fn generator(input: &str) -> Result<Positions> {
+pub(crate) fn generator(input: &str) -> Result<Positions> {
     let mut lines = input.lines().collect::<Vec<_>>();
 
     lines.insert(3, "  #D#C#B#A#");
@@ -250,7 +250,7 @@ fn pod_moves(map: &Map, pod: u8, start: usize) -> Vec<usize> {
 }
 
 #[aoc(day23, part2, jimb)]
-fn part2(input: &Positions) -> usize {
+pub(crate) fn part2(input: &Positions) -> usize {
     //let mut predecessors = HashMap::new();
 
     let states = astar_weighted(input.clone(), |positions| {
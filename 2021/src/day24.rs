@@ -6,8 +6,7 @@ use std::ops::RangeInclusive;
 
 macro_rules! log {
     { $format:literal $( , $arg:expr )* } => {
-        ()
-//        println!( $format $( , $arg )* )
+        log::trace!( $format $( , $arg )* )
     }
 }
 
@@ -447,3 +446,10 @@ impl fmt::Display for Indent {
         Ok(())
     }
 }
+
+solution! {
+    Day24 = day(2021, 24);
+    parse -> Problem = generate;
+    part1 -> i64 = |input| part1(input);
+    part2 -> i64 = |input| part2(input);
+}
@@ -170,3 +170,9 @@ impl fmt::Display for Pretty<&'_ Array2<Spot>> {
         Ok(())
     }
 }
+
+solution! {
+    Day25 = day(2021, 25);
+    parse -> Array2<Spot> = generate?;
+    part1 -> usize = part1;
+}
@@ -1,9 +1,110 @@
 use aoc_runner_derive::aoc_lib;
+use aoc_utils::solution::Day;
+
+/// Implement `aoc_utils::solution::Solution` for a day, using its `cargo aoc`
+/// generator and part functions, and define a function returning its `Day`.
+///
+/// ```ignore
+/// solution! {
+///     /// Doc comments and attributes apply to the function.
+///     Day05 = day(2021, 5);
+///     parse -> Vec<Line> = input_generator?;
+///     part1 -> i32 = part1_sparse;
+///     part2 -> i32 = part2;
+/// }
+/// ```
+///
+/// This defines `Day05`, and `fn day() -> Day`. A third argument in the
+/// parentheses names a variant, as for `Day::as_variant`.
+///
+/// The generator is passed the input with trailing newlines removed; see
+/// `trim_input`. Write `generator?` if it returns a `Result`, and just
+/// `generator` if it can't fail. Parts may be any expression that coerces to
+/// `fn(&Input) -> Answer`. Leave out `part2` for puzzles with only one part.
+macro_rules! solution {
+    (
+        $(#[$attr:meta])*
+        $name:ident = $constructor:ident($year:expr, $day:expr $(, $variant:expr)?);
+        parse -> $input:ty = $parse:ident?;
+        $($parts:tt)*
+    ) => {
+        solution! {
+            @impl $(#[$attr])*
+            $name = $constructor($year, $day $(, $variant)?);
+            parse -> $input = |input| $parse(input).map_err(anyhow::Error::from);
+            $($parts)*
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $name:ident = $constructor:ident($year:expr, $day:expr $(, $variant:expr)?);
+        parse -> $input:ty = $parse:ident;
+        $($parts:tt)*
+    ) => {
+        solution! {
+            @impl $(#[$attr])*
+            $name = $constructor($year, $day $(, $variant)?);
+            parse -> $input = |input| Ok($parse(input));
+            $($parts)*
+        }
+    };
+    (
+        @impl $(#[$attr:meta])*
+        $name:ident = $constructor:ident($year:expr, $day:expr $(, $variant:expr)?);
+        parse -> $input:ty = $parse:expr;
+        part1 -> $answer1:ty = $part1:expr;
+        $(part2 -> $answer2:ty = $part2:expr;)?
+    ) => {
+        struct $name;
+
+        impl aoc_utils::solution::Solution for $name {
+            type Input = $input;
+            type Answer1 = $answer1;
+            type Answer2 = solution!(@answer2 $($answer2)?);
+
+            fn parse(input: &str) -> anyhow::Result<$input> {
+                let parse: fn(&str) -> anyhow::Result<$input> = $parse;
+                parse($crate::trim_input(input))
+            }
+
+            fn part1(input: &$input) -> $answer1 {
+                let part1: fn(&$input) -> $answer1 = $part1;
+                part1(input)
+            }
+
+            fn part2(_input: &$input) -> Self::Answer2 {
+                solution!(@part2 $name, _input: $input $(, $answer2 = $part2)?)
+            }
+        }
+
+        $(#[$attr])*
+        pub fn $constructor() -> aoc_utils::solution::Day {
+            let day = aoc_utils::solution::Day::new::<$name>($year, $day);
+            $(let day = day.as_variant($variant);)?
+            solution!(@day day $($answer2)?)
+        }
+    };
+    (@answer2) => { std::convert::Infallible };
+    (@answer2 $answer2:ty) => { $answer2 };
+    (@part2 $name:ident, $arg:ident: $input:ty) => {
+        unreachable!("{} has no part two", stringify!($name))
+    };
+    (@part2 $name:ident, $arg:ident: $input:ty, $answer2:ty = $part2:expr) => {{
+        let part2: fn(&$input) -> $answer2 = $part2;
+        part2($arg)
+    }};
+    (@day $day:ident) => { $day.without_part2() };
+    (@day $day:ident $answer2:ty) => { $day };
+}
 
 pub mod index;
 pub mod astar_weighted;
 pub mod linear;
 
+mod day01;
+mod day02;
+mod day03;
+mod day04;
 mod day05;
 mod day06;
 mod day07;
@@ -11,10 +112,10 @@ mod day08;
 mod day09;
 mod day10;
 mod day11;
-mod day12;
+pub mod day12;
 mod day13;
 mod day14;
-mod day15;
+pub mod day15;
 mod day16;
 mod day17;
 mod day18;
@@ -93,4 +194,51 @@ pub fn around<I>(p: [usize; 2], bounds: (usize, usize), offsets: I) -> impl Iter
     })
 }
 
+/// Return the solutions for this year that implement `aoc_utils::solution::Solution`.
+pub fn solutions() -> Vec<Day> {
+    vec![
+        day01::day(),
+        day02::day(),
+        day03::day(),
+        day04::day(),
+        day05::day(),
+        day06::day(),
+        day07::day(),
+        day08::day(),
+        day09::day(),
+        day10::day(),
+        day11::day(),
+        day12::day(),
+        day13::day(),
+        day14::day(),
+        day15::day(),
+        day16::day(),
+        day16::faster(),
+        day17::day(),
+        day18::day(),
+        day18_sed::day(),
+        day18_heap::day(),
+        day19::day(),
+        day20::day(),
+        day21::day(),
+        day22::day(),
+        day23::day(),
+        day24::day(),
+        day25::day(),
+    ]
+}
+
+/// Strip trailing newlines from `input`, as `cargo aoc` does before passing it
+/// to our generators.
+fn trim_input(input: &str) -> &str {
+    input.trim_end_matches('\n')
+}
+
+/// Check `solutions()` against each sample in `src/sample` that has a
+/// `.expected` file; see `aoc_utils::samples`.
+#[cfg(test)]
+mod samples {
+    aoc_utils::sample_tests!();
+}
+
 aoc_lib! { year = 2021 }
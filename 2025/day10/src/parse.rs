@@ -1,77 +1,66 @@
 use std::str::FromStr;
 
 use crate::{Machine, Problem};
-use anyhow::bail;
+use aoc_utils::parse::{
+    self, Cursor, Error, Parser, bracketed, literal, many, separated, spaces, unsigned,
+};
 
 impl FromStr for Machine {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let Some(rest) = s.strip_prefix('[') else {
-            bail!("Missing '['");
-        };
-        let Some((lights, rest)) = rest.split_once(']') else {
-            bail!("Missing ']'");
-        };
-        let lights = lights
-            .chars()
-            .rev()
-            .map(|ch| match ch {
-                '#' => 1,
-                '.' => 0,
-                _ => panic!("Bad light character: {ch:?}"),
-            })
-            .fold(0, |acc, bit| acc << 1 | bit);
-        let Some((buttons, rest)) = rest.split_once('{') else {
-            bail!("Missing '{{'");
-        };
-        let buttons: Vec<u64> = buttons
-            .split_whitespace()
-            .map(|button| {
-                let Some(rest) = button.strip_prefix('(') else {
-                    panic!("Button doesn't start with '('");
-                };
-                let Some(lights) = rest.strip_suffix(')') else {
-                    panic!("Button doesn't end with ')'");
-                };
-                comma_list::<u32>(lights).fold(0, |acc, num| acc | (1 << num))
-            })
-            .collect();
-        let mut buttons_by_size = buttons.clone();
-        buttons_by_size.sort_by_key(|&button| button.count_ones());
-        buttons_by_size.reverse();
-        let Some(joltages) = rest.trim_end().strip_suffix('}') else {
-            bail!("Missing '}}'");
-        };
-        let joltages = comma_list::<u64>(joltages).collect();
-        Ok(Machine {
-            lights,
-            buttons,
-            buttons_by_size,
-            joltages,
-        })
+        Ok(parse::parse_all(s, machine)?)
     }
 }
 
-fn comma_list<T>(s: &str) -> impl Iterator<Item = T>
+fn machine(cursor: &mut Cursor) -> Result<Machine, Error> {
+    let lights = bracketed("[", many(light), "]")
+        .context("indicator lights")
+        .parse(cursor)?;
+    let lights = lights.iter().rev().fold(0, |acc, bit| (acc << 1) | bit);
+    spaces().parse(cursor)?;
+    let buttons: Vec<u64> = many(|cursor: &mut Cursor| {
+        let button = bracketed("(", comma_list::<u32>, ")")
+            .context("button")
+            .parse(cursor)?;
+        spaces().parse(cursor)?;
+        Ok(button.iter().fold(0, |acc, num| acc | (1 << num)))
+    })
+    .parse(cursor)?;
+    let mut buttons_by_size = buttons.clone();
+    buttons_by_size.sort_by_key(|&button| button.count_ones());
+    buttons_by_size.reverse();
+    let joltages = bracketed("{", comma_list::<u64>, "}")
+        .context("joltages")
+        .parse(cursor)?;
+    Ok(Machine {
+        lights,
+        buttons,
+        buttons_by_size,
+        joltages,
+    })
+}
+
+fn light(cursor: &mut Cursor) -> Result<u64, Error> {
+    literal("#")
+        .map(|()| 1)
+        .or(literal(".").map(|()| 0))
+        .parse(cursor)
+}
+
+fn comma_list<T>(cursor: &mut Cursor) -> Result<Vec<T>, Error>
 where
     T: FromStr,
     T::Err: std::fmt::Display,
 {
-    s.split(',').map(|num| {
-        T::from_str(num).unwrap_or_else(|err| panic!("Bad comma list element {num:?}: {err}"))
-    })
+    separated(unsigned(), literal(",")).parse(cursor)
 }
 
 impl FromStr for Problem {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let machines = s
-            .lines()
-            .filter(|line| !line.trim().is_empty())
-            .map(|line| Machine::from_str(line))
-            .collect::<Result<_, _>>()?;
+        let machines = parse::parse_lines(s, machine)?;
         Ok(Problem { machines })
     }
 }
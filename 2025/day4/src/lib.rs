@@ -0,0 +1,199 @@
+use anyhow::bail;
+use aoc_utils::solution::{Day, Solution};
+use std::{borrow::Cow, ops};
+
+#[derive(Clone)]
+struct Problem<'a> {
+    map: Cow<'a, [u8]>,
+    width: isize,
+    height: isize,
+}
+
+impl ops::Index<(isize, isize)> for Problem<'_> {
+    type Output = u8;
+
+    fn index(&self, (x, y): (isize, isize)) -> &Self::Output {
+        if 0 <= x && x < self.width && 0 <= y && y < self.height {
+            self.map.get((y * self.width + x) as usize).unwrap()
+        } else {
+            &b'.'
+        }
+    }
+}
+
+impl ops::IndexMut<(isize, isize)> for Problem<'_> {
+    fn index_mut(&mut self, (x, y): (isize, isize)) -> &mut Self::Output {
+        if 0 <= x && x < self.width && 0 <= y && y < self.height {
+            self.map
+                .to_mut()
+                .get_mut((y * self.width + x) as usize)
+                .unwrap()
+        } else {
+            panic!("Problem coords out of range: {:?}", (x, y));
+        }
+    }
+}
+
+fn offsets() -> impl Iterator<Item = (isize, isize)> {
+    const STEPS: [isize; 3] = [-1, 0, 1];
+    STEPS
+        .into_iter()
+        .flat_map(|dx| STEPS.into_iter().map(move |dy| (dx, dy)))
+        .filter(|&(dx, dy)| dx != 0 || dy != 0)
+}
+
+impl Problem<'static> {
+    /// Parse a map of paper rolls, one row per line.
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut map = Vec::with_capacity(width * rows.len());
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() != width {
+                bail!(
+                    "line {} is {} characters long, not {width}",
+                    i + 1,
+                    row.len()
+                );
+            }
+            if let Some(&ch) = row.iter().find(|&&ch| ch != b'.' && ch != b'@') {
+                bail!("line {}: unexpected character {:?}", i + 1, ch as char);
+            }
+            map.extend_from_slice(row);
+        }
+        Ok(Problem {
+            map: Cow::Owned(map),
+            width: width as isize,
+            height: rows.len() as isize,
+        })
+    }
+}
+
+impl Problem<'_> {
+    fn coords(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+        (0..self.width).flat_map(|x| (0..self.height).map(move |y| (x, y)))
+    }
+
+    fn rolls(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+        self.coords().filter(|&(x, y)| self[(x, y)] == b'@')
+    }
+
+    fn movable(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+        self.rolls().filter(|&(x, y)| {
+            offsets()
+                .filter(|&(dx, dy)| self[(x + dx, y + dy)] == b'@')
+                .count()
+                < 4
+        })
+    }
+}
+
+#[cfg(test)]
+static SAMPLE_INPUT_MAP: &[u8] = b"\
+..@@.@@@@.\
+@@@.@.@.@@\
+@@@@@.@.@@\
+@.@@@@..@.\
+@@.@@@@.@@\
+.@@@@@@@.@\
+.@.@.@.@@@\
+@.@@@.@@@@\
+.@@@@@@@@.\
+@.@.@@@.@.\
+";
+
+#[cfg(test)]
+static SAMPLE_INPUT: Problem = Problem {
+    map: Cow::Borrowed(SAMPLE_INPUT_MAP),
+    width: 10,
+    height: 10,
+};
+
+fn part1(problem: &Problem<'_>) -> usize {
+    problem.movable().count()
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(part1(&SAMPLE_INPUT), 13);
+}
+
+fn remove(input: &Problem<'_>, output: &mut Problem<'_>) -> usize {
+    let mut removed = 0;
+
+    output.map.to_mut().copy_from_slice(&input.map);
+    for (x, y) in input.movable() {
+        output[(x, y)] = b'.';
+        removed += 1;
+    }
+
+    removed
+}
+
+fn part2(problem: &Problem<'_>) -> usize {
+    let mut removed = 0;
+
+    let mut temp1 = Problem::clone(problem);
+    let mut temp2 = Problem::clone(problem);
+
+    loop {
+        let just_removed = remove(&temp1, &mut temp2);
+        if just_removed == 0 {
+            return removed;
+        }
+        removed += just_removed;
+        std::mem::swap(&mut temp1, &mut temp2);
+    }
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(part2(&SAMPLE_INPUT), 43);
+}
+
+#[test]
+fn test_parse() {
+    let text: String = SAMPLE_INPUT_MAP
+        .chunks(10)
+        .map(|row| format!("{}\n", std::str::from_utf8(row).unwrap()))
+        .collect();
+    let problem = Problem::parse(&text).unwrap();
+    assert_eq!((problem.width, problem.height), (10, 10));
+    assert_eq!(problem.map, SAMPLE_INPUT.map);
+    assert!(Problem::parse("..@\n.@\n").is_err());
+    assert!(Problem::parse("..@\n.x.\n").is_err());
+}
+
+#[test]
+#[ignore = "needs the private puzzle input"]
+fn test_input() {
+    use aoc_utils::input::{Inputs, Source};
+    let input = Inputs::default().load(2025, 4, &Source::Default).unwrap();
+    let problem = Problem::parse(&input).unwrap();
+    assert_eq!(part1(&problem), 1416);
+    assert_eq!(part2(&problem), 9086);
+}
+
+struct Day4;
+
+impl Solution for Day4 {
+    type Input = Problem<'static>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Problem<'static>> {
+        Problem::parse(input)
+    }
+
+    fn part1(problem: &Problem<'static>) -> usize {
+        part1(problem)
+    }
+
+    fn part2(problem: &Problem<'static>) -> usize {
+        part2(problem)
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day4>(2025, 4)
+}
@@ -1,132 +1,5 @@
-use std::{borrow::Cow, ops};
+use std::process::ExitCode;
 
-mod input;
-
-#[derive(Clone)]
-struct Problem<'a> {
-    map: Cow<'a, [u8]>,
-    width: isize,
-    height: isize,
-}
-
-impl ops::Index<(isize, isize)> for Problem<'_> {
-    type Output = u8;
-
-    fn index(&self, (x, y): (isize, isize)) -> &Self::Output {
-        if 0 <= x && x < self.width && 0 <= y && y < self.height {
-            self.map.get((y * self.width + x) as usize).unwrap()
-        } else {
-            &b'.'
-        }
-    }
-}
-
-impl ops::IndexMut<(isize, isize)> for Problem<'_> {
-    fn index_mut(&mut self, (x, y): (isize, isize)) -> &mut Self::Output {
-        if 0 <= x && x < self.width && 0 <= y && y < self.height {
-            self.map
-                .to_mut()
-                .get_mut((y * self.width + x) as usize)
-                .unwrap()
-        } else {
-            panic!("Problem coords out of range: {:?}", (x, y));
-        }
-    }
-}
-
-fn offsets() -> impl Iterator<Item = (isize, isize)> {
-    const STEPS: [isize; 3] = [-1, 0, 1];
-    STEPS
-        .into_iter()
-        .flat_map(|dx| STEPS.into_iter().map(move |dy| (dx, dy)))
-        .filter(|&(dx, dy)| dx != 0 || dy != 0)
-}
-
-impl Problem<'_> {
-    fn coords(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
-        (0..self.width).flat_map(|x| (0..self.height).map(move |y| (x, y)))
-    }
-
-    fn rolls(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
-        self.coords().filter(|&(x, y)| self[(x, y)] == b'@')
-    }
-
-    fn movable(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
-        self.rolls().filter(|&(x, y)| {
-            offsets()
-                .filter(|&(dx, dy)| self[(x + dx, y + dy)] == b'@')
-                .count()
-                < 4
-        })
-    }
-}
-
-#[cfg(test)]
-static SAMPLE_INPUT_MAP: &[u8] = b"\
-..@@.@@@@.\
-@@@.@.@.@@\
-@@@@@.@.@@\
-@.@@@@..@.\
-@@.@@@@.@@\
-.@@@@@@@.@\
-.@.@.@.@@@\
-@.@@@.@@@@\
-.@@@@@@@@.\
-@.@.@@@.@.\
-";
-
-#[cfg(test)]
-static SAMPLE_INPUT: Problem = Problem {
-    map: Cow::Borrowed(SAMPLE_INPUT_MAP),
-    width: 10,
-    height: 10,
-};
-
-fn part1(problem: &Problem<'_>) -> usize {
-    problem.movable().count()
-}
-
-#[test]
-fn test_part1() {
-    assert_eq!(part1(&SAMPLE_INPUT), 13);
-    assert_eq!(part1(&input::INPUT), 1416);
-}
-
-fn remove(input: &Problem<'_>, output: &mut Problem<'_>) -> usize {
-    let mut removed = 0;
-
-    output.map.to_mut().copy_from_slice(&input.map);
-    for (x, y) in input.movable() {
-        output[(x, y)] = b'.';
-        removed += 1;
-    }
-
-    removed
-}
-
-fn part2(problem: &Problem<'_>) -> usize {
-    let mut removed = 0;
-
-    let mut temp1 = Problem::clone(problem);
-    let mut temp2 = Problem::clone(problem);
-
-    loop {
-        let just_removed = remove(&temp1, &mut temp2);
-        if just_removed == 0 {
-            return removed;
-        }
-        removed += just_removed;
-        std::mem::swap(&mut temp1, &mut temp2);
-    }
-}
-
-#[test]
-fn test_part2() {
-    assert_eq!(part2(&SAMPLE_INPUT), 43);
-    assert_eq!(part2(&input::INPUT), 9086);
-}
-
-fn main() {
-    println!("part 1: {}", part1(&input::INPUT));
-    println!("part 2: {}", part2(&input::INPUT));
+fn main() -> ExitCode {
+    aoc_utils::runner::main(&[day4::day()])
 }
@@ -0,0 +1,127 @@
+#![allow(dead_code, unused_variables)]
+
+use anyhow::Context;
+use aoc_utils::parse::{self, Cursor, Parser, Sections, literal, unsigned};
+use aoc_utils::solution::{Day, Solution};
+use std::{cmp::max, ops::RangeInclusive, sync::LazyLock};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Problem {
+    fresh: Vec<RangeInclusive<usize>>,
+    available: Vec<usize>,
+}
+
+impl Problem {
+    /// Parse the fresh ingredient ID ranges, a blank line, and then the
+    /// available ingredient IDs.
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        let mut sections = Sections::new(input);
+        let fresh = sections
+            .parse_next_lines(range)
+            .context("bad fresh ID range")?;
+        let available = sections
+            .parse_next_lines(unsigned())
+            .context("bad available ingredient ID")?;
+        sections.finish()?;
+        Ok(Problem { fresh, available })
+    }
+}
+
+/// Parse a range of IDs like `3-5`.
+fn range(cursor: &mut Cursor) -> Result<RangeInclusive<usize>, parse::Error> {
+    let start = unsigned().parse(cursor)?;
+    literal("-").parse(cursor)?;
+    let end = unsigned().parse(cursor)?;
+    Ok(start..=end)
+}
+
+static TEST_INPUT: LazyLock<Problem> = LazyLock::new(|| Problem {
+    fresh: vec![3..=5, 10..=14, 16..=20, 12..=18],
+    available: vec![1, 5, 8, 11, 17, 32],
+});
+
+fn simplify_ranges(ranges: &mut Vec<RangeInclusive<usize>>) {
+    if ranges.is_empty() {
+        return;
+    }
+
+    ranges.sort_by_key(|r| *r.start());
+    let mut extending = 0;
+    for i in 0..ranges.len() {
+        if ranges[i].start() > ranges[extending].end() {
+            extending += 1;
+            ranges[extending] = ranges[i].clone();
+        } else {
+            ranges[extending] =
+                *ranges[extending].start()..=*max(ranges[extending].end(), ranges[i].end());
+        }
+    }
+    ranges.truncate(extending + 1);
+}
+
+fn in_ranges(ranges: &[RangeInclusive<usize>], n: usize) -> bool {
+    let p = ranges.partition_point(|r| *r.end() < n);
+    ranges.get(p).is_some_and(|r| r.contains(&n))
+}
+
+fn part1(input: &Problem) -> usize {
+    let mut input = input.clone();
+    simplify_ranges(&mut input.fresh);
+    input
+        .available
+        .iter()
+        .filter(|&&item| in_ranges(&input.fresh, item))
+        .count()
+}
+
+fn checked_range_len(range: &RangeInclusive<usize>) -> usize {
+    (range.end() - range.start()).checked_add(1).unwrap()
+}
+
+fn part2(input: &Problem) -> usize {
+    let mut input = input.clone();
+    simplify_ranges(&mut input.fresh);
+    input.fresh.iter().map(checked_range_len).sum()
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(part1(&TEST_INPUT), 3);
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(part2(&TEST_INPUT), 14);
+}
+
+#[test]
+fn test_parse() {
+    let problem = Problem::parse("3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n").unwrap();
+    assert_eq!(problem, *TEST_INPUT);
+    assert!(Problem::parse("3-5\n10-14\n").is_err());
+    assert!(Problem::parse("3..5\n\n1\n").is_err());
+}
+
+struct Day5;
+
+impl Solution for Day5 {
+    type Input = Problem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Problem> {
+        Problem::parse(input)
+    }
+
+    fn part1(problem: &Problem) -> usize {
+        part1(problem)
+    }
+
+    fn part2(problem: &Problem) -> usize {
+        part2(problem)
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day5>(2025, 5)
+}
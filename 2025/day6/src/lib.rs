@@ -0,0 +1,187 @@
+#![allow(unused_variables, dead_code)]
+
+use anyhow::{Context, anyhow, bail};
+use aoc_utils::solution::{Day, Solution};
+use std::str::FromStr as _;
+
+#[derive(Debug, PartialEq)]
+struct Problem {
+    numbers: Vec<Vec<u64>>,
+    operations: Vec<char>,
+}
+
+impl Problem {
+    /// Parse rows of numbers followed by a row of operators, reading the
+    /// worksheet the way part 1 does.
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        let mut lines: Vec<&str> = input.lines().collect();
+        let operations: Vec<char> = lines
+            .pop()
+            .ok_or_else(|| anyhow!("empty worksheet"))?
+            .split_whitespace()
+            .map(|op| match op {
+                "+" | "*" => Ok(op.chars().next().unwrap()),
+                _ => bail!("unexpected operator: {op:?}"),
+            })
+            .collect::<anyhow::Result<_>>()?;
+        let numbers: Vec<Vec<u64>> = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                line.split_whitespace()
+                    .map(u64::from_str)
+                    .collect::<Result<_, _>>()
+                    .with_context(|| format!("line {}", i + 1))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        if numbers.is_empty() {
+            bail!("worksheet has no numbers");
+        }
+        if let Some(i) = numbers.iter().position(|row| row.len() != operations.len()) {
+            bail!(
+                "line {} has {} numbers, but there are {} operators",
+                i + 1,
+                numbers[i].len(),
+                operations.len()
+            );
+        }
+        Ok(Problem {
+            numbers,
+            operations,
+        })
+    }
+
+    fn test_input() -> Self {
+        Problem {
+            numbers: vec![
+                vec![123, 328, 51, 64],
+                vec![45, 64, 387, 23],
+                vec![6, 98, 215, 314],
+            ],
+            operations: vec!['*', '+', '*', '+'],
+        }
+    }
+}
+
+static TEST_INPUT_STRING: &str = "\
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+";
+
+fn char_to_fn(op: char) -> fn(u64, u64) -> u64 {
+    match op {
+        '+' => <u64 as std::ops::Add<u64>>::add,
+        '*' => <u64 as std::ops::Mul<u64>>::mul,
+        _ => unreachable!("unexpected op: {:?}", op),
+    }
+}
+
+fn part1(problem: &Problem) -> u64 {
+    problem
+        .operations
+        .iter()
+        .enumerate()
+        .map(|(col, &op)| {
+            let op = char_to_fn(op);
+            let first = problem.numbers[0][col];
+            problem.numbers[1..]
+                .iter()
+                .map(|row| row[col])
+                .fold(first, op)
+        })
+        .sum()
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(part1(&Problem::test_input()), 4277556);
+}
+
+fn part2(problem: &str) -> u64 {
+    // Turn the input text into a grid of characters, for easy indexing.
+    let grid: Vec<Vec<char>> = problem.lines().map(|line| line.chars().collect()).collect();
+
+    // Split off the numbers from bottom line containing the operators.
+    // We can unwrap because we know there's at least one line.
+    let (operators, numbers) = grid.split_last().unwrap();
+
+    // Scan the operator line, producing column numbers and operator functions.
+    let operator_columns = operators
+        .iter()
+        .enumerate()
+        .filter(|&(_column, &op)| op != ' ')
+        .map(|(column, &op)| (column, char_to_fn(op)));
+
+    // Produce the results for each column.
+    let column_results = operator_columns.map(|(op_column, operator)| {
+        // Produce the operands to this operator, considering columns from
+        // left to right until we reach a column containing only spaces.
+        let operands = (op_column..)
+            .map(|column| {
+                // Produce the characters in `column` from all rows,
+                // substituting spaces if we go off the end of a line.
+                let column_chars = numbers
+                    .iter()
+                    .map(|row| row.get(column).cloned().unwrap_or(' '));
+                // Turn the column into a string, squeezing out spaces. This
+                // means that the blank column that separates problems turns
+                // into an empty string.
+                column_chars.filter(|&ch| ch != ' ').collect::<String>()
+            })
+            // When we reach an empty column, that's the end of the operands
+            // for this operator.
+            .take_while(|operand_str| !operand_str.is_empty())
+            // Parse the column of digits as a number. We can unwrap because
+            // we know they're all digits.
+            .map(|s| u64::from_str(&s).unwrap());
+
+        // Apply `operator` to all the operands. We can unwrap because we
+        // know there's at least one operand.
+        operands.reduce(operator).unwrap()
+    });
+
+    column_results.sum()
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(part2(TEST_INPUT_STRING), 3263827);
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        Problem::parse(TEST_INPUT_STRING).unwrap(),
+        Problem::test_input()
+    );
+    assert!(Problem::parse("1 2\n3\n+ *\n").is_err());
+    assert!(Problem::parse("1 2\n3 4\n+ -\n").is_err());
+}
+
+struct Day6;
+
+impl Solution for Day6 {
+    /// Part 2 reads the worksheet's columns differently, so it needs the
+    /// original text.
+    type Input = (Problem, String);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<(Problem, String)> {
+        Ok((Problem::parse(input)?, input.to_string()))
+    }
+
+    fn part1((problem, _): &(Problem, String)) -> u64 {
+        part1(problem)
+    }
+
+    fn part2((_, text): &(Problem, String)) -> u64 {
+        part2(text)
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day6>(2025, 6)
+}
@@ -0,0 +1,102 @@
+#![allow(dead_code, unused_variables)]
+
+mod distances;
+mod sets;
+
+use distances::Distances;
+use sets::Sets;
+
+use aoc_utils::solution::{Day, Solution};
+use std::str::FromStr as _;
+
+type Point = (u64, u64, u64);
+
+struct Problem {
+    boxes: Vec<Point>,
+}
+
+impl Problem {
+    fn parse(input: &str) -> Problem {
+        let boxes = input
+            .lines()
+            .map(|line| {
+                let mut iter = line.split(',').map(|coord| u64::from_str(coord).unwrap());
+                (
+                    iter.next().unwrap(),
+                    iter.next().unwrap(),
+                    iter.next().unwrap(),
+                )
+            })
+            .collect();
+
+        Problem { boxes }
+    }
+}
+
+fn part1(problem: &Problem, num_connections: usize) -> usize {
+    let distances = Distances::from_points(&problem.boxes);
+    let edges_by_length = distances.edges_by_length();
+    let mut circuits = Sets::new(problem.boxes.len());
+    for edge in &edges_by_length[..num_connections] {
+        circuits.join(edge.from(), edge.to());
+    }
+    let mut clumps = circuits.sets();
+    clumps.sort_by(|&a, &b| circuits.size(a).cmp(&circuits.size(b)).reverse());
+    clumps
+        .iter()
+        .take(3)
+        .map(|&clump| circuits.size(clump))
+        .product()
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(
+        part1(&Problem::parse(include_str!("test_input.txt")), 10),
+        40
+    );
+}
+
+fn part2(problem: &Problem) -> u64 {
+    let distances = Distances::from_points(&problem.boxes);
+    let edges_by_length = distances.edges_by_length();
+    let mut circuits = Sets::new(problem.boxes.len());
+    for edge in &edges_by_length {
+        if circuits.join(edge.from(), edge.to()) == problem.boxes.len() {
+            return problem.boxes[edge.from()].0 * problem.boxes[edge.to()].0;
+        }
+    }
+    panic!("never connected everybody");
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(
+        part2(&Problem::parse(include_str!("test_input.txt"))),
+        25272
+    );
+}
+
+struct Day8;
+
+impl Solution for Day8 {
+    type Input = Problem;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Problem> {
+        Ok(Problem::parse(input))
+    }
+
+    fn part1(problem: &Problem) -> usize {
+        part1(problem, 1000)
+    }
+
+    fn part2(problem: &Problem) -> u64 {
+        part2(problem)
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day8>(2025, 8)
+}
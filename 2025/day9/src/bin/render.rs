@@ -0,0 +1,24 @@
+//! Render the puzzle input read from standard input.
+//!
+//! With no arguments, draw the tiles to `day9.png`. Given a file name, write
+//! that instead: a PNG image, or, if the name ends in `.svg`, a diagram of
+//! the shape and its largest contained rectangle.
+
+fn main() -> anyhow::Result<()> {
+    aoc_utils::trace::init(0);
+
+    let input = std::io::read_to_string(std::io::stdin())?;
+    let path = match std::env::args_os().nth(1) {
+        Some(path) => std::path::PathBuf::from(path),
+        None => {
+            let mut path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+            path.push("day9.png");
+            path
+        }
+    };
+    if path.extension().is_some_and(|ext| ext == "svg") {
+        day9::diagram_input(&input, &path)
+    } else {
+        day9::render_input(&input, &path)
+    }
+}
@@ -0,0 +1,151 @@
+mod bands;
+mod edge;
+mod part2;
+mod test_data;
+
+use edge::{Edge, Point, is_horizontal, is_vertical};
+
+use aoc_utils::render::{Raster, Rgb};
+use aoc_utils::solution::{Day, Solution};
+use aoc_utils::svg::{Style, Svg};
+
+struct Problem {
+    red: Vec<Point>,
+}
+
+impl Problem {
+    fn from_str(input: &str) -> Self {
+        Problem {
+            red: input
+                .lines()
+                .map(|line| {
+                    let mut coords = line.split(',').map(|coord| coord.parse().unwrap());
+                    (coords.next().unwrap(), coords.next().unwrap())
+                })
+                .collect(),
+        }
+    }
+
+    fn edges(&self) -> impl Iterator<Item = Edge> + '_ {
+        let backlink = *self.red.last().unwrap()..=self.red[0];
+        self.red
+            .windows(2)
+            .map(|w| {
+                let &[from, to] = w else { unreachable!() };
+                from..=to
+            })
+            .chain(std::iter::once(backlink))
+            .inspect(|edge| {
+                assert!(is_horizontal(edge) || is_vertical(edge));
+                assert!(edge.start() != edge.end());
+            })
+    }
+}
+
+fn area(a: Point, b: Point) -> u64 {
+    use std::cmp::{max, min};
+
+    let ul = (min(a.0, b.0), min(a.1, b.1));
+    let lr = (max(a.0, b.0), max(a.1, b.1));
+    (lr.0 + 1 - ul.0) * (lr.1 + 1 - ul.1)
+}
+
+fn part1(problem: &Problem) -> u64 {
+    problem
+        .red
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)| problem.red[..i].iter().map(move |&b| (a, b)))
+        .map(|(a, b)| area(a, b))
+        .max()
+        .unwrap()
+}
+
+struct Day9;
+
+impl Solution for Day9 {
+    type Input = Problem;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Problem> {
+        Ok(Problem::from_str(input))
+    }
+
+    fn part1(problem: &Problem) -> u64 {
+        part1(problem)
+    }
+
+    fn part2(problem: &Problem) -> u64 {
+        part2::part2(problem)
+    }
+}
+
+pub fn day() -> Day {
+    Day::new::<Day9>(2025, 9)
+}
+
+/// Draw the tiles enclosed by the red tiles in `input` to a PNG file.
+pub fn render_input(input: &str, output: &std::path::Path) -> anyhow::Result<()> {
+    render(&Problem::from_str(input), (1000, 1000), 100, output)
+}
+
+/// Render `problem` to `output`.
+///
+/// The background is drawn black; areas within the shape are drawn in
+/// gray; and red tiles, obviously, are red.
+///
+/// The bitmap is `size` pixels, as `(rows, columns)`, and each pixel
+/// covers a `scale` by `scale` square of floor tiles.
+fn render(
+    problem: &Problem,
+    size: (usize, usize),
+    scale: usize,
+    output: &std::path::Path,
+) -> anyhow::Result<()> {
+    let mut raster = Raster::new(
+        0..(size.0 * scale) as i64,
+        0..(size.1 * scale) as i64,
+        scale as u64,
+    );
+    for band in bands::BandIter::from_edges(problem.edges()) {
+        let top = *band.rows.start() as i64;
+        let rows = top..*band.rows.end() as i64 + 1;
+        for run in &band.runs {
+            raster.paint_rect(
+                rows.clone(),
+                run.start as i64..run.end as i64,
+                Rgb([128, 128, 128]),
+            );
+        }
+        for &red in &band.reds {
+            raster.mark((top, red as i64), Rgb([255, 20, 20]));
+        }
+    }
+    raster.save(output)
+}
+
+/// Draw the shape outlined by the red tiles in `input`, and the largest
+/// rectangle it contains, to an SVG file.
+pub fn diagram_input(input: &str, output: &std::path::Path) -> anyhow::Result<()> {
+    let problem = Problem::from_str(input);
+    // Points are (row, column), but SVG wants (x, y).
+    let xy = |&(row, column): &Point| (column, row);
+
+    let mut svg = Svg::new();
+    svg.polygon(
+        problem.red.iter().map(xy),
+        Style::stroke("black").and_fill("lightgray"),
+    );
+    // Rectangle corners are tiles, so extend the far corner to cover the
+    // whole tile.
+    let (a, b) = part2::largest_rectangle(&problem);
+    let (a, b) = (xy(&a), xy(&b));
+    let min = (a.0.min(b.0), a.1.min(b.1));
+    let max = (a.0.max(b.0) + 1, a.1.max(b.1) + 1);
+    svg.rect(min, max, Style::fill("green").opacity(0.5));
+    for red in &problem.red {
+        svg.point(xy(red), None, Style::fill("red"));
+    }
+    svg.save(output)
+}
@@ -169,20 +169,29 @@ fn area(a: &Point, b: &Point) -> u64 {
     (bottom - top + 1) * (right - left + 1)
 }
 
-pub fn part2(problem: &Problem) -> u64 {
-    let mut largest = 1; // surely there is at least one tile
+/// Return opposite corners of the largest rectangle contained in the shape.
+pub fn largest_rectangle(problem: &Problem) -> (Point, Point) {
+    // Surely there is at least one tile.
+    let mut largest = (problem.red[0], problem.red[0]);
+    let mut largest_area = 1;
 
     for_each_contained_rectangle(problem, |a, b| {
         let area = area(&a, &b);
         log::debug!("        area {area}");
-        if area > largest {
-            largest = area;
+        if area > largest_area {
+            largest = (a, b);
+            largest_area = area;
         }
     });
 
     largest
 }
 
+pub fn part2(problem: &Problem) -> u64 {
+    let (a, b) = largest_rectangle(problem);
+    area(&a, &b)
+}
+
 #[cfg(test)]
 fn collect_rectangles(edges: &[crate::edge::Edge]) -> Vec<crate::edge::Edge> {
     let problem = Problem {
@@ -0,0 +1,175 @@
+//! `#[derive(AocParse)]`, for parsing puzzle input lines into structs.
+//!
+//! This is re-exported as `aoc_utils::AocParse`; see there for details.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitStr, parse_macro_input};
+
+/// Derive `FromStr` for a struct from a template for its text.
+///
+/// The template is the struct's text, with each field replaced by its name
+/// in braces:
+///
+/// ```ignore
+/// #[derive(AocParse)]
+/// #[aoc(template = "pos=<{x},{y},{z}>, r={r}")]
+/// struct Nanobot {
+///     x: i64,
+///     y: i64,
+///     z: i64,
+///     r: u64,
+/// }
+/// ```
+///
+/// Fields of tuple structs are named by number, like `{0}`. Each field's
+/// type must implement `FromStr`, and each field must appear in the template
+/// exactly once. Write `{{` and `}}` for literal braces.
+///
+/// The generated `from_str` splits the text at the template's fixed parts
+/// the way `aoc_utils::splits` does, trims whitespace around each field, and
+/// returns an `aoc_utils::parse::Error` saying where the text didn't match.
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn derive_aoc_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match derive(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn derive(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "AocParse can only be derived for structs",
+        ));
+    };
+    let template = template_attr(input)?;
+    let (seps, placeholders) = parse_template(&template.value())
+        .map_err(|message| syn::Error::new(template.span(), message))?;
+
+    // Match each of the struct's fields with its placeholder.
+    let fields: Vec<(String, syn::Member)> = match &data.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let ident = field.ident.clone().unwrap();
+                (ident.to_string(), syn::Member::Named(ident))
+            })
+            .collect(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len())
+            .map(|i| (i.to_string(), syn::Member::Unnamed(i.into())))
+            .collect(),
+        Fields::Unit => vec![],
+    };
+    for placeholder in &placeholders {
+        if !fields.iter().any(|(name, _)| name == placeholder) {
+            return Err(syn::Error::new(
+                template.span(),
+                format!("template refers to {{{placeholder}}}, but there is no such field"),
+            ));
+        }
+    }
+    let mut inits = vec![];
+    for (name, member) in &fields {
+        let Some(index) = placeholders.iter().position(|p| p == name) else {
+            return Err(syn::Error::new(
+                template.span(),
+                format!("template has no placeholder for field `{name}`"),
+            ));
+        };
+        inits.push(quote! { #member: fields[#index].parse().map_err(context)? });
+    }
+
+    let name = &input.ident;
+    let name_str = name.to_string();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::aoc_utils::parse::Error;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                let context = |err: ::aoc_utils::parse::Error| err.in_context(#name_str);
+                let fields = ::aoc_utils::parse::fields(s, &[#(#seps),*]).map_err(context)?;
+                ::std::result::Result::Ok(#name { #(#inits),* })
+            }
+        }
+    })
+}
+
+/// Return the string from the struct's `#[aoc(template = "...")]` attribute.
+fn template_attr(input: &DeriveInput) -> syn::Result<LitStr> {
+    let mut template = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("aoc"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("template") {
+                template = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `template = \"...\"`"))
+            }
+        })?;
+    }
+    template.ok_or_else(|| {
+        syn::Error::new(
+            input.ident.span(),
+            "AocParse needs a template, like #[aoc(template = \"{x},{y}\")]",
+        )
+    })
+}
+
+/// Split `template` into the text between placeholders, and the names in
+/// the placeholders.
+///
+/// There is always one more separator than there are placeholders, although
+/// the first and last may be empty.
+fn parse_template(template: &str) -> Result<(Vec<String>, Vec<String>), String> {
+    let mut seps = vec![String::new()];
+    let mut placeholders: Vec<String> = vec![];
+    let mut chars = template.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                seps.last_mut().unwrap().push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                seps.last_mut().unwrap().push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(ch) => name.push(ch),
+                        None => return Err(format!("unclosed placeholder {{{name}")),
+                    }
+                }
+                let name = name.trim().to_string();
+                if placeholders.contains(&name) {
+                    return Err(format!("placeholder {{{name}}} appears twice"));
+                }
+                if let Some(previous) = placeholders.last()
+                    && seps.last().unwrap().is_empty()
+                {
+                    return Err(format!(
+                        "placeholders {{{previous}}} and {{{name}}} need text between them"
+                    ));
+                }
+                placeholders.push(name);
+                seps.push(String::new());
+            }
+            '}' => return Err("unmatched '}'; write '}}' for a literal brace".to_string()),
+            _ => seps.last_mut().unwrap().push(ch),
+        }
+    }
+    Ok((seps, placeholders))
+}
@@ -0,0 +1,84 @@
+use aoc_utils::AocParse;
+use aoc_utils::parse::Position;
+
+#[derive(AocParse, Debug, PartialEq)]
+#[aoc(template = "pos=<{x},{y},{z}>, r={r}")]
+struct Nanobot {
+    x: i64,
+    y: i64,
+    z: i64,
+    r: u64,
+}
+
+#[derive(AocParse, Debug, PartialEq)]
+#[aoc(template = "{0},{1}")]
+struct Point(i32, i32);
+
+#[derive(AocParse, Debug, PartialEq)]
+#[aoc(template = "{start} -> {end}")]
+struct Line {
+    end: Point,
+    start: Point,
+}
+
+#[derive(AocParse, Debug, PartialEq)]
+#[aoc(template = "{{{name}}}: {count}")]
+struct Braced {
+    name: String,
+    count: usize,
+}
+
+#[test]
+fn test_derive() {
+    assert_eq!(
+        "pos=<0,-1,20>, r=4".parse(),
+        Ok(Nanobot {
+            x: 0,
+            y: -1,
+            z: 20,
+            r: 4
+        })
+    );
+    assert_eq!(
+        "0,9 -> 5, 9".parse(),
+        Ok(Line {
+            start: Point(0, 9),
+            end: Point(5, 9)
+        })
+    );
+    assert_eq!(
+        "{snow}: 25".parse(),
+        Ok(Braced {
+            name: "snow".to_string(),
+            count: 25
+        })
+    );
+}
+
+#[test]
+fn test_errors() {
+    let err = "pos=<0,-1,x>, r=4".parse::<Nanobot>().unwrap_err();
+    assert_eq!(
+        err.position,
+        Position {
+            line: 1,
+            column: 11
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        r#"line 1, column 11: expected i64, found "x" (invalid digit found in string) (in Nanobot)"#
+    );
+
+    let err = "pos=<0,-1,2> r=4".parse::<Nanobot>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        r#"line 1, column 11: expected a field followed by ">, r=", found "2> r=4" (in Nanobot)"#
+    );
+
+    let err = "0,9 -> 5".parse::<Line>().unwrap_err();
+    assert_eq!(err.position, Position { line: 1, column: 8 });
+    assert_eq!(err.context, ["Point", "Line"]);
+
+    assert!("{snow} 25".parse::<Braced>().is_err());
+}
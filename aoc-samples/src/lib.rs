@@ -0,0 +1,177 @@
+//! Generate tests from sample inputs, in a build script.
+//!
+//! See `aoc_utils::samples` for how samples and their expected answers are
+//! laid out, and for the `check` function the generated tests call. This is
+//! kept separate from `aoc-utils`, so that crates using it from their build
+//! scripts don't compile all of `aoc-utils` a second time.
+//!
+//! ```ignore
+//! fn main() {
+//!     aoc_samples::write_tests("src/sample").unwrap();
+//! }
+//! ```
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Return the path of the file holding `sample`'s expected answers.
+pub fn expected_path(sample: &Path) -> PathBuf {
+    let mut path = sample.as_os_str().to_owned();
+    path.push(".expected");
+    PathBuf::from(path)
+}
+
+/// Return the day of the puzzle that the sample at `path` is for.
+pub fn day_of(path: &Path) -> Option<u8> {
+    let name = path.file_name()?.to_str()?;
+    let rest = name.strip_prefix("day")?.trim_start_matches(['-', '_']);
+    let digits = rest
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..digits].parse().ok()
+}
+
+/// Return the samples in `dir` that have expected answers, in order by name.
+pub fn discover<P: AsRef<Path>>(dir: P) -> io::Result<Vec<PathBuf>> {
+    let mut samples = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if let Some(name) = path.to_str().and_then(|p| p.strip_suffix(".expected"))
+            && day_of(&path).is_some()
+        {
+            samples.push(PathBuf::from(name));
+        }
+    }
+    samples.sort();
+    Ok(samples)
+}
+
+/// Return the parts that the `.expected` file `text` gives answers for.
+///
+/// This only looks for `part1` and `part2` keys; the tests themselves parse
+/// the whole file, and fail if it's malformed.
+fn expected_parts(text: &str) -> Vec<u8> {
+    let mut parts: Vec<u8> = text
+        .lines()
+        .filter_map(|line| {
+            let (key, _value) = line.split_once('=')?;
+            match key.trim() {
+                "part1" => Some(1),
+                "part2" => Some(2),
+                _ => None,
+            }
+        })
+        .collect();
+    parts.sort();
+    parts.dedup();
+    parts
+}
+
+/// Return test functions for the samples in `dir`, one per sample per part.
+pub fn generate<P: AsRef<Path>>(dir: P) -> io::Result<String> {
+    let mut code = String::new();
+    for path in discover(&dir)? {
+        let expected = std::fs::read_to_string(expected_path(&path))?;
+        let name = test_name(&path);
+        for part in expected_parts(&expected) {
+            let variant = match part {
+                1 => "One",
+                _ => "Two",
+            };
+            writeln!(
+                code,
+                "#[test]\n\
+                 fn {name}_part{part}() {{\n    \
+                     ::aoc_utils::samples::check(&crate::solutions(), {path:?}, \
+                     ::aoc_utils::solution::Part::{variant});\n\
+                 }}\n",
+            )
+            .unwrap();
+        }
+    }
+    Ok(code)
+}
+
+/// Generate tests for the samples in `dir`, for a build script.
+///
+/// A relative `dir` is taken to be relative to the package's root. Write
+/// the tests to `sample_tests.rs` in `OUT_DIR`, and tell Cargo to rebuild
+/// when the samples change. Include them with `aoc_utils::sample_tests!`.
+pub fn write_tests<P: AsRef<Path>>(dir: P) -> io::Result<()> {
+    let not_cargo = || io::Error::other("not run by Cargo");
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").ok_or_else(not_cargo)?;
+    let out_dir = std::env::var_os("OUT_DIR").ok_or_else(not_cargo)?;
+    let dir = Path::new(&manifest_dir).join(dir);
+
+    println!("cargo::rerun-if-changed={}", dir.display());
+    for path in discover(&dir)? {
+        println!("cargo::rerun-if-changed={}", path.display());
+        println!("cargo::rerun-if-changed={}", expected_path(&path).display());
+    }
+    std::fs::write(Path::new(&out_dir).join("sample_tests.rs"), generate(&dir)?)
+}
+
+fn test_name(path: &Path) -> String {
+    let name = path.file_name().unwrap().to_string_lossy();
+    name.chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() {
+                ch.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        assert_eq!(day_of(Path::new("sample/day11")), Some(11));
+        assert_eq!(day_of(Path::new("sample/day12.even-larger")), Some(12));
+        assert_eq!(day_of(Path::new("bin/day-02a.test")), Some(2));
+        assert_eq!(day_of(Path::new("sample/day23_part2")), Some(23));
+        assert_eq!(day_of(Path::new("sample/dayX")), None);
+        assert_eq!(day_of(Path::new("sample/README")), None);
+        assert_eq!(test_name(Path::new("bin/day-02a.test")), "day_02a_test");
+        assert_eq!(
+            expected_path(Path::new("sample/day12.small")),
+            Path::new("sample/day12.small.expected")
+        );
+    }
+
+    #[test]
+    fn test_expected_parts() {
+        assert_eq!(expected_parts("part1 = 12\npart2 = \"42\"\n"), [1, 2]);
+        assert_eq!(expected_parts("part2=100\n"), [2]);
+        assert_eq!(expected_parts("# part1 = 3\n"), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_generate() {
+        let dir = std::env::temp_dir().join(format!("aoc-samples-gen-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("day01"), "2 3 7\n").unwrap();
+        std::fs::write(dir.join("day01.expected"), "part1 = 12\npart2 = \"42\"\n").unwrap();
+        std::fs::write(dir.join("day01.big"), "10 10\n").unwrap();
+        std::fs::write(dir.join("day01.big.expected"), "part2 = 100\n").unwrap();
+        std::fs::write(dir.join("day02"), "no expected answers\n").unwrap();
+
+        assert_eq!(
+            discover(&dir).unwrap(),
+            [dir.join("day01"), dir.join("day01.big")]
+        );
+        let code = generate(&dir).unwrap();
+        assert_eq!(code.matches("#[test]").count(), 3);
+        assert!(code.contains("fn day01_part1()"));
+        assert!(code.contains("fn day01_big_part2()"));
+        assert!(!code.contains("fn day01_big_part1()"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
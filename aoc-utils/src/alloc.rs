@@ -0,0 +1,423 @@
+//! A global allocator that counts allocations, for memory profiling.
+//!
+//! A binary can install [`Counting`] as its global allocator:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOC: aoc_utils::alloc::Counting = aoc_utils::alloc::Counting;
+//! ```
+//!
+//! Then [`Day::run`](crate::solution::Day::run) reports the [`Usage`] of
+//! parsing and of each part, and the runner prints it alongside the times.
+//!
+//! [`set_limit`] caps the number of bytes the program may have allocated at
+//! once. Unlike [`limit_memory`](crate::limit_memory), which just makes
+//! allocation fail, an allocation that would exceed the limit prints which
+//! day and phase was running, and how much was in use, before the process
+//! aborts.
+//!
+//! The counts are shared by all threads, so they're only meaningful if the
+//! program does one thing at a time. Calls to [`measure`] from different
+//! threads take turns, but allocations made by other threads while one is
+//! running are still counted against it.
+
+use crate::solution::{Day, Part};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering::Relaxed};
+
+/// A global allocator that passes requests on to [`System`], keeping count.
+pub struct Counting;
+
+/// How much memory some phase of a solution used.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Usage {
+    /// The most bytes allocated at any one time, including memory allocated
+    /// before the phase began.
+    pub peak: usize,
+
+    /// The number of allocations and reallocations.
+    pub allocations: usize,
+
+    /// The total number of bytes allocated.
+    pub bytes: usize,
+}
+
+/// The part of a solution that is running.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Phase {
+    /// Not running any solution.
+    Idle,
+    Parse,
+    Part(Part),
+}
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIMIT: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// The current phase, encoded by `Phase::to_u8`.
+static PHASE: AtomicU8 = AtomicU8::new(0);
+
+/// The day whose solution is running, if any.
+static DAY: Mutex<Option<Day>> = Mutex::new(None);
+
+/// Held by whichever thread is running [`measure`], so that concurrent calls
+/// don't clobber each other's day, phase and counts.
+static MEASURING: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    /// True if this thread is inside [`measure`], and so holds `MEASURING`.
+    static NESTED: Cell<bool> = const { Cell::new(false) };
+}
+
+impl Counting {
+    /// Note an allocation of `size` bytes, and return false if it would
+    /// exceed the limit.
+    fn add(&self, size: usize) -> bool {
+        let live = LIVE.fetch_add(size, Relaxed) + size;
+        if live > LIMIT.load(Relaxed) {
+            LIVE.fetch_sub(size, Relaxed);
+            report_limit(size, live - size);
+            return false;
+        }
+        PEAK.fetch_max(live, Relaxed);
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        BYTES.fetch_add(size, Relaxed);
+        true
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Relaxed);
+        if !self.add(layout.size()) {
+            return std::ptr::null_mut();
+        }
+        let ptr = unsafe { System.alloc(layout) };
+        if ptr.is_null() {
+            LIVE.fetch_sub(layout.size(), Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Relaxed);
+        if !self.add(layout.size()) {
+            return std::ptr::null_mut();
+        }
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if ptr.is_null() {
+            LIVE.fetch_sub(layout.size(), Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        LIVE.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // Count the new block as if it were allocated before the old one is
+        // freed, which is how the peak would look if the block moved.
+        if !self.add(new_size) {
+            return std::ptr::null_mut();
+        }
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if new_ptr.is_null() {
+            LIVE.fetch_sub(new_size, Relaxed);
+        } else {
+            LIVE.fetch_sub(layout.size(), Relaxed);
+        }
+        new_ptr
+    }
+}
+
+/// Return true if [`Counting`] is the global allocator.
+pub fn installed() -> bool {
+    INSTALLED.load(Relaxed)
+}
+
+/// Limit the number of bytes allocated at once to `limit`, or remove the
+/// limit if it is `None`.
+///
+/// This has no effect unless [`Counting`] is the global allocator.
+pub fn set_limit(limit: Option<usize>) {
+    LIMIT.store(limit.unwrap_or(usize::MAX), Relaxed);
+}
+
+/// Return the current limit on bytes allocated at once, if any.
+pub fn limit() -> Option<usize> {
+    match LIMIT.load(Relaxed) {
+        usize::MAX => None,
+        limit => Some(limit),
+    }
+}
+
+/// Run `f` as the given phase of `day`'s solution, and return its result
+/// and the memory it used.
+///
+/// If another thread is measuring something, wait until it's done. Calls may
+/// be nested: the inner call's usage is included in the outer call's, and
+/// the outer day and phase are restored when the inner call returns.
+///
+/// If [`Counting`] isn't the global allocator, the usage is `None`.
+pub fn measure<T>(day: &Day, phase: Phase, f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    let _turn = (!NESTED.get()).then(|| {
+        MEASURING
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner())
+    });
+    let outer_nested = NESTED.replace(true);
+    let outer_day = lock_day().replace(day.clone());
+    let outer_phase = PHASE.swap(phase.to_u8(), Relaxed);
+    let outer_usage = Usage {
+        peak: PEAK.swap(LIVE.load(Relaxed), Relaxed),
+        allocations: ALLOCATIONS.swap(0, Relaxed),
+        bytes: BYTES.swap(0, Relaxed),
+    };
+
+    let value = f();
+
+    let usage = Usage {
+        peak: PEAK.load(Relaxed),
+        allocations: ALLOCATIONS.load(Relaxed),
+        bytes: BYTES.load(Relaxed),
+    };
+    PEAK.fetch_max(outer_usage.peak, Relaxed);
+    ALLOCATIONS.fetch_add(outer_usage.allocations, Relaxed);
+    BYTES.fetch_add(outer_usage.bytes, Relaxed);
+    PHASE.store(outer_phase, Relaxed);
+    *lock_day() = outer_day;
+    NESTED.set(outer_nested);
+    (value, installed().then_some(usage))
+}
+
+fn lock_day() -> std::sync::MutexGuard<'static, Option<Day>> {
+    DAY.lock().unwrap_or_else(|poison| poison.into_inner())
+}
+
+impl Phase {
+    fn to_u8(self) -> u8 {
+        match self {
+            Phase::Idle => 0,
+            Phase::Parse => 1,
+            Phase::Part(Part::One) => 2,
+            Phase::Part(Part::Two) => 3,
+        }
+    }
+
+    fn from_u8(n: u8) -> Phase {
+        match n {
+            1 => Phase::Parse,
+            2 => Phase::Part(Part::One),
+            3 => Phase::Part(Part::Two),
+            _ => Phase::Idle,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Idle => f.write_str("outside any solution"),
+            Phase::Parse => f.write_str("parsing the input"),
+            Phase::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+/// Print a message explaining that an allocation of `size` bytes would
+/// exceed the limit, with `live` bytes already allocated.
+///
+/// This runs inside the allocator, so it must not allocate.
+fn report_limit(size: usize, live: usize) {
+    let mut message = Buffer::default();
+    let _ = write_limit_message(
+        &mut message,
+        DAY.try_lock().ok().as_deref().and_then(Option::as_ref),
+        Phase::from_u8(PHASE.load(Relaxed)),
+        size,
+        live,
+        LIMIT.load(Relaxed),
+    );
+    let _ = nix::unistd::write(std::io::stderr(), message.as_bytes());
+}
+
+fn write_limit_message(
+    out: &mut impl fmt::Write,
+    day: Option<&Day>,
+    phase: Phase,
+    size: usize,
+    live: usize,
+    limit: usize,
+) -> fmt::Result {
+    write!(out, "memory limit of {} exceeded ", Bytes(limit))?;
+    match day {
+        Some(day) if phase != Phase::Idle => write!(out, "in {day}, {phase}")?,
+        _ => write!(out, "{phase}")?,
+    }
+    writeln!(
+        out,
+        ": allocating {} with {} already in use",
+        Bytes(size),
+        Bytes(live)
+    )
+}
+
+/// Formats a number of bytes with a binary unit suited to its size.
+#[derive(Clone, Copy, Debug)]
+pub struct Bytes(pub usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit + 1 < UNITS.len() {
+            size /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{size:.2} {}", UNITS[unit])
+    }
+}
+
+/// Parse a size like `512M` or `2G`, with an optional binary unit suffix.
+pub fn parse_size(s: &str) -> anyhow::Result<usize> {
+    let number = s
+        .strip_suffix("iB")
+        .or_else(|| s.strip_suffix('B'))
+        .unwrap_or(s);
+    let (digits, shift) = match number.char_indices().last() {
+        Some((i, 'K' | 'k')) => (&number[..i], 10),
+        Some((i, 'M' | 'm')) => (&number[..i], 20),
+        Some((i, 'G' | 'g')) => (&number[..i], 30),
+        _ => (number, 0),
+    };
+    digits
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_mul(1 << shift))
+        .ok_or_else(|| anyhow::anyhow!("bad size {s:?}; expected something like 512M or 2G"))
+}
+
+/// A fixed-size buffer for formatting text without allocating.
+///
+/// Anything that doesn't fit is dropped.
+struct Buffer {
+    bytes: [u8; 256],
+    len: usize,
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Buffer {
+            bytes: [0; 256],
+            len: 0,
+        }
+    }
+}
+
+impl Buffer {
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl fmt::Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let n = s.len().min(self.bytes.len() - self.len);
+        self.bytes[self.len..self.len + n].copy_from_slice(&s.as_bytes()[..n]);
+        self.len += n;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::test::Arithmetic;
+    use std::fmt::Write as _;
+
+    #[global_allocator]
+    static ALLOC: Counting = Counting;
+
+    #[test]
+    fn test_measure() {
+        let day = Day::new::<Arithmetic>(2000, 1);
+        let (v, usage) = measure(&day, Phase::Parse, || vec![0_u8; 1 << 20]);
+        let usage = usage.unwrap();
+        assert!(usage.peak >= 1 << 20);
+        assert!(usage.allocations >= 1);
+        assert!(usage.bytes >= 1 << 20);
+        drop(v);
+    }
+
+    #[test]
+    fn test_measure_nested() {
+        let outer = Day::new::<Arithmetic>(2000, 1);
+        let inner = Day::new::<Arithmetic>(2000, 2);
+        let ((a, b, inner_usage), outer_usage) = measure(&outer, Phase::Parse, || {
+            let a = vec![0_u8; 1 << 20];
+            let (b, inner_usage) = measure(&inner, Phase::Part(Part::One), || {
+                assert_eq!(lock_day().as_ref().unwrap().day, 2);
+                vec![0_u8; 2 << 20]
+            });
+            assert_eq!(lock_day().as_ref().unwrap().day, 1);
+            assert_eq!(Phase::from_u8(PHASE.load(Relaxed)), Phase::Parse);
+            (a, b, inner_usage.unwrap())
+        });
+        let outer_usage = outer_usage.unwrap();
+        assert!(inner_usage.peak >= 3 << 20);
+        assert!(outer_usage.peak >= inner_usage.peak);
+        assert!(outer_usage.allocations > inner_usage.allocations);
+        assert!(outer_usage.bytes >= inner_usage.bytes + (1 << 20));
+        drop((a, b));
+    }
+
+    #[test]
+    fn test_limit_message() {
+        let day = Day::new::<Arithmetic>(2025, 10);
+        let mut message = Buffer::default();
+        write_limit_message(
+            &mut message,
+            Some(&day),
+            Phase::Part(Part::Two),
+            64 << 20,
+            1000 << 20,
+            1 << 30,
+        )
+        .unwrap();
+        assert_eq!(
+            std::str::from_utf8(message.as_bytes()).unwrap(),
+            "memory limit of 1.00 GiB exceeded in 2025 day 10, part 2: \
+             allocating 64.00 MiB with 1000.00 MiB already in use\n"
+        );
+
+        let mut message = Buffer::default();
+        write!(message, "{}", "x".repeat(1000)).unwrap();
+        assert_eq!(message.as_bytes().len(), 256);
+    }
+
+    #[test]
+    fn test_sizes() {
+        assert_eq!(parse_size("1000").unwrap(), 1000);
+        assert_eq!(parse_size("4K").unwrap(), 4096);
+        assert_eq!(parse_size("512M").unwrap(), 512 << 20);
+        assert_eq!(parse_size("2GiB").unwrap(), 2 << 30);
+        assert!(parse_size("lots").is_err());
+        assert!(parse_size("G").is_err());
+        assert_eq!(Bytes(100).to_string(), "100 B");
+        assert_eq!(Bytes(1536).to_string(), "1.50 KiB");
+        assert_eq!(Bytes(3 << 30).to_string(), "3.00 GiB");
+    }
+}
@@ -0,0 +1,344 @@
+//! Recording a simulation's successive states as an animation.
+//!
+//! This module is only available with the `animate` feature.
+//!
+//! A [`Recorder`] takes each state of a simulation as an [`Array2`], along
+//! with a function that gives each element's color, and writes the whole
+//! series out as an animated GIF or PNG:
+//!
+//! ```ignore
+//! let mut recorder = Recorder::new(|&ch: &char| match ch {
+//!     '|' => Rgb([20, 140, 20]),
+//!     '#' => Rgb([120, 80, 30]),
+//!     _ => Rgb([0, 0, 0]),
+//! })
+//! .every(10)
+//! .max_frames(200);
+//! for _ in 0..1000 {
+//!     recorder.frame(&map);
+//!     step_in_place(&mut map, &mut temp);
+//! }
+//! recorder.save(Path::new("lumber.gif"))?;
+//! ```
+//!
+//! Simulations often run for far more steps than anyone wants to watch, so
+//! the recorder can keep only every `n`th state offered to it, and stops
+//! keeping states once it has enough.
+//!
+//! Programs that record animations should save them to the file named by
+//! the `AOC_ANIMATE` environment variable; see [`requested`].
+
+use crate::render::Rgb;
+use anyhow::{Context, bail};
+use ndarray::Array2;
+use std::path::{Path, PathBuf};
+
+/// Return the file named by the `AOC_ANIMATE` environment variable, if any,
+/// to which programs should write their animations.
+pub fn requested() -> Option<PathBuf> {
+    std::env::var_os("AOC_ANIMATE")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// A file format for animations.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Gif,
+
+    /// Animated PNG.
+    Apng,
+}
+
+impl Format {
+    /// Choose a format based on `path`'s extension: `.gif` for GIF, and
+    /// `.png` or `.apng` for animated PNG.
+    pub fn from_path(path: &Path) -> Option<Format> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "gif" => Some(Format::Gif),
+            "png" | "apng" => Some(Format::Apng),
+            _ => None,
+        }
+    }
+}
+
+/// A series of frames being recorded from successive grids.
+pub struct Recorder<T, F> {
+    /// The function that gives each grid element's color.
+    color: F,
+
+    /// Keep one of every `every` frames offered.
+    every: usize,
+
+    /// Stop keeping frames once we have this many.
+    max_frames: usize,
+
+    /// The number of pixels along each side of a grid cell.
+    zoom: u32,
+
+    /// How long to show each frame, in milliseconds.
+    delay: u32,
+
+    /// The number of frames offered to `frame` so far.
+    offered: usize,
+
+    /// The dimensions of the grids we're recording: (rows, columns).
+    dim: Option<(usize, usize)>,
+
+    /// The frames we've kept, one RGB triple per grid cell, in row-major
+    /// order.
+    frames: Vec<Vec<u8>>,
+
+    _element: std::marker::PhantomData<fn(&T)>,
+}
+
+impl<T, F> Recorder<T, F>
+where
+    F: FnMut(&T) -> Rgb<u8>,
+{
+    /// Return a new recorder that colors grid elements with `color`.
+    ///
+    /// By default, the recorder keeps every frame, up to 1000 frames, draws
+    /// each grid cell as a 4x4 square of pixels, and shows each frame for
+    /// 100 milliseconds.
+    pub fn new(color: F) -> Self {
+        Recorder {
+            color,
+            every: 1,
+            max_frames: 1000,
+            zoom: 4,
+            delay: 100,
+            offered: 0,
+            dim: None,
+            frames: vec![],
+            _element: std::marker::PhantomData,
+        }
+    }
+
+    /// Keep only every `n`th frame offered, starting with the first.
+    pub fn every(mut self, n: usize) -> Self {
+        assert!(n > 0);
+        self.every = n;
+        self
+    }
+
+    /// Stop recording once we have `n` frames.
+    pub fn max_frames(mut self, n: usize) -> Self {
+        self.max_frames = n;
+        self
+    }
+
+    /// Draw each grid cell as a `zoom` by `zoom` square of pixels.
+    pub fn zoom(mut self, zoom: u32) -> Self {
+        assert!(zoom > 0);
+        self.zoom = zoom;
+        self
+    }
+
+    /// Show each frame for `delay` milliseconds.
+    pub fn delay(mut self, delay: u32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Offer `grid` as the next frame.
+    ///
+    /// Whether it is actually recorded depends on the settings of
+    /// [`every`](Recorder::every) and [`max_frames`](Recorder::max_frames).
+    /// Every grid must have the same dimensions.
+    pub fn frame(&mut self, grid: &Array2<T>) {
+        let dim = *self.dim.get_or_insert(grid.dim());
+        assert_eq!(grid.dim(), dim, "all frames must be the same size");
+
+        let offered = self.offered;
+        self.offered += 1;
+        if !offered.is_multiple_of(self.every) || self.is_full() {
+            return;
+        }
+        self.frames
+            .push(grid.iter().flat_map(|elt| (self.color)(elt).0).collect());
+    }
+
+    /// Return the number of frames recorded.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Return true if we have recorded as many frames as we will.
+    ///
+    /// A simulation can use this to stop early.
+    pub fn is_full(&self) -> bool {
+        self.frames.len() >= self.max_frames
+    }
+
+    /// Write the recorded frames to `path`, in the format its extension
+    /// suggests.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let Some(format) = Format::from_path(path) else {
+            bail!(
+                "can't tell what format to write {} in; use .gif or .png",
+                path.display()
+            );
+        };
+        self.save_as(path, format)
+    }
+
+    /// Write the recorded frames to `path` in `format`.
+    pub fn save_as(&self, path: &Path, format: Format) -> anyhow::Result<()> {
+        if self.frames.is_empty() {
+            bail!("no frames recorded");
+        }
+        let file = std::fs::File::create(path)
+            .with_context(|| format!("couldn't create {}", path.display()))?;
+        let stream = std::io::BufWriter::new(file);
+        match format {
+            Format::Gif => self.write_gif(stream),
+            Format::Apng => self.write_apng(stream),
+        }
+        .with_context(|| format!("error writing {}", path.display()))
+    }
+
+    /// Return the size of each frame in pixels, as (width, height).
+    fn size(&self) -> (u32, u32) {
+        let (rows, columns) = self.dim.unwrap();
+        (columns as u32 * self.zoom, rows as u32 * self.zoom)
+    }
+
+    /// Scale `frame` up by our zoom factor, producing pixels with
+    /// `channels` components each; any component beyond the third is 255.
+    fn pixels(&self, frame: &[u8], channels: usize) -> Vec<u8> {
+        let (width, height) = self.size();
+        let columns = self.dim.unwrap().1;
+        let zoom = self.zoom as usize;
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * channels);
+        for row in frame.chunks(columns * 3) {
+            let mut line = Vec::with_capacity(width as usize * channels);
+            for cell in row.chunks(3) {
+                for _ in 0..zoom {
+                    line.extend(cell);
+                    line.extend(std::iter::repeat_n(255, channels - 3));
+                }
+            }
+            for _ in 0..zoom {
+                pixels.extend(&line);
+            }
+        }
+        pixels
+    }
+
+    fn write_gif<W: std::io::Write>(&self, stream: W) -> anyhow::Result<()> {
+        use image::codecs::gif::{GifEncoder, Repeat};
+
+        let (width, height) = self.size();
+        let delay = image::Delay::from_numer_denom_ms(self.delay, 1);
+        let mut encoder = GifEncoder::new(stream);
+        encoder.set_repeat(Repeat::Infinite)?;
+        encoder.encode_frames(self.frames.iter().map(|frame| {
+            let image = image::RgbaImage::from_vec(width, height, self.pixels(frame, 4)).unwrap();
+            image::Frame::from_parts(image, 0, 0, delay)
+        }))?;
+        Ok(())
+    }
+
+    fn write_apng<W: std::io::Write>(&self, stream: W) -> anyhow::Result<()> {
+        let (width, height) = self.size();
+        let mut encoder = png::Encoder::new(stream, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_animated(self.frames.len() as u32, 0)?;
+        encoder.set_frame_delay(u16::try_from(self.delay).unwrap_or(u16::MAX), 1000)?;
+        let mut writer = encoder.write_header()?;
+        for frame in &self.frames {
+            writer.write_image_data(&self.pixels(frame, 3))?;
+        }
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn blinker(on: bool) -> Array2<bool> {
+        Array2::from_shape_fn(
+            (3, 3),
+            |(row, column)| {
+                if on { row == 1 } else { column == 1 }
+            },
+        )
+    }
+
+    fn recorder() -> Recorder<bool, impl FnMut(&bool) -> Rgb<u8>> {
+        Recorder::new(|&lit: &bool| {
+            if lit {
+                Rgb([255, 255, 0])
+            } else {
+                Rgb([0, 0, 64])
+            }
+        })
+    }
+
+    #[test]
+    fn test_every_and_max_frames() {
+        let mut recorder = recorder().every(3).max_frames(4);
+        for step in 0..20 {
+            recorder.frame(&blinker(step % 2 == 0));
+        }
+        // Steps 0, 3, 6, and 9.
+        assert_eq!(recorder.len(), 4);
+        assert!(recorder.is_full());
+        assert_eq!(recorder.frames[1], recorder.frames[3]);
+        assert_ne!(recorder.frames[0], recorder.frames[1]);
+    }
+
+    #[test]
+    fn test_zoom() {
+        let mut recorder = recorder().zoom(2);
+        recorder.frame(&blinker(true));
+        let pixels = recorder.pixels(&recorder.frames[0], 3);
+        assert_eq!(pixels.len(), 6 * 6 * 3);
+        // Row 2 of the pixels is the middle row of the grid.
+        assert_eq!(&pixels[0..3], &[0, 0, 64]);
+        assert_eq!(&pixels[6 * 3 * 2..6 * 3 * 2 + 3], &[255, 255, 0]);
+        assert_eq!(recorder.pixels(&recorder.frames[0], 4)[3], 255);
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = std::env::temp_dir().join(format!("aoc-animate-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut recorder = recorder();
+        for step in 0..4 {
+            recorder.frame(&blinker(step % 2 == 0));
+        }
+
+        let gif = dir.join("blinker.gif");
+        recorder.save(&gif).unwrap();
+        let decoder = image::codecs::gif::GifDecoder::new(std::io::BufReader::new(
+            std::fs::File::open(&gif).unwrap(),
+        ))
+        .unwrap();
+        use image::AnimationDecoder;
+        let frames = decoder.into_frames().collect_frames().unwrap();
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[0].buffer().dimensions(), (12, 12));
+
+        let apng = dir.join("blinker.png");
+        recorder.save(&apng).unwrap();
+        let decoder =
+            png::Decoder::new(std::io::BufReader::new(std::fs::File::open(&apng).unwrap()));
+        let reader = decoder.read_info().unwrap();
+        let control = reader.info().animation_control().unwrap();
+        assert_eq!(control.num_frames, 4);
+
+        assert!(recorder.save(&dir.join("blinker.bmp")).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
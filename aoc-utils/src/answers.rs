@@ -0,0 +1,191 @@
+//! Known-correct answers, for catching regressions.
+//!
+//! Answers depend on the puzzle input, so each year's answers live next to
+//! its inputs, in `inputs/<year>/answers.toml`:
+//!
+//! ```toml
+//! [day06]
+//! part1 = "386536"
+//! part2 = "1732821262171"
+//! ```
+//!
+//! Answers may also be written as TOML integers. The runner's `verify` mode
+//! checks solutions against these files, and can record answers it hasn't
+//! seen before.
+
+use crate::solution::Part;
+use anyhow::{Context, anyhow, bail};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// The recorded answers for one year's puzzles.
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    answers: BTreeMap<(u8, Part), String>,
+    changed: bool,
+}
+
+/// How an answer compares with the recorded one.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Verdict<'a> {
+    Pass,
+    Fail {
+        expected: &'a str,
+    },
+    /// There is no recorded answer for this part.
+    New,
+}
+
+impl Answers {
+    /// Load the answers recorded in `path`. If there is no such file, return
+    /// an empty set of answers that will be saved there.
+    pub fn load<P: Into<PathBuf>>(path: P) -> anyhow::Result<Answers> {
+        let path = path.into();
+        let answers = match std::fs::read_to_string(&path) {
+            Ok(text) => {
+                parse(&text).with_context(|| format!("bad answers file {}", path.display()))?
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("couldn't read answers file {}", path.display()));
+            }
+        };
+        Ok(Answers {
+            path,
+            answers,
+            changed: false,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Compare `value` with the recorded answer for `day` and `part`.
+    pub fn check(&self, day: u8, part: Part, value: &str) -> Verdict<'_> {
+        match self.get(day, part) {
+            None => Verdict::New,
+            Some(expected) if expected == value => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+        }
+    }
+
+    /// Record `value` as the answer for `day` and `part`.
+    pub fn record(&mut self, day: u8, part: Part, value: &str) {
+        let old = self.answers.insert((day, part), value.to_string());
+        self.changed |= old.as_deref() != Some(value);
+    }
+
+    /// Write the answers back to their file, if any have been recorded.
+    pub fn save(&mut self) -> anyhow::Result<()> {
+        if !self.changed {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, unparse(&self.answers))
+            .with_context(|| format!("couldn't write answers file {}", self.path.display()))?;
+        self.changed = false;
+        Ok(())
+    }
+}
+
+fn parse(text: &str) -> anyhow::Result<BTreeMap<(u8, Part), String>> {
+    let table: toml::Table = text.parse()?;
+    let mut answers = BTreeMap::new();
+    for (key, parts) in table {
+        let day: u8 = key
+            .strip_prefix("day")
+            .and_then(|day| day.parse().ok())
+            .ok_or_else(|| anyhow!("expected a table named like 'day06', not {key:?}"))?;
+        let toml::Value::Table(parts) = parts else {
+            bail!("{key} should be a table");
+        };
+        for (part, value) in parse_parts(parts).with_context(|| key.clone())? {
+            answers.insert((day, part), value);
+        }
+    }
+    Ok(answers)
+}
+
+/// Parse a table holding answers to `part1` and `part2`, either of which may
+/// be absent.
+pub(crate) fn parse_parts(table: toml::Table) -> anyhow::Result<BTreeMap<Part, String>> {
+    let mut parts = BTreeMap::new();
+    for (part, value) in table {
+        let part = match part.as_str() {
+            "part1" => Part::One,
+            "part2" => Part::Two,
+            _ => bail!("expected 'part1' or 'part2', not {part:?}"),
+        };
+        let value = match value {
+            toml::Value::String(value) => value,
+            toml::Value::Integer(value) => value.to_string(),
+            _ => bail!("answer to part {part} should be a string or integer"),
+        };
+        parts.insert(part, value);
+    }
+    Ok(parts)
+}
+
+fn unparse(answers: &BTreeMap<(u8, Part), String>) -> String {
+    let mut table = toml::Table::new();
+    for (&(day, part), value) in answers {
+        let day = table
+            .entry(format!("day{day:02}"))
+            .or_insert_with(|| toml::Table::new().into());
+        day.as_table_mut()
+            .unwrap()
+            .insert(format!("part{part}"), value.clone().into());
+    }
+    table.to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.check(6, Part::One, "5934"), Verdict::New);
+        answers.record(6, Part::One, "5934");
+        answers.record(25, Part::One, "58");
+        answers.save().unwrap();
+
+        std::fs::write(
+            &path,
+            std::fs::read_to_string(&path).unwrap() + "\n[day07]\npart2 = 168\n",
+        )
+        .unwrap();
+        let answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.check(6, Part::One, "5934"), Verdict::Pass);
+        assert_eq!(
+            answers.check(6, Part::One, "5935"),
+            Verdict::Fail { expected: "5934" }
+        );
+        assert_eq!(answers.check(6, Part::Two, "26984457539"), Verdict::New);
+        assert_eq!(answers.get(7, Part::Two), Some("168"));
+        assert_eq!(answers.get(25, Part::One), Some("58"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("[sixth]\npart1 = 1\n").is_err());
+        assert!(parse("[day06]\npart3 = 1\n").is_err());
+        assert!(parse("[day06]\npart1 = 1.5\n").is_err());
+        assert!(parse("day06 = 1\n").is_err());
+    }
+}
@@ -101,8 +101,17 @@ where
     type Item = Edge<N>;
 
     fn next(&mut self) -> Option<Edge<N>> {
-        let edge = self.pending.pop()?;
+        let Some(edge) = self.pending.pop() else {
+            tracing::debug!(visited = self.visited.len(), "search exhausted");
+            return None;
+        };
         if self.visited.insert(edge.to.clone()) {
+            tracing::trace!(
+                node = ?edge.to,
+                path_length = edge.path_length,
+                estimate = edge.estimate,
+                "visiting"
+            );
             for (neighbor, estimate) in (self.neighbors)(&edge.to) {
                 self.pending.push(Edge {
                     from: edge.to.clone(),
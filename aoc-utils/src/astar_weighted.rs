@@ -109,8 +109,12 @@ where
     type Item = Edge<N, W>;
 
     fn next(&mut self) -> Option<Edge<N, W>> {
-        let edge = self.pending.pop()?;
+        let Some(edge) = self.pending.pop() else {
+            tracing::debug!(visited = self.visited.len(), "search exhausted");
+            return None;
+        };
         if self.visited.insert(edge.to.clone()) {
+            tracing::trace!(node = ?edge.to, "visiting");
             for (neighbor, weight, estimate) in (self.neighbors)(&edge.to) {
                 self.pending.push(Edge {
                     from: edge.to.clone(),
@@ -0,0 +1,381 @@
+//! Benchmarking solutions, and comparing alternative implementations.
+//!
+//! [`measure`] runs a [`Day`] on its input repeatedly, and summarizes how
+//! long parsing and each part took. When a puzzle has several
+//! implementations (see [`Day::as_variant`]), [`disagreements`] checks that
+//! they all produce the same answers.
+//!
+//! A [`Baseline`] records the median times from one set of measurements, so
+//! that later runs can be compared against it. Baselines are saved as TOML
+//! files in `target/aoc-bench` at the top of the workspace, or in
+//! `$AOC_BENCH_DIR` if that is set.
+
+use crate::solution::{Day, Part};
+use anyhow::{Context, bail};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// How long to spend measuring each solution.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Keep taking samples until this much time has passed...
+    pub time: Duration,
+
+    /// ... but take at least this many ...
+    pub min_samples: usize,
+
+    /// ... and no more than this many.
+    pub max_samples: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            time: Duration::from_secs(3),
+            min_samples: 3,
+            max_samples: 1000,
+        }
+    }
+}
+
+/// Statistics about a set of timing samples.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Summary {
+    pub samples: usize,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Summary {
+    /// Summarize `samples`, which must not be empty.
+    pub fn new(samples: &[Duration]) -> Summary {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Summary {
+            samples: n,
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            min: sorted[0],
+            max: sorted[n - 1],
+        }
+    }
+}
+
+/// Timings and answers from benchmarking one [`Day`].
+#[derive(Debug)]
+pub struct Measurement {
+    pub day: Day,
+    pub parse: Summary,
+    pub parts: Vec<(Part, Summary)>,
+    pub answers: Vec<(Part, String)>,
+}
+
+impl Measurement {
+    /// Return the name under which baselines record this measurement.
+    pub fn key(&self) -> String {
+        let Day { year, day, .. } = self.day;
+        match self.day.variant {
+            Some(variant) => format!("{year}-{day:02}-{variant}"),
+            None => format!("{year}-{day:02}"),
+        }
+    }
+}
+
+/// Run `day` on `input` repeatedly, as directed by `config`, and summarize
+/// how long each phase took.
+pub fn measure(
+    day: &Day,
+    input: &str,
+    parts: &[Part],
+    config: &Config,
+) -> anyhow::Result<Measurement> {
+    // The first run warms things up, and gives us the answers.
+    let first = day.run(input, parts)?;
+    let answers = first
+        .answers
+        .iter()
+        .map(|answer| (answer.part, answer.value.clone()))
+        .collect();
+
+    let mut parse = vec![];
+    let mut part_times: BTreeMap<Part, Vec<Duration>> = BTreeMap::new();
+    let start = Instant::now();
+    while parse.len() < config.min_samples
+        || (start.elapsed() < config.time && parse.len() < config.max_samples)
+    {
+        let run = day.run(input, parts)?;
+        parse.push(run.parse);
+        for answer in run.answers {
+            part_times
+                .entry(answer.part)
+                .or_default()
+                .push(answer.elapsed);
+        }
+    }
+
+    Ok(Measurement {
+        day: day.clone(),
+        parse: Summary::new(&parse),
+        parts: part_times
+            .into_iter()
+            .map(|(part, times)| (part, Summary::new(&times)))
+            .collect(),
+        answers,
+    })
+}
+
+/// Implementations of the same puzzle that gave different answers.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Disagreement {
+    pub part: Part,
+
+    /// Each implementation's name, and its answer.
+    pub answers: Vec<(String, String)>,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "implementations disagree on part {}:", self.part)?;
+        for (name, answer) in &self.answers {
+            write!(f, " {name} says {answer};")?;
+        }
+        Ok(())
+    }
+}
+
+/// Return the parts on which `measurements` of the same puzzle disagree.
+///
+/// Implementations that didn't solve some part don't count towards it.
+pub fn disagreements(measurements: &[Measurement]) -> Vec<Disagreement> {
+    Part::ALL
+        .iter()
+        .filter_map(|&part| {
+            let answers: Vec<(String, String)> = measurements
+                .iter()
+                .filter_map(|m| {
+                    let (_, answer) = m.answers.iter().find(|(p, _)| *p == part)?;
+                    Some((
+                        m.day.variant.unwrap_or("default").to_string(),
+                        answer.clone(),
+                    ))
+                })
+                .collect();
+            let first = &answers.first()?.1;
+            answers
+                .iter()
+                .any(|(_, answer)| answer != first)
+                .then_some(Disagreement { part, answers })
+        })
+        .collect()
+}
+
+/// Median times from a past set of measurements.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    /// Median times in nanoseconds, indexed by [`Measurement::key`] and
+    /// then by phase: `parse`, `part1`, or `part2`.
+    medians: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+impl Baseline {
+    /// Load the baseline named `name` from `dir`.
+    pub fn load(dir: &Path, name: &str) -> anyhow::Result<Baseline> {
+        let path = baseline_path(dir, name);
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                bail!("no baseline named {name:?} in {}", dir.display())
+            }
+            Err(err) => return Err(err).context(format!("couldn't read {}", path.display())),
+        };
+        let medians =
+            toml::from_str(&text).with_context(|| format!("bad baseline {}", path.display()))?;
+        Ok(Baseline { medians })
+    }
+
+    /// Load the baseline named `name` from `dir`, or return an empty
+    /// baseline if there is none.
+    pub fn load_or_default(dir: &Path, name: &str) -> anyhow::Result<Baseline> {
+        if baseline_path(dir, name).exists() {
+            Baseline::load(dir, name)
+        } else {
+            Ok(Baseline::default())
+        }
+    }
+
+    /// Save this baseline as `name` in `dir`, replacing any earlier baseline
+    /// of that name.
+    pub fn save(&self, dir: &Path, name: &str) -> anyhow::Result<()> {
+        std::fs::create_dir_all(dir)?;
+        let path = baseline_path(dir, name);
+        std::fs::write(&path, toml::to_string(&self.medians)?)
+            .with_context(|| format!("couldn't write baseline {}", path.display()))
+    }
+
+    pub fn record(&mut self, measurement: &Measurement) {
+        let phases = self.medians.entry(measurement.key()).or_default();
+        phases.insert("parse".to_string(), nanos(measurement.parse.median));
+        for (part, summary) in &measurement.parts {
+            phases.insert(format!("part{part}"), nanos(summary.median));
+        }
+    }
+
+    /// Return the median time recorded for `phase` of `measurement`'s day,
+    /// where `phase` is `None` for parsing, or a part.
+    pub fn median(&self, measurement: &Measurement, phase: Option<Part>) -> Option<Duration> {
+        let phase = match phase {
+            None => "parse".to_string(),
+            Some(part) => format!("part{part}"),
+        };
+        let nanos = self.medians.get(&measurement.key())?.get(&phase)?;
+        Some(Duration::from_nanos(*nanos))
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+fn baseline_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{name}.toml"))
+}
+
+/// Return the directory in which to keep baselines.
+pub fn default_dir() -> PathBuf {
+    match std::env::var_os("AOC_BENCH_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/aoc-bench"),
+    }
+}
+
+/// Return the change from `old` to `new`, as a percentage of `old`.
+pub fn percent_change(old: Duration, new: Duration) -> f64 {
+    (new.as_secs_f64() - old.as_secs_f64()) / old.as_secs_f64() * 100.0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::Solution;
+    use crate::solution::test::Arithmetic;
+
+    /// Like `Arithmetic`, but wrong about part 2.
+    struct Sloppy;
+
+    impl Solution for Sloppy {
+        type Input = Vec<i64>;
+        type Answer1 = i64;
+        type Answer2 = i64;
+
+        fn parse(input: &str) -> anyhow::Result<Vec<i64>> {
+            Arithmetic::parse(input)
+        }
+
+        fn part1(input: &Vec<i64>) -> i64 {
+            Arithmetic::part1(input)
+        }
+
+        fn part2(input: &Vec<i64>) -> i64 {
+            Arithmetic::part2(input) + 1
+        }
+    }
+
+    fn quick() -> Config {
+        Config {
+            time: Duration::ZERO,
+            min_samples: 5,
+            max_samples: 5,
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let ms = Duration::from_millis;
+        let summary = Summary::new(&[ms(4), ms(2), ms(6), ms(8)]);
+        assert_eq!(summary.samples, 4);
+        assert_eq!(summary.median, ms(5));
+        assert_eq!(summary.mean, ms(5));
+        assert_eq!((summary.min, summary.max), (ms(2), ms(8)));
+        let stddev = summary.stddev.as_secs_f64();
+        assert!((stddev - 0.0025819889).abs() < 1e-9);
+
+        assert_eq!(Summary::new(&[ms(3), ms(1), ms(2)]).median, ms(2));
+        assert_eq!(Summary::new(&[ms(3)]).stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_disagreements() {
+        let days = [
+            Day::new::<Arithmetic>(2000, 1),
+            Day::new::<Sloppy>(2000, 1).as_variant("sloppy"),
+        ];
+        let measurements: Vec<Measurement> = days
+            .iter()
+            .map(|day| measure(day, "2 3 7", &Part::ALL, &quick()).unwrap())
+            .collect();
+        assert_eq!(measurements[0].parse.samples, 5);
+        assert_eq!(measurements[1].parts.len(), 2);
+        assert_eq!(measurements[1].key(), "2000-01-sloppy");
+
+        assert!(disagreements(&measurements[..1]).is_empty());
+        let found = disagreements(&measurements);
+        assert_eq!(
+            found,
+            [Disagreement {
+                part: Part::Two,
+                answers: vec![
+                    ("default".to_string(), "42".to_string()),
+                    ("sloppy".to_string(), "43".to_string())
+                ],
+            }]
+        );
+
+        // Comparing only part one, they agree.
+        let part1 = measure(&days[1], "2 3 7", &[Part::One], &quick()).unwrap();
+        assert!(disagreements(&[measurements.into_iter().next().unwrap(), part1]).is_empty());
+    }
+
+    #[test]
+    fn test_baseline() {
+        let dir = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+        let day = Day::new::<Arithmetic>(2000, 1);
+        let measurement = measure(&day, "1 2", &[Part::Two], &quick()).unwrap();
+
+        let mut baseline = Baseline::default();
+        baseline.record(&measurement);
+        baseline.save(&dir, "main").unwrap();
+        assert!(Baseline::load(&dir, "nonesuch").is_err());
+
+        let loaded = Baseline::load(&dir, "main").unwrap();
+        let parse = loaded.median(&measurement, None).unwrap();
+        assert_eq!(nanos(parse), nanos(measurement.parse.median));
+        assert!(loaded.median(&measurement, Some(Part::Two)).is_some());
+        assert!(loaded.median(&measurement, Some(Part::One)).is_none());
+
+        let change = percent_change(Duration::from_millis(200), Duration::from_millis(150));
+        assert!((change + 25.0).abs() < 1e-9);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
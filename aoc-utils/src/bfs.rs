@@ -50,8 +50,12 @@ where
     type Item = (N, N, usize);
 
     fn next(&mut self) -> Option<(N, N, usize)> {
-        let (from, to, length) = self.pending.pop_front()?;
+        let Some((from, to, length)) = self.pending.pop_front() else {
+            tracing::debug!(visited = self.visited.len(), "search exhausted");
+            return None;
+        };
         if self.visited.insert(to.clone()) {
+            tracing::trace!(length, visited = self.visited.len(), "visiting");
             for neighbor in (self.neighbors)(&to) {
                 self.pending.push_back((to.clone(), neighbor, length + 1));
             }
@@ -0,0 +1,283 @@
+//! Axis-aligned boxes in any number of dimensions, and sets of them.
+//!
+//! A [`Box<T, N>`] is the Cartesian product of `N` half-open ranges: a line
+//! segment, a rectangle, a cuboid, and so on. Subtracting one box from another
+//! generally doesn't leave a box, but it always leaves a handful of disjoint
+//! boxes, and that's enough to build [`BoxSet`], which can represent any union
+//! of boxes exactly and measure its volume.
+//!
+//! Note that this module's `Box` shadows the standard prelude's `Box` wherever
+//! it's imported by name.
+
+use crate::intersection::Intersection;
+use crate::linear::Vector;
+use num_traits::{One, Zero};
+use std::ops::{Mul, Range, Sub};
+
+/// An axis-aligned box, given as a half-open range along each axis.
+///
+/// A box is empty if any of its ranges is empty.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Box<T, const N: usize> {
+    pub ranges: [Range<T>; N],
+}
+
+impl<T: Copy + Ord, const N: usize> Box<T, N> {
+    pub fn new(ranges: [Range<T>; N]) -> Self {
+        Box { ranges }
+    }
+
+    /// Return the box that starts at `min`, inclusive, and extends to `max`,
+    /// exclusive, along every axis.
+    pub fn from_corners(min: Vector<T, N>, max: Vector<T, N>) -> Self {
+        Box {
+            ranges: std::array::from_fn(|axis| min[axis]..max[axis]),
+        }
+    }
+
+    /// Return the box running from `min` to `max`, inclusive, along every
+    /// axis. Puzzles usually describe boxes this way.
+    pub fn from_inclusive(min: Vector<T, N>, max: Vector<T, N>) -> Self
+    where
+        T: One + std::ops::Add<Output = T>,
+    {
+        Box::from_corners(min, max.map(|c| c + T::one()))
+    }
+
+    /// Return the corner of `self` with the least coordinates.
+    pub fn min(&self) -> Vector<T, N> {
+        Vector(std::array::from_fn(|axis| self.ranges[axis].start))
+    }
+
+    /// Return the corner of `self` with the greatest coordinates. This lies
+    /// just outside the box.
+    pub fn max(&self) -> Vector<T, N> {
+        Vector(std::array::from_fn(|axis| self.ranges[axis].end))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.iter().any(|range| range.start >= range.end)
+    }
+
+    pub fn contains(&self, point: &Vector<T, N>) -> bool {
+        self.ranges
+            .iter()
+            .zip(point.0.iter())
+            .all(|(range, coord)| range.contains(coord))
+    }
+
+    /// Return true if `self` and `other` have any points in common.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.clone().intersection(other.clone()).is_empty()
+    }
+
+    /// Return the number of integer points within `self`.
+    pub fn volume(&self) -> T
+    where
+        T: Zero + One + Sub<Output = T> + Mul<Output = T>,
+    {
+        if self.is_empty() {
+            return T::zero();
+        }
+
+        self.ranges.iter().fold(T::one(), |product, range| {
+            product * (range.end - range.start)
+        })
+    }
+
+    /// Return a set of disjoint, non-empty boxes that together cover exactly
+    /// the points in `self` that are not in `other`.
+    ///
+    /// The result has at most `2 * N` boxes.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        if self.is_empty() {
+            return vec![];
+        }
+
+        let cut = self.clone().intersection(other.clone());
+        if cut.is_empty() {
+            return vec![self.clone()];
+        }
+
+        // Work through the axes one at a time, slicing off the parts of `rest`
+        // that lie below and above `cut` along that axis, and then narrowing
+        // `rest` down to `cut`'s extent. Once we've gone through all the axes,
+        // `rest` is `cut` itself, which we drop.
+        let mut pieces = vec![];
+        let mut rest = self.clone();
+        for axis in 0..N {
+            let range = rest.ranges[axis].clone();
+            let keep = cut.ranges[axis].clone();
+            if range.start < keep.start {
+                let mut below = rest.clone();
+                below.ranges[axis] = range.start..keep.start;
+                pieces.push(below);
+            }
+            if keep.end < range.end {
+                let mut above = rest.clone();
+                above.ranges[axis] = keep.end..range.end;
+                pieces.push(above);
+            }
+            rest.ranges[axis] = keep;
+        }
+
+        pieces
+    }
+}
+
+/// The intersection of two boxes is the intersection of their ranges along
+/// each axis. The result may be empty.
+impl<T: Copy + Ord, const N: usize> Intersection<Box<T, N>> for Box<T, N> {
+    type Output = Box<T, N>;
+    fn intersection(self, right: Box<T, N>) -> Box<T, N> {
+        let mut right = right.ranges.into_iter();
+        Box {
+            ranges: self
+                .ranges
+                .map(|left| left.intersection(right.next().unwrap())),
+        }
+    }
+}
+
+/// A set of points, represented as a union of disjoint boxes.
+///
+/// Inserting or removing a box may split boxes already in the set, so the
+/// number of boxes can grow well beyond the number of operations performed.
+#[derive(Clone, Debug, Default)]
+pub struct BoxSet<T, const N: usize> {
+    boxes: Vec<Box<T, N>>,
+}
+
+impl<T: Copy + Ord, const N: usize> BoxSet<T, N> {
+    pub fn new() -> Self {
+        BoxSet { boxes: vec![] }
+    }
+
+    /// Add all the points in `new` to `self`.
+    pub fn insert(&mut self, new: Box<T, N>) {
+        self.remove(&new);
+        if !new.is_empty() {
+            self.boxes.push(new);
+        }
+    }
+
+    /// Remove all the points in `old` from `self`.
+    pub fn remove(&mut self, old: &Box<T, N>) {
+        if !self.boxes.iter().any(|b| b.overlaps(old)) {
+            return;
+        }
+
+        self.boxes = std::mem::take(&mut self.boxes)
+            .into_iter()
+            .flat_map(|b| b.subtract(old))
+            .collect();
+    }
+
+    pub fn contains(&self, point: &Vector<T, N>) -> bool {
+        self.boxes.iter().any(|b| b.contains(point))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    /// Return the number of integer points in `self`.
+    pub fn volume(&self) -> T
+    where
+        T: Zero + One + Sub<Output = T> + Mul<Output = T>,
+    {
+        self.boxes.iter().fold(T::zero(), |sum, b| sum + b.volume())
+    }
+
+    /// Return an iterator over the disjoint boxes making up `self`, in no
+    /// particular order.
+    pub fn boxes(&self) -> impl Iterator<Item = &Box<T, N>> {
+        self.boxes.iter()
+    }
+}
+
+impl<T: Copy + Ord, const N: usize> Extend<Box<T, N>> for BoxSet<T, N> {
+    fn extend<I: IntoIterator<Item = Box<T, N>>>(&mut self, iter: I) {
+        for b in iter {
+            self.insert(b);
+        }
+    }
+}
+
+impl<T: Copy + Ord, const N: usize> FromIterator<Box<T, N>> for BoxSet<T, N> {
+    fn from_iter<I: IntoIterator<Item = Box<T, N>>>(iter: I) -> Self {
+        let mut set = BoxSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn cube(min: i64, max: i64) -> Box<i64, 3> {
+        Box::from_inclusive(Vector([min; 3]), Vector([max; 3]))
+    }
+
+    #[test]
+    fn test_box() {
+        let a = Box::new([0..4, 0..3]);
+        let b = Box::new([2..6, 1..2]);
+        assert_eq!(a.volume(), 12);
+        assert_eq!(a.clone().intersection(b.clone()), Box::new([2..4, 1..2]));
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&Box::new([4..5, 0..3])));
+        assert!(a.contains(&Vector([3, 2])));
+        assert!(!a.contains(&Vector([4, 2])));
+        assert_eq!(Box::new([3..3, 0..10]).volume(), 0);
+
+        let pieces = a.subtract(&b);
+        assert_eq!(
+            pieces,
+            vec![
+                Box::new([0..2, 0..3]),
+                Box::new([2..4, 0..1]),
+                Box::new([2..4, 2..3])
+            ]
+        );
+        assert_eq!(pieces.iter().map(Box::volume).sum::<i64>(), 10);
+        assert_eq!(a.subtract(&Box::new([10..11, 0..1])), vec![a.clone()]);
+        assert!(a.subtract(&Box::new([-1..5, -1..5])).is_empty());
+
+        // Subtracting a hole from the middle leaves 2N pieces.
+        let pieces = cube(0, 2).subtract(&cube(1, 1));
+        assert_eq!(pieces.len(), 6);
+        assert_eq!(pieces.iter().map(Box::volume).sum::<i64>(), 26);
+        for (i, p) in pieces.iter().enumerate() {
+            assert!(!p.overlaps(&cube(1, 1)));
+            for q in &pieces[..i] {
+                assert!(!p.overlaps(q));
+            }
+        }
+    }
+
+    #[test]
+    fn test_box_set() {
+        // The small reboot example from 2021 day 22.
+        let mut set = BoxSet::new();
+        set.insert(cube(10, 12));
+        assert_eq!(set.volume(), 27);
+        set.insert(cube(11, 13));
+        assert_eq!(set.volume(), 27 + 19);
+        set.remove(&cube(9, 11));
+        assert_eq!(set.volume(), 27 + 19 - 8);
+        set.insert(cube(10, 10));
+        assert_eq!(set.volume(), 39);
+
+        assert!(set.contains(&Vector([10, 10, 10])));
+        assert!(!set.contains(&Vector([11, 11, 11])));
+        assert!(set.contains(&Vector([13, 13, 13])));
+
+        let set: BoxSet<i64, 2> = [Box::new([0..2, 0..2]), Box::new([1..3, 1..3])]
+            .into_iter()
+            .collect();
+        assert_eq!(set.volume(), 7);
+        assert!(BoxSet::<i64, 2>::new().is_empty());
+    }
+}
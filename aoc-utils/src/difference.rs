@@ -0,0 +1,552 @@
+use crate::enclose::Enclose;
+use crate::intersection::Intersection;
+use num_traits::{CheckedAdd, CheckedSub, One};
+use std::cmp::{max, min};
+use std::ops::{
+    Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+
+/// Set difference for ranges.
+///
+/// Removing one range from another leaves at most two pieces: the part of
+/// `self` below `right`, and the part above it. Either or both may be absent.
+/// The pieces aren't necessarily the same type as `self`: for example,
+/// removing `3..5` from `0..` leaves `0..3` and `5..`.
+///
+/// As with [`Intersection`], we don't provide implementations that mix
+/// end-inclusive and end-exclusive ranges. And since the value just past the
+/// end of an inclusive range is the start of the piece above it, implementations
+/// where `right` has an inclusive end require `Idx` to have a successor, which
+/// in practice means it's an integer type.
+pub trait Difference<Right> {
+    type Below;
+    type Above;
+
+    /// Return the parts of `self` that lie below and above `right`.
+    ///
+    /// If `right` is empty, the pieces together cover all of `self`.
+    fn difference(self, right: Right) -> (Option<Self::Below>, Option<Self::Above>);
+}
+
+fn range<Idx: Ord>(start: Idx, end: Idx) -> Option<Range<Idx>> {
+    (start < end).then_some(start..end)
+}
+
+fn inclusive<Idx: Ord>(start: Idx, end: Idx) -> Option<RangeInclusive<Idx>> {
+    (start <= end).then_some(start..=end)
+}
+
+fn succ<Idx: CheckedAdd + One>(i: Idx) -> Option<Idx> {
+    i.checked_add(&Idx::one())
+}
+
+fn pred<Idx: CheckedSub + One>(i: Idx) -> Option<Idx> {
+    i.checked_sub(&Idx::one())
+}
+
+/// Return the first value above the inclusive range `start..=end`, or `start`
+/// if the range is empty.
+fn above_start<Idx: Ord + CheckedAdd + One>(start: Idx, end: Idx) -> Option<Idx> {
+    if end < start { Some(start) } else { succ(end) }
+}
+
+/// Return the part of `start..=end` that lies below `limit`.
+fn below_inclusive<Idx>(start: Idx, end: Idx, limit: Idx) -> Option<RangeInclusive<Idx>>
+where
+    Idx: Ord + Copy + CheckedSub + One,
+{
+    // Since `limit > start`, `limit - 1` can't underflow.
+    if limit <= start {
+        return None;
+    }
+    inclusive(start, min(end, pred(limit)?))
+}
+
+// Unlike `impl_intersection!`, the implementations here need different bounds
+// on `Idx` depending on whether inclusive ends are involved. Since the bounds
+// can't be repeated inside the nested repetitions, this macro peels off one
+// implementation at a time.
+macro_rules! impl_difference {
+    ( [$($generics:tt)*]($sylf:ident, $right:ident): ) => {};
+
+    ( [$($generics:tt)*]($sylf:ident, $right:ident):
+       for $Left:ty { }
+       $($rest:tt)*
+    ) => {
+        impl_difference! { [$($generics)*]($sylf, $right): $($rest)* }
+    };
+
+    ( [$($generics:tt)*]($sylf:ident, $right:ident):
+       for $Left:ty {
+           ($Right:ty) -> ($Below:ty, $Above:ty) $body:block
+           $($more:tt)*
+       }
+       $($rest:tt)*
+    ) => {
+        impl<$($generics)*> Difference<$Right> for $Left {
+            type Below = $Below;
+            type Above = $Above;
+            #[allow(unused_variables)]
+            fn difference($sylf, $right: $Right) -> (Option<$Below>, Option<$Above>) {
+                $body
+            }
+        }
+
+        impl_difference! {
+            [$($generics)*]($sylf, $right):
+            for $Left { $($more)* }
+            $($rest)*
+        }
+    };
+}
+
+impl_difference! {
+    [Idx: Ord + Copy](self, right):
+
+    for RangeFull {
+     // (RangeFull) leaves nothing, and would leave `Idx` unconstrained
+        (RangeFrom<Idx>) -> (RangeTo<Idx>, RangeFrom<Idx>) { (Some(..right.start), None) }
+        (RangeTo<Idx>) -> (RangeTo<Idx>, RangeFrom<Idx>) { (None, Some(right.end..)) }
+        (Range<Idx>) -> (RangeTo<Idx>, RangeFrom<Idx>) {
+            (Some(..right.start), Some(max(right.start, right.end)..))
+        }
+    }
+
+    for RangeFrom<Idx> {
+        (RangeFull) -> (Range<Idx>, RangeFrom<Idx>) { (None, None) }
+        (RangeFrom<Idx>) -> (Range<Idx>, RangeFrom<Idx>) {
+            (range(self.start, right.start), None)
+        }
+        (RangeTo<Idx>) -> (Range<Idx>, RangeFrom<Idx>) {
+            (None, Some(max(self.start, right.end)..))
+        }
+        (Range<Idx>) -> (Range<Idx>, RangeFrom<Idx>) {
+            (range(self.start, right.start),
+             Some(max(self.start, max(right.start, right.end))..))
+        }
+    }
+
+    for RangeTo<Idx> {
+        (RangeFull) -> (RangeTo<Idx>, Range<Idx>) { (None, None) }
+        (RangeFrom<Idx>) -> (RangeTo<Idx>, Range<Idx>) {
+            (Some(..min(self.end, right.start)), None)
+        }
+        (RangeTo<Idx>) -> (RangeTo<Idx>, Range<Idx>) { (None, range(right.end, self.end)) }
+        (Range<Idx>) -> (RangeTo<Idx>, Range<Idx>) {
+            (Some(..min(self.end, right.start)),
+             range(max(right.start, right.end), self.end))
+        }
+    }
+
+    for Range<Idx> {
+        (RangeFull) -> (Range<Idx>, Range<Idx>) { (None, None) }
+        (RangeFrom<Idx>) -> (Range<Idx>, Range<Idx>) {
+            (range(self.start, min(self.end, right.start)), None)
+        }
+        (RangeTo<Idx>) -> (Range<Idx>, Range<Idx>) {
+            (None, range(max(self.start, right.end), self.end))
+        }
+        (Range<Idx>) -> (Range<Idx>, Range<Idx>) {
+            (range(self.start, min(self.end, right.start)),
+             range(max(self.start, max(right.start, right.end)), self.end))
+        }
+    }
+}
+
+impl_difference! {
+    [Idx: Ord + Copy + One + CheckedAdd + CheckedSub](self, right):
+
+    for RangeFull {
+        (RangeToInclusive<Idx>) -> (RangeTo<Idx>, RangeFrom<Idx>) {
+            (None, succ(right.end).map(|start| start..))
+        }
+        (RangeInclusive<Idx>) -> (RangeTo<Idx>, RangeFrom<Idx>) {
+            {
+                let (start, end) = right.into_inner();
+                (Some(..start), above_start(start, end).map(|start| start..))
+            }
+        }
+    }
+
+    for RangeFrom<Idx> {
+        (RangeToInclusive<Idx>) -> (Range<Idx>, RangeFrom<Idx>) {
+            (None, succ(right.end).map(|start| max(self.start, start)..))
+        }
+        (RangeInclusive<Idx>) -> (Range<Idx>, RangeFrom<Idx>) {
+            {
+                let (start, end) = right.into_inner();
+                (range(self.start, start),
+                 above_start(start, end).map(|start| max(self.start, start)..))
+            }
+        }
+    }
+
+    for RangeToInclusive<Idx> {
+        (RangeFull) -> (RangeToInclusive<Idx>, RangeInclusive<Idx>) { (None, None) }
+        (RangeFrom<Idx>) -> (RangeToInclusive<Idx>, RangeInclusive<Idx>) {
+            (pred(right.start).map(|end| ..=min(self.end, end)), None)
+        }
+     // (RangeTo<Idx>) cannot mix inclusive/exclusive
+        (RangeToInclusive<Idx>) -> (RangeToInclusive<Idx>, RangeInclusive<Idx>) {
+            (None, succ(right.end).and_then(|start| inclusive(start, self.end)))
+        }
+     // (Range<Idx>) cannot mix inclusive/exclusive
+        (RangeInclusive<Idx>) -> (RangeToInclusive<Idx>, RangeInclusive<Idx>) {
+            {
+                let (start, end) = right.into_inner();
+                (pred(start).map(|below| ..=min(self.end, below)),
+                 above_start(start, end).and_then(|start| inclusive(start, self.end)))
+            }
+        }
+    }
+
+    for RangeInclusive<Idx> {
+        (RangeFull) -> (RangeInclusive<Idx>, RangeInclusive<Idx>) { (None, None) }
+        (RangeFrom<Idx>) -> (RangeInclusive<Idx>, RangeInclusive<Idx>) {
+            {
+                let (start, end) = self.into_inner();
+                (below_inclusive(start, end, right.start), None)
+            }
+        }
+     // (RangeTo<Idx>) cannot mix inclusive/exclusive
+        (RangeToInclusive<Idx>) -> (RangeInclusive<Idx>, RangeInclusive<Idx>) {
+            {
+                let (start, end) = self.into_inner();
+                (None, succ(right.end).and_then(|above| inclusive(max(start, above), end)))
+            }
+        }
+     // (Range<Idx>) cannot mix inclusive/exclusive
+        (RangeInclusive<Idx>) -> (RangeInclusive<Idx>, RangeInclusive<Idx>) {
+            {
+                let (left_start, left_end) = self.into_inner();
+                let (right_start, right_end) = right.into_inner();
+                (below_inclusive(left_start, left_end, right_start),
+                 above_start(right_start, right_end)
+                     .and_then(|above| inclusive(max(left_start, above), left_end)))
+            }
+        }
+    }
+}
+
+/// Symmetric difference for bounded ranges.
+///
+/// The values in exactly one of two ranges always form at most two ranges of
+/// the same type: if the ranges overlap, the pieces of their enclosing range
+/// that lie outside their intersection; otherwise, the ranges themselves.
+pub trait SymmetricDifference: Sized {
+    /// Return the values in exactly one of `self` and `right`, as up to two
+    /// ranges. If both are present, the first is below the second.
+    fn symmetric_difference(self, right: Self) -> (Option<Self>, Option<Self>);
+}
+
+macro_rules! impl_symmetric_difference {
+    ( $( <$tyvar:ident: $bound:ident $( + $bounds:ident )*> for $Range:ty; )* ) => {
+        $(
+            impl<$tyvar: $bound $( + $bounds )*> SymmetricDifference for $Range {
+                fn symmetric_difference(self, right: Self) -> (Option<Self>, Option<Self>) {
+                    if self.is_empty() {
+                        return (None, (!right.is_empty()).then_some(right));
+                    }
+                    if right.is_empty() {
+                        return (Some(self), None);
+                    }
+
+                    let meet = self.clone().intersection(right.clone());
+                    if meet.is_empty() {
+                        return if self.start() < right.start() {
+                            (Some(self), Some(right))
+                        } else {
+                            (Some(right), Some(self))
+                        };
+                    }
+
+                    self.enclose(right).difference(meet)
+                }
+            }
+        )*
+    }
+}
+
+/// Give `Range` the same `start` accessor `RangeInclusive` has, so both can
+/// share the implementation above.
+trait Start<Idx> {
+    fn start(&self) -> &Idx;
+}
+
+impl<Idx> Start<Idx> for Range<Idx> {
+    fn start(&self) -> &Idx {
+        &self.start
+    }
+}
+
+impl_symmetric_difference! {
+    <Idx: Ord + Copy> for Range<Idx>;
+    <Idx: Ord + Copy + One + CheckedAdd + CheckedSub> for RangeInclusive<Idx>;
+}
+
+/// Predicates that work the same way on every kind of range.
+///
+/// These are implemented for anything that implements [`RangeBounds`], so they
+/// cover all six standard range types, as well as pairs of [`Bound`]s.
+pub trait RangeExt<Idx: Ord>: RangeBounds<Idx> {
+    /// Return true if `self` contains no values.
+    ///
+    /// Ranges unbounded at either end are never empty.
+    fn is_empty_range(&self) -> bool {
+        use Bound::*;
+        match (self.start_bound(), self.end_bound()) {
+            (Unbounded, _) | (_, Unbounded) => false,
+            (Included(start), Included(end)) => start > end,
+            (Included(start), Excluded(end))
+            | (Excluded(start), Included(end))
+            | (Excluded(start), Excluded(end)) => start >= end,
+        }
+    }
+
+    /// Return true if every value in `other` is also in `self`.
+    ///
+    /// An empty range is contained in every range. When one end is inclusive
+    /// and the other exclusive, this doesn't assume `Idx` is an integer type,
+    /// so `0..=4` is not considered to contain `0..5`.
+    fn contains_range<R: RangeExt<Idx>>(&self, other: &R) -> bool {
+        use Bound::*;
+        if other.is_empty_range() {
+            return true;
+        }
+
+        let start_ok = match (self.start_bound(), other.start_bound()) {
+            (Unbounded, _) => true,
+            (_, Unbounded) => false,
+            (Excluded(s), Included(o)) => s < o,
+            (Included(s), Included(o) | Excluded(o)) | (Excluded(s), Excluded(o)) => s <= o,
+        };
+
+        let end_ok = match (self.end_bound(), other.end_bound()) {
+            (Unbounded, _) => true,
+            (_, Unbounded) => false,
+            (Excluded(s), Included(o)) => o < s,
+            (Included(s), Included(o) | Excluded(o)) | (Excluded(s), Excluded(o)) => o <= s,
+        };
+
+        start_ok && end_ok
+    }
+}
+
+impl<Idx: Ord, R: RangeBounds<Idx>> RangeExt<Idx> for R {}
+
+/// Split `range` at each of `points`, returning an iterator over the pieces.
+///
+/// The `points` must be in increasing order. Those that don't fall strictly
+/// within `range` don't split anything, and are skipped. Iteration stops at the
+/// first point at or beyond the end of `range`, so `points` may be infinite.
+///
+/// For example, to split a range at every multiple of `10`:
+///
+///     # use aoc_utils::difference::split_at;
+///     let pieces: Vec<_> = split_at(5..35, (0..).step_by(10)).collect();
+///     assert_eq!(pieces, vec![5..10, 10..20, 20..30, 30..35]);
+pub fn split_at<Idx, I>(range: Range<Idx>, points: I) -> impl Iterator<Item = Range<Idx>>
+where
+    Idx: Ord + Copy,
+    I: IntoIterator<Item = Idx>,
+{
+    let mut points = points.into_iter();
+    let mut start = (range.start < range.end).then_some(range.start);
+    std::iter::from_fn(move || {
+        let here = start?;
+        for point in points.by_ref() {
+            if point >= range.end {
+                break;
+            }
+            if point > here {
+                start = Some(point);
+                return Some(here..point);
+            }
+        }
+        start = None;
+        Some(here..range.end)
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::reversed_empty_ranges)]
+mod test {
+    use super::*;
+    use crate::cartesian_product;
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_difference() {
+        assert_eq!((..).difference(3..), (Some(..3), None));
+        assert_eq!((..).difference(..3), (None, Some(3..)));
+        assert_eq!((..).difference(3..5), (Some(..3), Some(5..)));
+        assert_eq!((..).difference(5..3), (Some(..5), Some(5..)));
+        assert_eq!((..).difference(..=3), (None, Some(4..)));
+        assert_eq!((..).difference(3..=5), (Some(..3), Some(6..)));
+        assert_eq!((..).difference(0..=u8::MAX), (Some(..0), None));
+
+        assert_eq!((0..).difference(..), (None, None));
+        assert_eq!((0..).difference(3..), (Some(0..3), None));
+        assert_eq!((3..).difference(0..), (None, None));
+        assert_eq!((0..).difference(..3), (None, Some(3..)));
+        assert_eq!((5..).difference(..3), (None, Some(5..)));
+        assert_eq!((0..).difference(3..5), (Some(0..3), Some(5..)));
+        assert_eq!((4..).difference(3..5), (None, Some(5..)));
+        assert_eq!((0..).difference(..=3), (None, Some(4..)));
+        assert_eq!((0..).difference(3..=5), (Some(0..3), Some(6..)));
+
+        assert_eq!((..10).difference(..), (None, None));
+        assert_eq!((..10).difference(3..), (Some(..3), None));
+        assert_eq!((..10).difference(12..), (Some(..10), None));
+        assert_eq!((..10).difference(..3), (None, Some(3..10)));
+        assert_eq!((..10).difference(..12), (None, None));
+        assert_eq!((..10).difference(3..5), (Some(..3), Some(5..10)));
+
+        assert_eq!((..=10).difference(..), (None, None));
+        assert_eq!((..=10).difference(3..), (Some(..=2), None));
+        assert_eq!((..=10).difference(..=3), (None, Some(4..=10)));
+        assert_eq!((..=10).difference(..=10), (None, None));
+        assert_eq!((..=10).difference(3..=5), (Some(..=2), Some(6..=10)));
+        assert_eq!((..=u8::MAX).difference(0..=5), (None, Some(6..=u8::MAX)));
+
+        assert_eq!((0..10).difference(..), (None, None));
+        assert_eq!((0..10).difference(3..), (Some(0..3), None));
+        assert_eq!((0..10).difference(..3), (None, Some(3..10)));
+        assert_eq!((0..10).difference(3..5), (Some(0..3), Some(5..10)));
+        assert_eq!((0..10).difference(-3..5), (None, Some(5..10)));
+        assert_eq!((0..10).difference(3..15), (Some(0..3), None));
+        assert_eq!((0..10).difference(12..15), (Some(0..10), None));
+        assert_eq!((0..10).difference(5..5), (Some(0..5), Some(5..10)));
+        assert_eq!((0..0).difference(5..8), (None, None));
+
+        assert_eq!((0..=10).difference(..), (None, None));
+        assert_eq!((0..=10).difference(3..), (Some(0..=2), None));
+        assert_eq!((0..=10).difference(..=3), (None, Some(4..=10)));
+        assert_eq!((0..=10).difference(3..=5), (Some(0..=2), Some(6..=10)));
+        assert_eq!((0..=10).difference(0..=10), (None, None));
+        assert_eq!((0..=10).difference(10..=12), (Some(0..=9), None));
+        assert_eq!((0..=10).difference(5..=4), (Some(0..=4), Some(5..=10)));
+        assert_eq!((0..=u8::MAX).difference(0..=u8::MAX), (None, None));
+    }
+
+    /// Check that the pieces of every difference among small ranges cover
+    /// exactly the right values.
+    #[test]
+    fn test_difference_exhaustively() {
+        let values = -1..8;
+        for (ls, le, rs, re) in quadruples(0..6) {
+            let (below, above) = (ls..le).difference(rs..re);
+            for v in values.clone() {
+                let expected = (ls..le).contains(&v) && !(rs..re).contains(&v);
+                let below_has = below.clone().is_some_and(|b| b.contains(&v));
+                let above_has = above.clone().is_some_and(|a| a.contains(&v));
+                assert!(!(below_has && above_has));
+                assert_eq!(
+                    below_has || above_has,
+                    expected,
+                    "{ls}..{le} - {rs}..{re} at {v}"
+                );
+                if below_has {
+                    assert!(v < rs);
+                }
+            }
+
+            let (below, above) = (ls..=le).difference(rs..=re);
+            for v in values.clone() {
+                let expected = (ls..=le).contains(&v) && !(rs..=re).contains(&v);
+                let below_has = below.clone().is_some_and(|b| b.contains(&v));
+                let above_has = above.clone().is_some_and(|a| a.contains(&v));
+                assert!(!(below_has && above_has));
+                assert_eq!(
+                    below_has || above_has,
+                    expected,
+                    "{ls}..={le} - {rs}..={re} at {v}"
+                );
+            }
+
+            let (first, second) = (ls..le).symmetric_difference(rs..re);
+            for v in values.clone() {
+                let expected = (ls..le).contains(&v) != (rs..re).contains(&v);
+                let first_has = first.clone().is_some_and(|r| r.contains(&v));
+                let second_has = second.clone().is_some_and(|r| r.contains(&v));
+                assert!(!(first_has && second_has));
+                assert_eq!(
+                    first_has || second_has,
+                    expected,
+                    "{ls}..{le} ^ {rs}..{re} at {v}"
+                );
+            }
+
+            let (first, second) = (ls..=le).symmetric_difference(rs..=re);
+            for v in values.clone() {
+                let expected = (ls..=le).contains(&v) != (rs..=re).contains(&v);
+                let first_has = first.clone().is_some_and(|r| r.contains(&v));
+                let second_has = second.clone().is_some_and(|r| r.contains(&v));
+                assert!(!(first_has && second_has));
+                assert_eq!(
+                    first_has || second_has,
+                    expected,
+                    "{ls}..={le} ^ {rs}..={re} at {v}"
+                );
+            }
+        }
+    }
+
+    /// Return all quadruples of values drawn from `r`.
+    fn quadruples(r: Range<i32>) -> impl Iterator<Item = (i32, i32, i32, i32)> {
+        let pairs = cartesian_product(r.clone(), r);
+        cartesian_product(pairs.clone(), pairs).map(|((a, b), (c, d))| (a, b, c, d))
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        assert_eq!((0..5).symmetric_difference(3..8), (Some(0..3), Some(5..8)));
+        assert_eq!((3..8).symmetric_difference(0..5), (Some(0..3), Some(5..8)));
+        assert_eq!((6..8).symmetric_difference(0..5), (Some(0..5), Some(6..8)));
+        assert_eq!((0..5).symmetric_difference(0..5), (None, None));
+        assert_eq!((0..5).symmetric_difference(2..2), (Some(0..5), None));
+        assert_eq!(
+            (0..=5).symmetric_difference(5..=8),
+            (Some(0..=4), Some(6..=8))
+        );
+    }
+
+    #[test]
+    fn test_predicates() {
+        assert!(!RangeExt::<i32>::is_empty_range(&(..)));
+        assert!(!(3..).is_empty_range());
+        assert!(!(..3).is_empty_range());
+        assert!((3..3).is_empty_range());
+        assert!(!(3..=3).is_empty_range());
+        assert!((4..=3).is_empty_range());
+
+        assert!((..).contains_range(&(3..5)));
+        assert!((0..).contains_range(&(3..5)));
+        assert!(!(4..).contains_range(&(3..5)));
+        assert!(!(0..).contains_range(&(..5)));
+        assert!((..5).contains_range(&(..5)));
+        assert!((..=5).contains_range(&(0..5)));
+        assert!(!(..5).contains_range(&(0..=5)));
+        assert!((0..10).contains_range(&(3..5)));
+        assert!((0..10).contains_range(&(20..15)));
+        assert!(!(0..10).contains_range(&(5..15)));
+        assert!((0..=10).contains_range(&(10..=10)));
+        assert!(!(0..=4).contains_range(&(0..5)));
+        assert!((0..5).contains_range(&(0..=4)));
+    }
+
+    #[test]
+    fn test_split_at() {
+        let split = |range: Range<i32>, points: &[i32]| {
+            split_at(range, points.iter().cloned()).collect::<Vec<_>>()
+        };
+        assert_eq!(split(0..10, &[]), vec![0..10]);
+        assert_eq!(split(0..10, &[3, 7]), vec![0..3, 3..7, 7..10]);
+        assert_eq!(split(0..10, &[-5, 0, 3, 3, 10, 12]), vec![0..3, 3..10]);
+        assert_eq!(split(5..5, &[5]), vec![]);
+        assert_eq!(
+            split_at(9..20, (0..).step_by(10)).collect::<Vec<_>>(),
+            vec![9..10, 10..20]
+        );
+    }
+}
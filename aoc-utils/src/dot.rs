@@ -0,0 +1,351 @@
+//! Writing graphs in Graphviz's DOT language.
+//!
+//! A [`Dot`] collects nodes and edges, with any Graphviz attributes you like,
+//! and writes them out for `dot` or `neato` to lay out. Nodes can be any
+//! hashable type; each is labeled with its `Debug` form unless you give it
+//! a label of your own.
+//!
+//! ```
+//! use aoc_utils::dot::Dot;
+//!
+//! let mut dot = Dot::directed();
+//! dot.edges([('C', 'A'), ('C', 'F'), ('A', 'B'), ('B', 'E'), ('F', 'E')]);
+//! dot.node('C').set("shape", "box");
+//! dot.highlight(['C', 'A', 'B', 'E']);
+//! let text = dot.to_string();
+//! assert!(text.contains(r#"n0 -> n1 [color="red", penwidth="2"];"#));
+//! ```
+//!
+//! Search traversals can be drawn directly: [`Dot::edges`] accepts the
+//! edges produced by [`astar`], [`astar_weighted`] and [`breadth_first`], which
+//! makes it easy to see how much of a graph a search explored before finding
+//! its answer.
+//!
+//! By convention, programs that can draw their graphs do so when the
+//! `AOC_DOT` environment variable names a file to write; see [`requested`].
+//! Since the runner times and benchmarks a [`Solution`]'s parsing and parts,
+//! drawing belongs in a separate binary, not in those.
+//!
+//! [`Solution`]: crate::solution::Solution
+//! [`astar`]: crate::astar::astar
+//! [`astar_weighted`]: crate::astar_weighted::astar_weighted
+//! [`breadth_first`]: crate::bfs::breadth_first
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+
+/// A graph to be written in DOT.
+pub struct Dot<N> {
+    directed: bool,
+
+    /// Attributes for the graph as a whole.
+    attributes: Attributes,
+
+    /// The nodes, in the order they were added, and their attributes.
+    nodes: Vec<(N, Attributes)>,
+
+    /// A map from each node to its index in `nodes`.
+    ids: HashMap<N, usize>,
+
+    /// The edges, as indices in `nodes`, and their attributes.
+    edges: Vec<(usize, usize, Attributes)>,
+
+    /// A map from each edge's endpoints to its index in `edges`.
+    edge_ids: HashMap<(usize, usize), usize>,
+}
+
+/// Graphviz attributes for a node, an edge, or the whole graph.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Attributes(Vec<(String, String)>);
+
+impl Attributes {
+    /// Set the attribute `name` to `value`, replacing any earlier value.
+    pub fn set(&mut self, name: &str, value: impl fmt::Display) -> &mut Self {
+        let value = value.to_string();
+        match self.0.iter_mut().find(|(n, _)| n == name) {
+            Some(pair) => pair.1 = value,
+            None => self.0.push((name.to_string(), value)),
+        }
+        self
+    }
+
+    /// Set the `label` attribute.
+    pub fn label(&mut self, label: impl fmt::Display) -> &mut Self {
+        self.set("label", label)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl fmt::Display for Attributes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        f.write_str(" [")?;
+        for (i, (name, value)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{name}={}", quote(value))?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Something that can be drawn as an edge.
+///
+/// This is implemented for `(from, to)` pairs, the `(from, to, path_length)`
+/// triples produced by [`breadth_first`], and the `Edge` types produced by
+/// [`astar`] and [`astar_weighted`].
+///
+/// [`astar`]: crate::astar::astar
+/// [`astar_weighted`]: crate::astar_weighted::astar_weighted
+/// [`breadth_first`]: crate::bfs::breadth_first
+pub trait IntoEdge<N> {
+    fn into_edge(self) -> (N, N);
+}
+
+impl<N> IntoEdge<N> for (N, N) {
+    fn into_edge(self) -> (N, N) {
+        self
+    }
+}
+
+impl<N> IntoEdge<N> for (N, N, usize) {
+    fn into_edge(self) -> (N, N) {
+        (self.0, self.1)
+    }
+}
+
+impl<N> IntoEdge<N> for crate::astar::Edge<N> {
+    fn into_edge(self) -> (N, N) {
+        (self.from, self.to)
+    }
+}
+
+impl<N, W> IntoEdge<N> for crate::astar_weighted::Edge<N, W> {
+    fn into_edge(self) -> (N, N) {
+        (self.from, self.to)
+    }
+}
+
+/// Return the file named by the `AOC_DOT` environment variable, if any, to
+/// which solutions should write their graphs.
+pub fn requested() -> Option<PathBuf> {
+    std::env::var_os("AOC_DOT")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+impl<N> Dot<N>
+where
+    N: Clone + Eq + Hash + fmt::Debug,
+{
+    /// Return an empty directed graph.
+    pub fn directed() -> Self {
+        Dot::new(true)
+    }
+
+    /// Return an empty undirected graph.
+    pub fn undirected() -> Self {
+        Dot::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Dot {
+            directed,
+            attributes: Attributes::default(),
+            nodes: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+            edge_ids: HashMap::new(),
+        }
+    }
+
+    /// Return the attributes of the graph as a whole, like `rankdir`.
+    pub fn graph(&mut self) -> &mut Attributes {
+        &mut self.attributes
+    }
+
+    /// Add `node` to the graph if it isn't already present, and return its
+    /// attributes.
+    pub fn node(&mut self, node: N) -> &mut Attributes {
+        let id = self.id(node);
+        &mut self.nodes[id].1
+    }
+
+    /// Add an edge from `from` to `to` if it isn't already present, along with
+    /// its endpoints, and return its attributes.
+    ///
+    /// In an undirected graph, an edge from `to` to `from` is the same edge.
+    pub fn edge(&mut self, from: N, to: N) -> &mut Attributes {
+        let key = (self.id(from), self.id(to));
+        let index = match self.find_edge(key) {
+            Some(index) => index,
+            None => {
+                self.edges.push((key.0, key.1, Attributes::default()));
+                self.edge_ids.insert(key, self.edges.len() - 1);
+                self.edges.len() - 1
+            }
+        };
+        &mut self.edges[index].2
+    }
+
+    /// Add all of `edges`.
+    pub fn edges<I>(&mut self, edges: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: IntoEdge<N>,
+    {
+        for edge in edges {
+            let (from, to) = edge.into_edge();
+            self.edge(from, to);
+        }
+        self
+    }
+
+    /// Draw the nodes of `path`, and the edges between them, in red.
+    ///
+    /// Steps along the path that aren't edges of the graph are added.
+    pub fn highlight<I>(&mut self, path: I) -> &mut Self
+    where
+        I: IntoIterator<Item = N>,
+    {
+        self.highlight_with(path, "red")
+    }
+
+    /// Draw the nodes of `path`, and the edges between them, in `color`.
+    pub fn highlight_with<I>(&mut self, path: I, color: &str) -> &mut Self
+    where
+        I: IntoIterator<Item = N>,
+    {
+        let mut previous: Option<N> = None;
+        for node in path {
+            self.node(node.clone()).set("color", color);
+            if let Some(previous) = previous {
+                self.edge(previous, node.clone())
+                    .set("color", color)
+                    .set("penwidth", 2);
+            }
+            previous = Some(node);
+        }
+        self
+    }
+
+    /// Write the graph to `path`.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    fn id(&mut self, node: N) -> usize {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+        let mut attributes = Attributes::default();
+        attributes.label(format!("{node:?}"));
+        self.nodes.push((node.clone(), attributes));
+        self.ids.insert(node, self.nodes.len() - 1);
+        self.nodes.len() - 1
+    }
+
+    fn find_edge(&self, (from, to): (usize, usize)) -> Option<usize> {
+        self.edge_ids.get(&(from, to)).copied().or_else(|| {
+            if self.directed {
+                None
+            } else {
+                self.edge_ids.get(&(to, from)).copied()
+            }
+        })
+    }
+}
+
+impl<N> fmt::Display for Dot<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        writeln!(f, "{kind} {{")?;
+        for (name, value) in &self.attributes.0 {
+            writeln!(f, "  {name}={};", quote(value))?;
+        }
+        for (id, (_, attributes)) in self.nodes.iter().enumerate() {
+            writeln!(f, "  n{id}{attributes};")?;
+        }
+        for (from, to, attributes) in &self.edges {
+            writeln!(f, "  n{from} {arrow} n{to}{attributes};")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// Return `text` as a DOT quoted string.
+fn quote(text: &str) -> String {
+    let mut quoted = String::from('"');
+    for ch in text.chars() {
+        match ch {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(ch);
+            }
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_undirected() {
+        let mut dot = Dot::undirected();
+        dot.graph().set("layout", "neato");
+        dot.edge("start", "A").label(3);
+        dot.edge("A", "start").set("style", "dashed");
+        dot.node("A").label("big \"A\"");
+        assert_eq!(
+            dot.to_string(),
+            "graph {\n\
+             \x20 layout=\"neato\";\n\
+             \x20 n0 [label=\"\\\"start\\\"\"];\n\
+             \x20 n1 [label=\"big \\\"A\\\"\"];\n\
+             \x20 n0 -- n1 [label=\"3\", style=\"dashed\"];\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn test_search() {
+        // A breadth-first search of a line of five nodes, from the middle.
+        let mut dot = Dot::directed();
+        dot.edges(crate::bfs::breadth_first(2_i32, |&n| {
+            [n - 1, n + 1].into_iter().filter(|n| (0..5).contains(n))
+        }));
+        dot.highlight([2, 3, 4]);
+        let text = dot.to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[1], r#"  n0 [label="2", color="red"];"#);
+        assert_eq!(lines[3], r#"  n2 [label="3", color="red"];"#);
+        assert_eq!(lines[4], r#"  n3 [label="0"];"#);
+        assert!(lines.contains(&r#"  n0 -> n2 [color="red", penwidth="2"];"#));
+        assert!(lines.contains(&"  n2 -> n0;"));
+        // Five nodes; and edges 2->1, 2->3, 1->0, 1->2, 3->2, 3->4,
+        // 0->1, 4->3.
+        assert_eq!(dot.nodes.len(), 5);
+        assert_eq!(dot.edges.len(), 8);
+    }
+}
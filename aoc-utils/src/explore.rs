@@ -0,0 +1,420 @@
+//! Recording where a search spends its effort.
+//!
+//! When [`astar`] or [`astar_weighted`] takes too long, the question is
+//! usually where it's looking. Wrapping the search iterator with [`record`]
+//! notes, for each node the search reaches, when it was popped from the queue,
+//! the cost of the path that reached it, and the heuristic's estimate of the
+//! remaining cost:
+//!
+//! ```
+//! use aoc_utils::astar::astar;
+//! use aoc_utils::explore::{self, Measure};
+//!
+//! // Search a 5x5 grid for its far corner.
+//! let mut search = explore::record(astar((0_usize, 0_usize), |&(r, c)| {
+//!     [(r + 1, c), (r, c + 1)]
+//!         .into_iter()
+//!         .filter(|&(r, c)| r < 5 && c < 5)
+//!         .map(|(r, c)| ((r, c), 8 - r - c))
+//! }));
+//! let found = search.find(|edge| edge.to == (4, 4)).unwrap();
+//! assert_eq!(found.path_length, 8);
+//!
+//! assert_eq!(search.visits()[&(4, 4)].cost, 8);
+//! let heatmap = search.heatmap(|&node| node, Measure::Order);
+//! assert_eq!(heatmap.dim(), (5, 5));
+//! ```
+//!
+//! For searches over grids, a [`Heatmap`] shows any of those measures as a
+//! color for each cell, either printed with ANSI escapes or, with the
+//! `render` feature, drawn as an image.
+//!
+//! Recording costs a hash table entry for every node visited, so a solution
+//! shouldn't record its searches when it's being timed. Instead, a separate
+//! binary can record the same search and save its heatmap to the file named
+//! by `AOC_HEATMAP`; see [`requested`].
+//!
+//! [`astar`]: crate::astar::astar
+//! [`astar_weighted`]: crate::astar_weighted::astar_weighted
+
+use ndarray::Array2;
+use num_traits::AsPrimitive;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::path::PathBuf;
+
+/// An edge produced by a search iterator.
+///
+/// This is implemented for the edges produced by [`astar`],
+/// [`astar_weighted`] and [`breadth_first`]. Breadth-first searches have no
+/// heuristic, so their estimates are always zero.
+///
+/// [`astar`]: crate::astar::astar
+/// [`astar_weighted`]: crate::astar_weighted::astar_weighted
+/// [`breadth_first`]: crate::bfs::breadth_first
+pub trait Step {
+    type Node;
+    type Cost;
+
+    /// The node this edge arrives at.
+    fn to(&self) -> &Self::Node;
+
+    /// The cost of the path from the start to `to`, along this edge.
+    fn cost(&self) -> Self::Cost;
+
+    /// The estimated cost of the rest of the path from `to` to the end.
+    fn estimate(&self) -> Self::Cost;
+}
+
+impl<N> Step for crate::astar::Edge<N> {
+    type Node = N;
+    type Cost = usize;
+
+    fn to(&self) -> &N {
+        &self.to
+    }
+
+    fn cost(&self) -> usize {
+        self.path_length
+    }
+
+    fn estimate(&self) -> usize {
+        self.estimate
+    }
+}
+
+impl<N, W: Clone> Step for crate::astar_weighted::Edge<N, W> {
+    type Node = N;
+    type Cost = W;
+
+    fn to(&self) -> &N {
+        &self.to
+    }
+
+    fn cost(&self) -> W {
+        self.path_weight.clone()
+    }
+
+    fn estimate(&self) -> W {
+        self.estimate.clone()
+    }
+}
+
+impl<N> Step for (N, N, usize) {
+    type Node = N;
+    type Cost = usize;
+
+    fn to(&self) -> &N {
+        &self.1
+    }
+
+    fn cost(&self) -> usize {
+        self.2
+    }
+
+    fn estimate(&self) -> usize {
+        0
+    }
+}
+
+/// What a search knew about a node when it first reached it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Visit<W> {
+    /// The number of edges popped before the first one to reach this node.
+    pub order: usize,
+
+    /// The cost of the path that first reached this node.
+    pub cost: W,
+
+    /// The heuristic's estimate of the cost from this node to the end.
+    pub estimate: W,
+}
+
+/// Which part of a [`Visit`] a [`Heatmap`] should show.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Measure {
+    Order,
+    Cost,
+    Estimate,
+
+    /// The cost plus the estimate: the search's guess at the length of the
+    /// whole path through this node.
+    Total,
+}
+
+/// A search iterator that records each node it reaches.
+///
+/// This iterates over the same edges as the search it wraps.
+pub struct Explored<I, N, W> {
+    search: I,
+
+    /// The number of edges popped so far.
+    popped: usize,
+
+    visits: HashMap<N, Visit<W>>,
+}
+
+/// Wrap `search` to record its progress.
+pub fn record<I, N, W>(search: I) -> Explored<I, N, W>
+where
+    I: Iterator,
+    I::Item: Step<Node = N, Cost = W>,
+{
+    Explored {
+        search,
+        popped: 0,
+        visits: HashMap::new(),
+    }
+}
+
+/// Return the file named by the `AOC_HEATMAP` environment variable, if any, to
+/// which programs should write heatmaps of their searches.
+pub fn requested() -> Option<PathBuf> {
+    std::env::var_os("AOC_HEATMAP")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+impl<I, N, W> Iterator for Explored<I, N, W>
+where
+    I: Iterator,
+    I::Item: Step<Node = N, Cost = W>,
+    N: Clone + Eq + Hash,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let edge = self.search.next()?;
+        let order = self.popped;
+        self.popped += 1;
+        self.visits
+            .entry(edge.to().clone())
+            .or_insert_with(|| Visit {
+                order,
+                cost: edge.cost(),
+                estimate: edge.estimate(),
+            });
+        Some(edge)
+    }
+}
+
+impl<I, N, W> Explored<I, N, W>
+where
+    N: Eq + Hash,
+{
+    /// Return the number of edges popped so far.
+    pub fn popped(&self) -> usize {
+        self.popped
+    }
+
+    /// Return the nodes reached so far, and what we know about each one.
+    ///
+    /// The start node is not included, since no edge leads to it.
+    pub fn visits(&self) -> &HashMap<N, Visit<W>> {
+        &self.visits
+    }
+
+    /// Return a heatmap of `measure` for the nodes visited so far.
+    ///
+    /// The `position` function gives each node's `(row, column)` position
+    /// in the grid. The grid is just large enough to hold every node
+    /// visited.
+    pub fn heatmap<P>(&self, mut position: P, measure: Measure) -> Heatmap
+    where
+        P: FnMut(&N) -> (usize, usize),
+        W: AsPrimitive<f64>,
+    {
+        let cells: Vec<((usize, usize), f64)> = self
+            .visits
+            .iter()
+            .map(|(node, visit)| {
+                let value = match measure {
+                    Measure::Order => visit.order as f64,
+                    Measure::Cost => visit.cost.as_(),
+                    Measure::Estimate => visit.estimate.as_(),
+                    Measure::Total => visit.cost.as_() + visit.estimate.as_(),
+                };
+                (position(node), value)
+            })
+            .collect();
+        Heatmap::new(cells)
+    }
+}
+
+/// A grid of values, shown as colors.
+///
+/// Values are scaled so that the smallest is drawn dark blue and the
+/// largest pale yellow. Cells with no value are left blank.
+#[derive(Clone, Debug)]
+pub struct Heatmap {
+    /// Each cell's value, scaled to fall between 0 and 1.
+    cells: Array2<Option<f64>>,
+}
+
+impl Heatmap {
+    /// Return a heatmap showing `cells`, a series of `((row, column), value)`
+    /// pairs.
+    pub fn new<I>(cells: I) -> Heatmap
+    where
+        I: IntoIterator<Item = ((usize, usize), f64)>,
+    {
+        let cells: Vec<_> = cells.into_iter().collect();
+        let rows = cells
+            .iter()
+            .map(|&((row, _), _)| row + 1)
+            .max()
+            .unwrap_or(0);
+        let columns = cells
+            .iter()
+            .map(|&((_, col), _)| col + 1)
+            .max()
+            .unwrap_or(0);
+        let min = cells.iter().map(|&(_, v)| v).fold(f64::INFINITY, f64::min);
+        let max = cells
+            .iter()
+            .map(|&(_, v)| v)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let range = if max > min { max - min } else { 1.0 };
+
+        let mut grid = Array2::from_elem((rows, columns), None);
+        for (at, value) in cells {
+            grid[at] = Some((value - min) / range);
+        }
+        Heatmap { cells: grid }
+    }
+
+    /// Return the heatmap's dimensions, as `(rows, columns)`.
+    pub fn dim(&self) -> (usize, usize) {
+        self.cells.dim()
+    }
+
+    /// Return the color of the cell at `(row, column)`, or `None` if it has
+    /// no value.
+    pub fn color(&self, at: (usize, usize)) -> Option<[u8; 3]> {
+        self.cells.get(at).copied().flatten().map(ramp)
+    }
+
+    /// Draw the heatmap on a [`Raster`] no more than `max_size` pixels on a
+    /// side, with blank cells drawn as `background`.
+    ///
+    /// [`Raster`]: crate::render::Raster
+    #[cfg(feature = "render")]
+    pub fn to_raster(
+        &self,
+        max_size: usize,
+        background: crate::render::Rgb<u8>,
+    ) -> crate::render::Raster {
+        use crate::render::{Raster, Rgb};
+        Raster::from_grid(&self.cells, max_size, |value| {
+            value.map_or(background, |value| Rgb(ramp(value)))
+        })
+    }
+
+    /// Write the heatmap to `path` as a PNG image, no more than 1000 pixels
+    /// on a side.
+    #[cfg(feature = "render")]
+    pub fn save(&self, path: &std::path::Path) -> anyhow::Result<()> {
+        self.to_raster(1000, crate::render::Rgb([0, 0, 0]))
+            .save(path)
+    }
+}
+
+/// Show the heatmap in the terminal, using ANSI escapes to set each cell's
+/// background color. Each cell is two characters wide, to keep it roughly
+/// square.
+impl fmt::Display for Heatmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.rows() {
+            for value in row {
+                match value {
+                    Some(value) => {
+                        let [r, g, b] = ramp(*value);
+                        write!(f, "\x1b[48;2;{r};{g};{b}m  ")?;
+                    }
+                    None => write!(f, "\x1b[0m  ")?,
+                }
+            }
+            writeln!(f, "\x1b[0m")?;
+        }
+        Ok(())
+    }
+}
+
+/// Return the color for `value`, which must fall between 0 and 1.
+fn ramp(value: f64) -> [u8; 3] {
+    // Dark blue, through purple and orange, to pale yellow.
+    const STOPS: [[f64; 3]; 5] = [
+        [13.0, 8.0, 135.0],
+        [126.0, 3.0, 168.0],
+        [204.0, 71.0, 120.0],
+        [248.0, 149.0, 64.0],
+        [240.0, 249.0, 33.0],
+    ];
+    let position = value.clamp(0.0, 1.0) * (STOPS.len() - 1) as f64;
+    let index = (position as usize).min(STOPS.len() - 2);
+    let fraction = position - index as f64;
+    let (low, high) = (STOPS[index], STOPS[index + 1]);
+    std::array::from_fn(|i| (low[i] + (high[i] - low[i]) * fraction).round() as u8)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::astar_weighted::astar_weighted;
+
+    #[test]
+    fn test_record() {
+        // A 3x3 grid where the cheapest path runs along the top and right
+        // edges, searched from the top left corner for the bottom right.
+        let weights = [[1, 1, 1], [5, 9, 1], [1, 1, 1]];
+        let mut search = record(astar_weighted((0_usize, 0_usize), |&(r, c)| {
+            [(r + 1, c), (r, c + 1)]
+                .into_iter()
+                .filter(|&(r, c)| r < 3 && c < 3)
+                .map(|(r, c)| ((r, c), weights[r][c], 4 - r - c))
+        }));
+        let found = search.find(|edge| edge.to == (2, 2)).unwrap();
+        assert_eq!(found.path_weight, 4);
+
+        let visits = search.visits();
+        assert_eq!(
+            visits[&(0, 1)],
+            Visit {
+                order: 0,
+                cost: 1,
+                estimate: 3
+            }
+        );
+        assert_eq!(visits[&(2, 2)].order, 3);
+        assert_eq!(visits[&(2, 2)].cost, 4);
+        assert!(!visits.contains_key(&(1, 0)));
+        assert!(!visits.contains_key(&(1, 1)));
+        assert_eq!(search.popped(), 4);
+
+        let heatmap = search.heatmap(|&node| node, Measure::Cost);
+        assert_eq!(heatmap.dim(), (3, 3));
+        assert_eq!(heatmap.color((0, 0)), None);
+        assert_eq!(heatmap.color((1, 1)), None);
+        assert_eq!(heatmap.color((0, 1)), Some([13, 8, 135]));
+        assert_eq!(heatmap.color((2, 2)), Some([240, 249, 33]));
+    }
+
+    #[test]
+    fn test_display() {
+        let heatmap = Heatmap::new([((0, 0), 0.0), ((0, 1), 10.0), ((1, 1), 5.0)]);
+        assert_eq!(
+            heatmap.to_string(),
+            "\x1b[48;2;13;8;135m  \x1b[48;2;240;249;33m  \x1b[0m\n\
+             \x1b[0m  \x1b[48;2;204;71;120m  \x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_ramp() {
+        assert_eq!(ramp(0.0), [13, 8, 135]);
+        assert_eq!(ramp(0.125), [70, 6, 152]);
+        assert_eq!(ramp(1.0), [240, 249, 33]);
+        assert_eq!(ramp(2.0), [240, 249, 33]);
+    }
+}
@@ -0,0 +1,376 @@
+//! A per-user cache of puzzle inputs, filled on demand.
+//!
+//! A [`Store`] looks for inputs in a cache directory outside the source tree,
+//! by default `~/.cache/aoc-inputs/<year>/day<NN>.txt`. When an input isn't
+//! there, the store asks its [`Fetcher`] for it and saves the result, so each
+//! puzzle's input is fetched at most once.
+//!
+//! Any [`Inputs`] directory can serve as a fetcher. With the `http` feature,
+//! [`HttpFetcher`] downloads inputs from the Advent of Code site, using the
+//! session cookie from a logged-in browser.
+
+use crate::input::Inputs;
+use anyhow::Context;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A way to get puzzle inputs that aren't in the cache.
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> anyhow::Result<String>;
+}
+
+/// Copy inputs from a directory laid out like the workspace's `inputs`.
+impl Fetcher for Inputs {
+    fn fetch(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        Ok(self.load(year, day, &crate::input::Source::Default)?)
+    }
+}
+
+impl<F: Fetcher + ?Sized> Fetcher for Box<F> {
+    fn fetch(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        (**self).fetch(year, day)
+    }
+}
+
+/// Use the fetcher if there is one. A `Store` with no fetcher can still
+/// serve the inputs already in its cache.
+impl<F: Fetcher> Fetcher for Option<F> {
+    fn fetch(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        match self {
+            Some(fetcher) => fetcher.fetch(year, day),
+            None => anyhow::bail!("no way to fetch inputs is configured"),
+        }
+    }
+}
+
+/// A cache directory of puzzle inputs, backed by a [`Fetcher`].
+#[derive(Debug)]
+pub struct Store<F> {
+    cache: Inputs,
+    fetcher: F,
+}
+
+impl<F: Fetcher> Store<F> {
+    pub fn new<P: Into<PathBuf>>(cache: P, fetcher: F) -> Self {
+        Store {
+            cache: Inputs::new(cache),
+            fetcher,
+        }
+    }
+
+    /// Return a store using the per-user cache directory; see
+    /// [`default_cache_dir`].
+    pub fn with_default_cache(fetcher: F) -> anyhow::Result<Self> {
+        Ok(Store::new(default_cache_dir()?, fetcher))
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        self.cache.dir()
+    }
+
+    pub fn fetcher(&self) -> &F {
+        &self.fetcher
+    }
+
+    /// Return the path at which the input for `year` and `day` is cached.
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache.path(year, day)
+    }
+
+    /// Return true if the input for `year` and `day` is already cached.
+    pub fn is_cached(&self, year: u16, day: u8) -> bool {
+        self.path(year, day).is_file()
+    }
+
+    /// Return the input for `year` and `day`, fetching and caching it if
+    /// necessary.
+    pub fn get(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        let path = self.path(year, day);
+        match std::fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("couldn't read cached input {}", path.display()));
+            }
+        }
+
+        let input = self
+            .fetcher
+            .fetch(year, day)
+            .with_context(|| format!("couldn't fetch input for {year} day {day}"))?;
+        if input.is_empty() {
+            anyhow::bail!("fetched an empty input for {year} day {day}");
+        }
+        save(&path, &input)
+            .with_context(|| format!("couldn't cache input at {}", path.display()))?;
+        Ok(input)
+    }
+}
+
+/// Write `contents` to `path` so that readers never see a partial file.
+fn save(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let temp = path.with_extension(format!("tmp.{}", std::process::id()));
+    std::fs::write(&temp, contents)?;
+    std::fs::rename(&temp, path)
+}
+
+/// Return the per-user directory in which to cache puzzle inputs.
+///
+/// This is `$AOC_CACHE` if that is set, and otherwise `aoc-inputs` in the
+/// user's cache directory: `$XDG_CACHE_HOME`, or `~/.cache`.
+pub fn default_cache_dir() -> anyhow::Result<PathBuf> {
+    if let Some(dir) = std::env::var_os("AOC_CACHE") {
+        return Ok(PathBuf::from(dir));
+    }
+    let base = match std::env::var_os("XDG_CACHE_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => std::env::home_dir()
+            .context("can't find a cache directory for inputs; please set AOC_CACHE")?
+            .join(".cache"),
+    };
+    Ok(base.join("aoc-inputs"))
+}
+
+#[cfg(feature = "http")]
+pub use http::HttpFetcher;
+
+#[cfg(feature = "http")]
+mod http {
+    use super::Fetcher;
+    use anyhow::{Context, bail};
+    use std::fmt;
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    /// Fetch puzzle inputs over HTTP from the Advent of Code site, or
+    /// anything that serves `<base>/<year>/day/<day>/input` the same way.
+    ///
+    /// Consecutive requests are spaced at least [`min_interval`] apart, to
+    /// go easy on the server.
+    ///
+    /// [`min_interval`]: HttpFetcher::min_interval
+    pub struct HttpFetcher {
+        base_url: String,
+        session: String,
+        min_interval: Duration,
+        last_request: Mutex<Option<Instant>>,
+    }
+
+    impl HttpFetcher {
+        pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+        pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+        /// Return a fetcher that authenticates with the session cookie
+        /// `session`.
+        pub fn new(session: &str) -> Self {
+            HttpFetcher {
+                base_url: Self::DEFAULT_BASE_URL.to_string(),
+                session: session.trim().to_string(),
+                min_interval: Self::DEFAULT_MIN_INTERVAL,
+                last_request: Mutex::new(None),
+            }
+        }
+
+        /// Return a fetcher using the session cookie in the `AOC_SESSION`
+        /// environment variable, or `None` if that isn't set. If
+        /// `AOC_BASE_URL` is set, fetch from there instead of the real site.
+        pub fn from_env() -> Option<Self> {
+            let session = std::env::var("AOC_SESSION").ok()?;
+            let mut fetcher = HttpFetcher::new(&session);
+            if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+                fetcher = fetcher.base_url(&base_url);
+            }
+            Some(fetcher)
+        }
+
+        pub fn base_url(self, base_url: &str) -> Self {
+            HttpFetcher {
+                base_url: base_url.trim_end_matches('/').to_string(),
+                ..self
+            }
+        }
+
+        /// Wait at least `min_interval` between requests.
+        pub fn min_interval(self, min_interval: Duration) -> Self {
+            HttpFetcher {
+                min_interval,
+                ..self
+            }
+        }
+
+        pub fn url(&self, year: u16, day: u8) -> String {
+            format!("{}/{year}/day/{day}/input", self.base_url)
+        }
+
+        /// Sleep until we're allowed to make another request, and note
+        /// that we're making it.
+        fn wait_turn(&self) {
+            let mut last = self.last_request.lock().unwrap();
+            if let Some(last) = *last {
+                let ready = last + self.min_interval;
+                let now = Instant::now();
+                if now < ready {
+                    std::thread::sleep(ready - now);
+                }
+            }
+            *last = Some(Instant::now());
+        }
+    }
+
+    // Leave the session cookie out of debugging output.
+    impl fmt::Debug for HttpFetcher {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("HttpFetcher")
+                .field("base_url", &self.base_url)
+                .field("min_interval", &self.min_interval)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl Fetcher for HttpFetcher {
+        fn fetch(&self, year: u16, day: u8) -> anyhow::Result<String> {
+            let url = self.url(year, day);
+            self.wait_turn();
+            let response = ureq::get(&url)
+                .header("Cookie", &format!("session={}", self.session))
+                .header(
+                    "User-Agent",
+                    "aoc-utils (github.com/jimblandy/advent-of-code-2021)",
+                )
+                .call();
+            let mut response = match response {
+                Ok(response) => response,
+                Err(ureq::Error::StatusCode(404)) => {
+                    bail!("{url}: not found; is the puzzle unlocked yet?")
+                }
+                Err(ureq::Error::StatusCode(status @ (400 | 500))) => {
+                    bail!("{url}: server returned {status}; has the session cookie expired?")
+                }
+                Err(err) => return Err(err).with_context(|| format!("request for {url} failed")),
+            };
+            response
+                .body_mut()
+                .read_to_string()
+                .with_context(|| format!("couldn't read response from {url}"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// A fetcher that counts how often it's asked.
+    struct Counter(Cell<usize>);
+
+    impl Fetcher for Counter {
+        fn fetch(&self, year: u16, day: u8) -> anyhow::Result<String> {
+            self.0.set(self.0.get() + 1);
+            if day > 25 {
+                anyhow::bail!("no such day");
+            }
+            Ok(format!("{year} {day}\n"))
+        }
+    }
+
+    #[test]
+    fn test_store() {
+        let dir = temp_dir("store");
+        let store = Store::new(&dir, Counter(Cell::new(0)));
+        assert!(!store.is_cached(2021, 6));
+        assert_eq!(store.get(2021, 6).unwrap(), "2021 6\n");
+        assert!(store.is_cached(2021, 6));
+        assert_eq!(store.get(2021, 6).unwrap(), "2021 6\n");
+        assert_eq!(store.fetcher().0.get(), 1);
+        assert_eq!(
+            std::fs::read_to_string(dir.join("2021/day06.txt")).unwrap(),
+            "2021 6\n"
+        );
+
+        assert!(store.get(2021, 26).is_err());
+        assert!(!store.is_cached(2021, 26));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_inputs_fetcher() {
+        let source = temp_dir("fetch-source");
+        std::fs::create_dir_all(source.join("2018")).unwrap();
+        std::fs::write(source.join("2018/day01.txt"), "+1\n-2\n").unwrap();
+
+        let cache = temp_dir("fetch-cache");
+        let store = Store::new(&cache, Inputs::new(&source));
+        assert_eq!(store.get(2018, 1).unwrap(), "+1\n-2\n");
+        assert!(store.get(2018, 2).is_err());
+
+        // Once cached, the source doesn't matter.
+        std::fs::remove_dir_all(&source).unwrap();
+        assert_eq!(store.get(2018, 1).unwrap(), "+1\n-2\n");
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_http_fetcher() {
+        use std::time::{Duration, Instant};
+
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let stub = std::thread::spawn(move || {
+            let mut requests = vec![];
+            for request in server.incoming_requests().take(3) {
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string());
+                requests.push((request.url().to_string(), cookie));
+                let response = match request.url() {
+                    "/2021/day/6/input" => tiny_http::Response::from_string("3,4,3,1,2\n"),
+                    _ => tiny_http::Response::from_string("nope").with_status_code(404),
+                };
+                request.respond(response).unwrap();
+            }
+            requests
+        });
+
+        let fetcher = HttpFetcher::new("c0ffee\n")
+            .base_url(&format!("{base_url}/"))
+            .min_interval(Duration::from_millis(100));
+        assert!(!format!("{fetcher:?}").contains("c0ffee"));
+        assert_eq!(fetcher.url(2021, 6), format!("{base_url}/2021/day/6/input"));
+
+        let dir = temp_dir("http");
+        let store = Store::new(&dir, fetcher);
+        let start = Instant::now();
+        assert_eq!(store.get(2021, 6).unwrap(), "3,4,3,1,2\n");
+        assert_eq!(store.get(2021, 6).unwrap(), "3,4,3,1,2\n");
+        let err = store.get(2021, 7).unwrap_err();
+        assert!(format!("{err:#}").contains("unlocked"));
+        assert!(store.get(2021, 8).is_err());
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        let requests = stub.join().unwrap();
+        let cookie = Some("session=c0ffee".to_string());
+        assert_eq!(
+            requests,
+            vec![
+                ("/2021/day/6/input".to_string(), cookie.clone()),
+                ("/2021/day/7/input".to_string(), cookie.clone()),
+                ("/2021/day/8/input".to_string(), cookie),
+            ]
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,210 @@
+//! Loading puzzle inputs at run time.
+//!
+//! Puzzle inputs are personal, so rather than building them into the
+//! binaries, we read them when we run. By default, the input for a given
+//! puzzle is in `inputs/<year>/day<NN>.txt` at the top of the workspace,
+//! with the day zero-padded to two digits. Setting the `AOC_INPUTS`
+//! environment variable selects a different directory to look in.
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Where to get the input for a puzzle.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum Source {
+    /// The puzzle's usual file in the inputs directory.
+    #[default]
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Interpret a command-line argument naming an input file. As usual, `-`
+    /// means standard input.
+    pub fn from_arg(arg: &str) -> Source {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+}
+
+/// A directory of puzzle inputs, organized by year.
+#[derive(Clone, Debug)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Default for Inputs {
+    fn default() -> Self {
+        let dir = match std::env::var_os("AOC_INPUTS") {
+            Some(dir) => PathBuf::from(dir),
+            None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs"),
+        };
+        Inputs { dir }
+    }
+}
+
+impl Inputs {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Inputs { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Return the path at which we expect the input for `year` and `day`.
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    /// Return the path of the recorded answers for `year`'s puzzles; see
+    /// [`answers`](crate::answers).
+    pub fn answers_path(&self, year: u16) -> PathBuf {
+        self.dir.join(year.to_string()).join("answers.toml")
+    }
+
+    /// Return the input for `year` and `day`, read from `source`.
+    pub fn load(&self, year: u16, day: u8, source: &Source) -> Result<String, Error> {
+        match source {
+            Source::Default => {
+                let path = self.path(year, day);
+                std::fs::read_to_string(&path).map_err(|err| {
+                    if err.kind() == io::ErrorKind::NotFound {
+                        Error::Missing { year, day, path }
+                    } else {
+                        Error::Unreadable { path, err }
+                    }
+                })
+            }
+            Source::File(path) => std::fs::read_to_string(path).map_err(|err| Error::Unreadable {
+                path: path.clone(),
+                err,
+            }),
+            Source::Stdin => io::read_to_string(io::stdin()).map_err(Error::Stdin),
+        }
+    }
+}
+
+/// Return the input for `year` and `day`, for a program that solves only
+/// that puzzle.
+///
+/// If the program was given a command-line argument, treat it as a path
+/// to read the input from, or `-` for standard input. Otherwise, use the
+/// puzzle's default input file. If the input can't be read, print an error
+/// message and exit.
+pub fn from_command_line(year: u16, day: u8) -> String {
+    let source = match std::env::args().nth(1) {
+        Some(arg) => Source::from_arg(&arg),
+        None => Source::Default,
+    };
+    Inputs::default()
+        .load(year, day, &source)
+        .unwrap_or_else(|err| {
+            eprintln!("error: {err}");
+            std::process::exit(1);
+        })
+}
+
+/// An error reading a puzzle's input.
+#[derive(Debug)]
+pub enum Error {
+    /// There is no input in the usual place for this puzzle.
+    Missing {
+        year: u16,
+        day: u8,
+        path: PathBuf,
+    },
+
+    /// An input file exists, or was named explicitly, but we couldn't read it.
+    Unreadable {
+        path: PathBuf,
+        err: io::Error,
+    },
+
+    Stdin(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Missing { year, day, path } => write!(
+                f,
+                "no input for {year} day {day}: expected to find it in {}; \
+                 save your puzzle input there, or supply a path, or '-' for standard input",
+                path.display()
+            ),
+            Error::Unreadable { path, err } => {
+                write!(f, "couldn't read input file {}: {err}", path.display())
+            }
+            Error::Stdin(err) => write!(f, "couldn't read input from standard input: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Missing { .. } => None,
+            Error::Unreadable { err, .. } | Error::Stdin(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("2018")).unwrap();
+        std::fs::write(dir.join("2018/day05.txt"), "dabAcCaCBAcCcaDA\n").unwrap();
+
+        let inputs = Inputs::new(&dir);
+        assert_eq!(inputs.path(2018, 5), dir.join("2018").join("day05.txt"));
+        assert_eq!(
+            inputs.load(2018, 5, &Source::Default).unwrap(),
+            "dabAcCaCBAcCcaDA\n"
+        );
+        assert_eq!(
+            inputs
+                .load(2025, 25, &Source::File(dir.join("2018/day05.txt")))
+                .unwrap(),
+            "dabAcCaCBAcCcaDA\n"
+        );
+
+        let err = inputs.load(2018, 6, &Source::Default).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Missing {
+                year: 2018,
+                day: 6,
+                ..
+            }
+        ));
+        assert!(err.to_string().contains("day06.txt"));
+
+        let err = inputs
+            .load(2018, 5, &Source::File(dir.join("nonesuch")))
+            .unwrap_err();
+        assert!(matches!(err, Error::Unreadable { .. }));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_source() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("day05.txt"),
+            Source::File(PathBuf::from("day05.txt"))
+        );
+    }
+}
@@ -0,0 +1,261 @@
+//! Sets of values represented as sorted lists of disjoint ranges.
+
+use crate::enclose::Enclose;
+use crate::intersection::Intersection;
+use num_traits::{CheckedAdd, CheckedSub, One, Zero};
+use std::ops::{Range, RangeInclusive};
+
+/// A set of `Idx` values, stored as a sorted list of half-open ranges.
+///
+/// The ranges are non-empty, and no two of them overlap or even touch: adjacent
+/// ranges are always merged. Since this representation is canonical, two sets
+/// are equal exactly when they contain the same values.
+///
+/// Methods that accept ranges take anything implementing [`Interval`], so both
+/// `a..b` and `a..=b` work.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IntervalSet<Idx> {
+    ranges: Vec<Range<Idx>>,
+}
+
+/// A range type that [`IntervalSet`] can accept.
+pub trait Interval<Idx> {
+    /// Return the half-open range covering the same values as `self`.
+    fn into_range(self) -> Range<Idx>;
+}
+
+impl<Idx> Interval<Idx> for Range<Idx> {
+    fn into_range(self) -> Range<Idx> {
+        self
+    }
+}
+
+impl<Idx: CheckedAdd + One> Interval<Idx> for RangeInclusive<Idx> {
+    /// Convert `self` to a half-open range.
+    ///
+    /// Panic if the end of `self` is the greatest value of `Idx`, since the
+    /// half-open range would have no representable end.
+    fn into_range(self) -> Range<Idx> {
+        let (start, end) = self.into_inner();
+        let end = end
+            .checked_add(&Idx::one())
+            .expect("IntervalSet: inclusive range ends at maximum value");
+        start..end
+    }
+}
+
+impl<Idx: Copy + Ord> IntervalSet<Idx> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: vec![] }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Return an iterator over the ranges making up `self`, in increasing order.
+    pub fn ranges(&self) -> impl Iterator<Item = &Range<Idx>> + Clone {
+        self.ranges.iter()
+    }
+
+    pub fn contains(&self, value: &Idx) -> bool {
+        let p = self.ranges.partition_point(|r| r.end <= *value);
+        self.ranges.get(p).is_some_and(|r| r.contains(value))
+    }
+
+    /// Return true if any value in `range` is in `self`.
+    pub fn overlaps<R: Interval<Idx>>(&self, range: R) -> bool {
+        let range = range.into_range();
+        if range.is_empty() {
+            return false;
+        }
+
+        let p = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges.get(p).is_some_and(|r| r.start < range.end)
+    }
+
+    /// Add the values in `range` to `self`.
+    pub fn insert<R: Interval<Idx>>(&mut self, range: R) {
+        let range = range.into_range();
+        if range.is_empty() {
+            return;
+        }
+
+        // Find the ranges that overlap or touch `range`, and replace them
+        // all with a single range that encloses them.
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let end = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = self.ranges[first..end]
+            .iter()
+            .fold(range, |merged, r| merged.enclose(r.clone()));
+        self.ranges.splice(first..end, [merged]);
+    }
+
+    /// Remove the values in `range` from `self`.
+    pub fn remove<R: Interval<Idx>>(&mut self, range: R) {
+        let range = range.into_range();
+        if range.is_empty() {
+            return;
+        }
+
+        // Only the first and last overlapping ranges can stick out past
+        // `range`; everything in between is simply dropped.
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let end = self.ranges.partition_point(|r| r.start < range.end);
+        if first == end {
+            return;
+        }
+
+        let mut leftovers = Vec::with_capacity(2);
+        let head = &self.ranges[first];
+        if head.start < range.start {
+            leftovers.push(head.start..range.start);
+        }
+        let tail = &self.ranges[end - 1];
+        if range.end < tail.end {
+            leftovers.push(range.end..tail.end);
+        }
+        self.ranges.splice(first..end, leftovers);
+    }
+
+    /// Return the set of values in either `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in &other.ranges {
+            result.insert(range.clone());
+        }
+        result
+    }
+
+    /// Return the set of values in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let a = &self.ranges[i];
+            let b = &other.ranges[j];
+            let meet = a.clone().intersection(b.clone());
+            if !meet.is_empty() {
+                ranges.push(meet);
+            }
+
+            // Whichever range ends first can't overlap anything else.
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Return the set of values in `bound` that are not in `self`.
+    pub fn complement<R: Interval<Idx>>(&self, bound: R) -> Self {
+        let bound = bound.into_range();
+        let mut ranges = vec![];
+        let mut next = bound.start;
+        for range in &self.ranges {
+            let range = range.clone().intersection(bound.clone());
+            if range.is_empty() {
+                continue;
+            }
+            if next < range.start {
+                ranges.push(next..range.start);
+            }
+            next = range.end;
+        }
+        if next < bound.end {
+            ranges.push(next..bound.end);
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Return the number of values in `self`, or `None` if that can't be
+    /// represented as an `Idx`.
+    pub fn len(&self) -> Option<Idx>
+    where
+        Idx: Zero + CheckedAdd + CheckedSub,
+    {
+        self.ranges.iter().try_fold(Idx::zero(), |sum, r| {
+            sum.checked_add(&r.end.checked_sub(&r.start)?)
+        })
+    }
+}
+
+impl<Idx: Copy + Ord, R: Interval<Idx>> Extend<R> for IntervalSet<Idx> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<Idx: Copy + Ord, R: Interval<Idx>> FromIterator<R> for IntervalSet<Idx> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ranges<Idx: Copy + Ord>(set: &IntervalSet<Idx>) -> Vec<Range<Idx>> {
+        set.ranges().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        // The fresh ingredient ranges from 2025 day 5.
+        let fresh: IntervalSet<usize> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+        assert_eq!(ranges(&fresh), vec![3..6, 10..21]);
+        assert_eq!(fresh.len(), Some(14));
+        let available = [1, 5, 8, 11, 17, 32];
+        assert_eq!(available.iter().filter(|i| fresh.contains(i)).count(), 3);
+
+        let mut set = IntervalSet::new();
+        set.insert(0..2);
+        set.insert(4..6);
+        set.insert(2..4); // touching on both sides
+        assert_eq!(ranges(&set), vec![0..6]);
+        set.insert(7..7);
+        assert_eq!(ranges(&set), vec![0..6]);
+        set.insert(8..10);
+        set.insert(-5..-3);
+        assert_eq!(ranges(&set), vec![-5..-3, 0..6, 8..10]);
+
+        set.remove(1..9);
+        assert_eq!(ranges(&set), vec![-5..-3, 0..1, 9..10]);
+        set.remove(-4..=0);
+        assert_eq!(ranges(&set), vec![-5..-4, 9..10]);
+        set.remove(20..30);
+        assert_eq!(ranges(&set), vec![-5..-4, 9..10]);
+        set.remove(-10..10);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: IntervalSet<i32> = [0..5, 10..15, 20..25].into_iter().collect();
+        let b: IntervalSet<i32> = [3..12, 14..21].into_iter().collect();
+        assert_eq!(ranges(&a.union(&b)), vec![0..25]);
+        assert_eq!(
+            ranges(&a.intersection(&b)),
+            vec![3..5, 10..12, 14..15, 20..21]
+        );
+        assert_eq!(
+            ranges(&a.complement(-5..30)),
+            vec![-5..0, 5..10, 15..20, 25..30]
+        );
+        assert_eq!(ranges(&a.complement(2..=12)), vec![5..10]);
+        assert!(a.overlaps(4..=4));
+        assert!(!a.overlaps(5..10));
+        assert!(a.overlaps(5..11));
+
+        let big: IntervalSet<i8> = [-100..0, 1..100].into_iter().collect();
+        assert_eq!(big.len(), None);
+        assert_eq!(big.complement(-100..100).len(), Some(1));
+    }
+}
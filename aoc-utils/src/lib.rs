@@ -1,18 +1,49 @@
 pub use ndarray;
+pub use tracing;
 use ndarray::{Array2, Axis};
 use std::cmp::{Ordering, max, min};
 use std::ops::{Add, Range, Sub};
 
+pub mod alloc;
+#[cfg(feature = "animate")]
+pub mod animate;
+pub mod answers;
 pub mod astar;
 pub mod astar_weighted;
+pub mod bench;
 pub mod bfs;
+pub mod cuboid;
+pub mod difference;
+pub mod dot;
 pub mod enclose;
+pub mod explore;
+pub mod fetch;
+pub mod input;
 pub mod intersection;
+pub mod interval_set;
+pub mod linear;
 pub mod machine;
+pub mod ocr;
+pub mod parse;
+pub mod pretty;
+pub mod registration;
+#[cfg(feature = "render")]
+pub mod render;
 pub mod ring;
+pub mod runner;
+pub mod samples;
+pub mod solution;
+pub mod spacetime;
+pub mod svg;
+pub mod trace;
+#[cfg(feature = "tui")]
+pub mod tui;
 pub mod unfold;
 pub mod ones_iter;
 
+pub use aoc_derive::AocParse;
+pub use parse::Cursor;
+
 pub trait IteratorExt: Iterator {
     fn unique_min_by_key<B, F>(self, f: F) -> Option<Self::Item>
     where
@@ -142,6 +173,9 @@ where
 /// Split `input` into fields separated by the non-underscore sections of
 /// `seps`, trim any surrounding whitespace, apply `parser` to each, and return
 /// the result as a vector of success values from `parser`.
+///
+/// This panics if `input` doesn't match `seps`; [`scan!`] returns an error
+/// instead.
 pub fn splits<T, E, P>(mut input: &str, seps: &str, mut parser: P) -> Result<Vec<T>, E>
 where
     P: FnMut(&str) -> Result<T, E>,
@@ -280,25 +314,6 @@ pub fn extend_range<Idx: Ord + Copy>(a: Range<Idx>, b: Idx) -> Range<Idx> {
     min(a.start, b)..max(a.end, b)
 }
 
-pub struct Cursor<'a>(std::iter::Peekable<std::str::Chars<'a>>);
-
-impl<'a> Cursor<'a> {
-    pub fn new(slice: &str) -> Cursor {
-        Cursor(slice.chars().peekable())
-    }
-
-    pub fn peek(&mut self) -> Option<char> {
-        self.0.peek().cloned()
-    }
-}
-
-impl<'a> Iterator for Cursor<'a> {
-    type Item = char;
-    fn next(&mut self) -> Option<char> {
-        self.0.next()
-    }
-}
-
 pub fn manhattan<T>(a: T, b: T) -> T
 where
     T: PartialOrd + Add<Output = T> + Sub<Output = T>,
@@ -345,6 +360,9 @@ where
 }
 
 /// Limit the calling process's memory consumption to 1GiB.
+///
+/// This just makes allocations fail once the limit is reached. The
+/// [`alloc::Counting`] allocator can also say what was running at the time.
 pub fn limit_memory() {
     nix::sys::resource::setrlimit(
         nix::sys::resource::Resource::RLIMIT_DATA,
@@ -0,0 +1,470 @@
+//! Vectors and square matrices of any fixed dimension.
+//!
+//! A [`Vector<T, N>`] is just an array of `N` components of type `T`, with the
+//! usual arithmetic operators. A [`Matrix<T, N>`] is an array of `N` column
+//! vectors, so multiplying a matrix by a vector takes a linear combination of
+//! the columns, weighted by the vector's components.
+//!
+//! Puzzles that involve rotating things around usually only care about
+//! rotations by multiples of a right angle. Those are exactly the matrices
+//! whose columns are unit vectors along distinct axes, with some signs flipped:
+//! the 'signed permutation matrices'. [`Matrix::signed_permutations`] generates
+//! all of them, and [`Matrix::rotations`] generates only those that don't
+//! involve a reflection. In three dimensions, there are 48 and 24 of these,
+//! respectively.
+
+use crate::{Manhattan, manhattan};
+use num_traits::{One, Signed, Zero};
+use std::{fmt, ops};
+
+/// A vector with `N` components of type `T`.
+///
+/// Vectors are ordered lexicographically, first component most significant.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Vector<T, const N: usize>(pub [T; N]);
+
+/// A square matrix, represented as an array of its columns.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub struct Matrix<T, const N: usize>(pub [Vector<T, N>; N]);
+
+impl<T: Copy, const N: usize> Vector<T, N> {
+    /// Apply `f` to each component of `self`.
+    pub fn map<U, F>(self, f: F) -> Vector<U, N>
+    where
+        F: FnMut(T) -> U,
+    {
+        Vector(self.0.map(f))
+    }
+
+    /// Apply `f` to corresponding pairs of components of `self` and `rhs`.
+    pub fn zip_with<U, F>(self, rhs: Self, mut f: F) -> Vector<U, N>
+    where
+        F: FnMut(T, T) -> U,
+    {
+        Vector(std::array::from_fn(|i| f(self.0[i], rhs.0[i])))
+    }
+}
+
+impl<T: Copy + Zero, const N: usize> Vector<T, N> {
+    pub fn zero() -> Self {
+        Vector([T::zero(); N])
+    }
+}
+
+impl<T: Copy + Zero + One, const N: usize> Vector<T, N> {
+    /// Return the unit vector pointing along the positive `axis`.
+    pub fn unit(axis: usize) -> Self {
+        assert!(axis < N, "axis {} out of range for {}-vector", axis, N);
+        let mut v = Self::zero();
+        v.0[axis] = T::one();
+        v
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Copy + Zero + ops::Mul<Output = T>,
+{
+    pub fn dot(self, rhs: Self) -> T {
+        self.0
+            .iter()
+            .zip(rhs.0.iter())
+            .fold(T::zero(), |sum, (&a, &b)| sum + a * b)
+    }
+
+    /// Return the square of the Euclidean length of `self`.
+    pub fn norm_squared(self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Copy + Zero + PartialOrd + ops::Sub<Output = T> + ops::Mul<Output = T>,
+{
+    /// Return the square of the Euclidean distance from `self` to `rhs`.
+    ///
+    /// This never subtracts a larger component from a smaller one, so it works
+    /// fine for unsigned component types.
+    pub fn distance_squared(self, rhs: Self) -> T {
+        self.zip_with(rhs, manhattan).norm_squared()
+    }
+}
+
+impl<T: Copy + Zero + Signed, const N: usize> Vector<T, N> {
+    /// Return the sum of the absolute values of `self`'s components.
+    pub fn manhattan_norm(self) -> T {
+        self.0.iter().fold(T::zero(), |sum, c| sum + c.abs())
+    }
+}
+
+impl<T> Vector<T, 3>
+where
+    T: Copy + ops::Sub<Output = T> + ops::Mul<Output = T>,
+{
+    pub fn cross(self, rhs: Self) -> Self {
+        let [a0, a1, a2] = self.0;
+        let [b0, b1, b2] = rhs.0;
+        Vector([a1 * b2 - a2 * b1, a2 * b0 - a0 * b2, a0 * b1 - a1 * b0])
+    }
+}
+
+impl<T, const N: usize> Manhattan for Vector<T, N>
+where
+    T: Copy + Zero + PartialOrd + ops::Add<Output = T> + ops::Sub<Output = T>,
+{
+    type Output = T;
+    fn manhattan(self, b: Self) -> T {
+        self.zip_with(b, manhattan)
+            .0
+            .into_iter()
+            .fold(T::zero(), |sum, d| sum + d)
+    }
+}
+
+impl<T, const N: usize> ops::Index<usize> for Vector<T, N> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        &self.0[index]
+    }
+}
+
+impl<T, const N: usize> ops::IndexMut<usize> for Vector<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.0[index]
+    }
+}
+
+impl<T: Copy + ops::Add<Output = T>, const N: usize> ops::Add for Vector<T, N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<T: Copy + ops::Sub<Output = T>, const N: usize> ops::Sub for Vector<T, N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<T: Copy + ops::Add<Output = T>, const N: usize> ops::AddAssign for Vector<T, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Copy + ops::Sub<Output = T>, const N: usize> ops::SubAssign for Vector<T, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Copy + ops::Neg<Output = T>, const N: usize> ops::Neg for Vector<T, N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.map(|c| -c)
+    }
+}
+
+impl<T: Copy + ops::Mul<Output = T>, const N: usize> ops::Mul<T> for Vector<T, N> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        self.map(|c| c * rhs)
+    }
+}
+
+impl<T: Copy + ops::Div<Output = T>, const N: usize> ops::Div<T> for Vector<T, N> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self {
+        self.map(|c| c / rhs)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    fn from(array: [T; N]) -> Self {
+        Vector(array)
+    }
+}
+
+impl<T> From<(T, T)> for Vector<T, 2> {
+    fn from((x, y): (T, T)) -> Self {
+        Vector([x, y])
+    }
+}
+
+impl<T> From<(T, T, T)> for Vector<T, 3> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Vector([x, y, z])
+    }
+}
+
+impl<T> From<Vector<T, 2>> for (T, T) {
+    fn from(Vector([x, y]): Vector<T, 2>) -> Self {
+        (x, y)
+    }
+}
+
+impl<T> From<Vector<T, 3>> for (T, T, T) {
+    fn from(Vector([x, y, z]): Vector<T, 3>) -> Self {
+        (x, y, z)
+    }
+}
+
+/// Vectors print their components separated by commas, the way puzzle inputs
+/// usually write them.
+impl<T: fmt::Display, const N: usize> fmt::Display for Vector<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, c) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            c.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Copy + Zero, const N: usize> Matrix<T, N> {
+    pub fn zero() -> Self {
+        Matrix([Vector::zero(); N])
+    }
+}
+
+impl<T: Copy + Zero + One, const N: usize> Matrix<T, N> {
+    pub fn identity() -> Self {
+        Matrix(std::array::from_fn(Vector::unit))
+    }
+}
+
+impl<T: Copy, const N: usize> Matrix<T, N> {
+    /// Build a matrix from an array of rows, as one would write it on paper.
+    pub fn from_rows(rows: [[T; N]; N]) -> Self {
+        Matrix(std::array::from_fn(|col| {
+            Vector(std::array::from_fn(|row| rows[row][col]))
+        }))
+    }
+
+    pub fn transpose(self) -> Self {
+        Matrix::from_rows(self.0.map(|column| column.0))
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N>
+where
+    T: Copy + Zero + One + ops::Neg<Output = T> + ops::Mul<Output = T>,
+{
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut result = Self::identity();
+        let mut square = self;
+        while exp > 0 {
+            if exp & 1 != 0 {
+                result = result * square;
+            }
+            square = square * square;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Return the determinant of `self`.
+    ///
+    /// This uses cofactor expansion, which takes time proportional to `N!`.
+    /// That's fine for the small matrices puzzles use.
+    pub fn determinant(self) -> T {
+        let columns: Vec<Vec<T>> = self.0.iter().map(|column| column.0.to_vec()).collect();
+        cofactor_determinant(&columns)
+    }
+
+    /// Return all `N`-dimensional signed permutation matrices.
+    ///
+    /// These are the `2ᴺ N!` transformations that map each axis onto some
+    /// (possibly negated) axis. They include reflections; see [`rotations`] if
+    /// you want only the orientation-preserving transformations.
+    ///
+    /// The identity matrix comes first.
+    ///
+    /// [`rotations`]: Matrix::rotations
+    pub fn signed_permutations() -> Vec<Self> {
+        let mut matrices = vec![];
+        for perm in permutations(N) {
+            for signs in 0..1_usize << N {
+                matrices.push(Matrix(std::array::from_fn(|col| {
+                    let unit = Vector::unit(perm[col]);
+                    if signs & (1 << col) != 0 { -unit } else { unit }
+                })));
+            }
+        }
+        matrices
+    }
+
+    /// Return all `N`-dimensional rotations by multiples of a right angle.
+    ///
+    /// These are the signed permutation matrices whose determinant is one. In
+    /// three dimensions, there are 24 of them: the orientations a die can be
+    /// placed in. The identity matrix comes first.
+    pub fn rotations() -> Vec<Self>
+    where
+        T: PartialEq,
+    {
+        Self::signed_permutations()
+            .into_iter()
+            .filter(|m| m.determinant() == T::one())
+            .collect()
+    }
+}
+
+fn cofactor_determinant<T>(columns: &[Vec<T>]) -> T
+where
+    T: Copy + Zero + One + ops::Neg<Output = T> + ops::Mul<Output = T>,
+{
+    if columns.is_empty() {
+        return T::one();
+    }
+
+    // Expand along the first row.
+    let mut sum = T::zero();
+    let mut sign = T::one();
+    for (i, column) in columns.iter().enumerate() {
+        if !column[0].is_zero() {
+            let minor: Vec<Vec<T>> = columns
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, other)| other[1..].to_vec())
+                .collect();
+            sum = sum + sign * column[0] * cofactor_determinant(&minor);
+        }
+        sign = -sign;
+    }
+    sum
+}
+
+/// Return all permutations of `0..n`, in lexicographic order.
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+
+    let mut perms = vec![];
+    for first in 0..n {
+        for rest in permutations(n - 1) {
+            let mut perm = vec![first];
+            perm.extend(rest.into_iter().map(|i| if i >= first { i + 1 } else { i }));
+            perms.push(perm);
+        }
+    }
+    perms
+}
+
+impl<T, const N: usize> ops::Mul<Vector<T, N>> for Matrix<T, N>
+where
+    T: Copy + Zero + ops::Mul<Output = T>,
+{
+    type Output = Vector<T, N>;
+    fn mul(self, rhs: Vector<T, N>) -> Vector<T, N> {
+        self.0
+            .iter()
+            .zip(rhs.0.iter())
+            .fold(Vector::zero(), |sum, (&column, &weight)| {
+                sum + column * weight
+            })
+    }
+}
+
+impl<T, const N: usize> ops::Mul for Matrix<T, N>
+where
+    T: Copy + Zero + ops::Mul<Output = T>,
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Matrix(rhs.0.map(|column| self * column))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    type V3 = Vector<i64, 3>;
+    type M3 = Matrix<i64, 3>;
+
+    #[test]
+    fn test_vector() {
+        let a = V3::from((1, 2, 3));
+        let b = Vector([4, -5, 6]);
+        assert_eq!(a + b, Vector([5, -3, 9]));
+        assert_eq!(a - b, Vector([-3, 7, -3]));
+        assert_eq!(-a, Vector([-1, -2, -3]));
+        assert_eq!(a * 2, Vector([2, 4, 6]));
+        assert_eq!(b / 2, Vector([2, -2, 3]));
+        assert_eq!(a.dot(b), 12);
+        assert_eq!(a.cross(b), Vector([27, 6, -13]));
+        assert_eq!(a.cross(b).dot(a), 0);
+        assert_eq!(b.manhattan_norm(), 15);
+        assert_eq!(a.manhattan(b), 13);
+        assert_eq!(a.norm_squared(), 14);
+        assert_eq!(a.distance_squared(b), 67);
+        assert_eq!(a.to_string(), "1,2,3");
+        assert_eq!(<(i64, i64, i64)>::from(b), (4, -5, 6));
+
+        // Unsigned components shouldn't underflow.
+        let p = Vector::<u64, 3>([0, 1, 2]);
+        let q = Vector([5, 4, 3]);
+        assert_eq!(p.distance_squared(q), 35);
+        assert_eq!(q.distance_squared(p), 35);
+        assert_eq!(p.manhattan(q), 9);
+    }
+
+    #[test]
+    fn test_matrix() {
+        // clockwise looking down the positive x axis at the origin
+        let cwx = M3::from_rows([[1, 0, 0], [0, 0, 1], [0, -1, 0]]);
+        let x = V3::unit(0);
+        let y = V3::unit(1);
+        let z = V3::unit(2);
+        assert_eq!(cwx * x, x);
+        assert_eq!(cwx * y, -z);
+        assert_eq!(cwx * z, y);
+        assert_eq!(cwx.pow(4), M3::identity());
+        assert_eq!(cwx.pow(3), cwx.transpose());
+        assert_eq!(cwx.determinant(), 1);
+
+        let m = M3::from_rows([[2, 0, 1], [1, 3, 2], [1, 1, 2]]);
+        assert_eq!(m.determinant(), 6);
+        assert_eq!(m * Vector([1, 10, 100]), Vector([102, 231, 211]));
+        assert_eq!((cwx * m) * x, cwx * (m * x));
+    }
+
+    #[test]
+    fn test_rotations() {
+        let rotations = M3::rotations();
+        assert_eq!(rotations.len(), 24);
+        assert_eq!(rotations[0], M3::identity());
+
+        let images: HashSet<V3> = rotations
+            .iter()
+            .map(|&r| r * Vector([1, 10, 100]))
+            .collect();
+        assert_eq!(images.len(), 24);
+
+        // Rotations form a group.
+        let set: HashSet<M3> = rotations.iter().cloned().collect();
+        for &a in &rotations {
+            for &b in &rotations {
+                assert!(set.contains(&(a * b)));
+            }
+            assert!(set.contains(&a.transpose()));
+            assert_eq!(a * a.transpose(), M3::identity());
+        }
+
+        let all = M3::signed_permutations();
+        assert_eq!(all.len(), 48);
+        assert_eq!(all.iter().filter(|m| m.determinant() == -1).count(), 24);
+
+        assert_eq!(Matrix::<i32, 2>::rotations().len(), 4);
+        assert_eq!(Matrix::<i32, 4>::rotations().len(), 192);
+    }
+}
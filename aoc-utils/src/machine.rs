@@ -1,3 +1,5 @@
+use crate::Cursor;
+use crate::parse::{self, Parser, literal, optional, signed, unsigned};
 use std::fmt;
 
 pub type Word = isize;
@@ -19,7 +21,7 @@ pub trait Semantic: Sync + Send {
     fn step(&self, insn: &Insn, state: &mut State);
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct AssemblyInsn {
     pub mnemonic: &'static str,
     pub a: Word,
@@ -191,3 +193,61 @@ pub fn assemble<'a>(asm: &'a [AssemblyInsn]) -> Vec<Insn<'a>> {
         })
         .collect::<Vec<_>>()
 }
+
+/// Parse a program in the puzzle's notation: an `#ip` line naming the register
+/// bound to the instruction pointer, followed by one instruction per line.
+///
+/// Return the instruction pointer's register and the program.
+pub fn parse_program(text: &str) -> Result<(usize, Vec<AssemblyInsn>), parse::Error> {
+    parse::parse_all(text, |cursor: &mut Cursor| {
+        literal("#ip ").parse(cursor)?;
+        let ip = unsigned().parse(cursor)?;
+        literal("\n").parse(cursor)?;
+        let mut program = vec![];
+        while !cursor.rest().trim().is_empty() {
+            program.push(assembly_insn(cursor)?);
+            optional(literal("\n")).parse(cursor)?;
+        }
+        Ok((ip, program))
+    })
+}
+
+fn assembly_insn(cursor: &mut Cursor) -> Result<AssemblyInsn, parse::Error> {
+    let rest = cursor.rest();
+    let len = rest.find(' ').unwrap_or(rest.len());
+    let mnemonic = INSN_BY_MNEMONIC
+        .iter()
+        .map(|(name, _sem)| *name)
+        .find(|name| *name == &rest[..len])
+        .ok_or_else(|| cursor.error("an instruction mnemonic"))?;
+    cursor.advance(len);
+    let mut operand = || {
+        literal(" ").parse(cursor)?;
+        signed().parse(cursor)
+    };
+    Ok(AssemblyInsn {
+        mnemonic,
+        a: operand()?,
+        b: operand()?,
+        c: operand()?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_program() {
+        let (ip, program) = parse_program("#ip 1\nseti 5 0 1\naddi 0 -1 0\n").unwrap();
+        assert_eq!(ip, 1);
+        let program: Vec<_> = assemble(&program).iter().map(Insn::to_string).collect();
+        assert_eq!(program, ["seti 5 0 1", "addi 0 -1 0"]);
+
+        let err = parse_program("#ip 1\nsetx 5 0 1\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"line 2, column 1: expected an instruction mnemonic, found "setx 5 0 1""#
+        );
+    }
+}
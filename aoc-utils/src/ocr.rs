@@ -0,0 +1,309 @@
+//! Reading the block letters that some puzzles draw as their answer.
+//!
+//! Advent of Code uses two fonts for this: one whose letters are six pixels
+//! tall and about four wide, and one whose letters are ten pixels tall and
+//! six wide. Given the lit pixels, as a set of points, a grid of booleans,
+//! or text with `#` for lit pixels, these functions return the letters
+//! drawn.
+//!
+//! Letters are told apart by the blank columns between them, so the spacing
+//! and the position of the whole picture don't matter.
+
+use std::fmt;
+
+/// Letters six pixels tall.
+const SMALL: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters ten pixels tall.
+const LARGE: &[(char, &str)] = &[
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// Why a picture couldn't be read.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// No pixels are lit.
+    Empty,
+
+    /// The picture isn't as tall as either font.
+    Height(usize),
+
+    /// The letter at `index` isn't one we know. `glyph` shows its pixels.
+    Unknown { index: usize, glyph: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Empty => write!(f, "no pixels are lit"),
+            Error::Height(height) => write!(
+                f,
+                "picture is {height} pixels tall, but letters are 6 or 10 pixels tall"
+            ),
+            Error::Unknown { index, glyph } => {
+                write!(f, "letter {index} isn't one we recognize:\n{glyph}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Read the letters drawn by the lit pixels at `points`, given as `(x, y)`
+/// pairs.
+pub fn from_points<T>(points: impl IntoIterator<Item = (T, T)>) -> Result<String, Error>
+where
+    T: Copy + Into<i64>,
+{
+    let points: Vec<(i64, i64)> = points
+        .into_iter()
+        .map(|(x, y)| (x.into(), y.into()))
+        .collect();
+    let (Some(left), Some(right)) = (
+        points.iter().map(|p| p.0).min(),
+        points.iter().map(|p| p.0).max(),
+    ) else {
+        return Err(Error::Empty);
+    };
+    let top = points.iter().map(|p| p.1).min().unwrap();
+    let bottom = points.iter().map(|p| p.1).max().unwrap();
+
+    let mut rows = vec![vec![false; (right - left + 1) as usize]; (bottom - top + 1) as usize];
+    for (x, y) in points {
+        rows[(y - top) as usize][(x - left) as usize] = true;
+    }
+    recognize(rows)
+}
+
+/// Read the letters drawn in `rows`, where `true` is a lit pixel.
+pub fn from_grid<R: AsRef<[bool]>>(rows: &[R]) -> Result<String, Error> {
+    recognize(rows.iter().map(|row| row.as_ref().to_vec()).collect())
+}
+
+/// Read the letters drawn in `text`, where `#` is a lit pixel, and anything
+/// else is dark.
+pub fn from_text(text: &str) -> Result<String, Error> {
+    recognize(
+        text.lines()
+            .map(|line| line.chars().map(|ch| ch == '#').collect())
+            .collect(),
+    )
+}
+
+fn recognize(mut rows: Vec<Vec<bool>>) -> Result<String, Error> {
+    let lit = |row: &Vec<bool>| row.iter().any(|&pixel| pixel);
+    let Some(top) = rows.iter().position(lit) else {
+        return Err(Error::Empty);
+    };
+    let bottom = rows.iter().rposition(lit).unwrap();
+    rows.truncate(bottom + 1);
+    rows.drain(..top);
+
+    let font = match rows.len() {
+        6 => SMALL,
+        10 => LARGE,
+        height => return Err(Error::Height(height)),
+    };
+
+    let width = rows.iter().map(Vec::len).max().unwrap();
+    let column_lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < width {
+        if !column_lit(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && column_lit(x) {
+            x += 1;
+        }
+        let glyph = draw(&rows, start..x);
+        let letter = font
+            .iter()
+            .find(|(_, pattern)| trim(pattern) == glyph)
+            .map(|&(letter, _)| letter)
+            .ok_or_else(|| Error::Unknown {
+                index: letters.len(),
+                glyph: glyph.clone(),
+            })?;
+        letters.push(letter);
+    }
+    Ok(letters)
+}
+
+/// Draw the given columns of `rows` in the same form as the font tables.
+fn draw(rows: &[Vec<bool>], columns: std::ops::Range<usize>) -> String {
+    rows.iter()
+        .map(|row| {
+            columns
+                .clone()
+                .map(|x| if row.get(x) == Some(&true) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Remove columns with no lit pixels from `pattern`.
+fn trim(pattern: &str) -> String {
+    let rows: Vec<Vec<bool>> = pattern
+        .lines()
+        .map(|line| line.chars().map(|ch| ch == '#').collect())
+        .collect();
+    let width = rows[0].len();
+    let first = (0..width).find(|&x| rows.iter().any(|row| row[x])).unwrap();
+    let last = (0..width)
+        .rfind(|&x| rows.iter().any(|row| row[x]))
+        .unwrap();
+    draw(&rows, first..last + 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fonts() {
+        for font in [SMALL, LARGE] {
+            for &(letter, pattern) in font {
+                assert_eq!(from_text(pattern), Ok(letter.to_string()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_small() {
+        let text = "\
+            #..#.####.###..\n\
+            #..#.#....#..#.\n\
+            ####.###..#..#.\n\
+            #..#.#....###..\n\
+            #..#.#....#.#..\n\
+            #..#.####.#..#.\n";
+        assert_eq!(from_text(text), Ok("HER".to_string()));
+
+        let points: Vec<(i32, i32)> = text
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.char_indices()
+                    .filter(|&(_, ch)| ch == '#')
+                    .map(move |(x, _)| (x as i32 + 100, y as i32 - 7))
+            })
+            .collect();
+        assert_eq!(from_points(points), Ok("HER".to_string()));
+    }
+
+    #[test]
+    fn test_large() {
+        let rows: Vec<Vec<bool>> = [LARGE[10].1, LARGE[13].1]
+            .iter()
+            .map(|pattern| pattern.lines().collect::<Vec<_>>())
+            .fold(vec![String::new(); 10], |mut rows, letter| {
+                for (row, line) in rows.iter_mut().zip(letter) {
+                    *row += line;
+                    *row += "..";
+                }
+                rows
+            })
+            .iter()
+            .map(|row| row.chars().map(|ch| ch == '#').collect())
+            .collect();
+        assert_eq!(from_grid(&rows), Ok("NX".to_string()));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(from_text("....\n"), Err(Error::Empty));
+        assert_eq!(from_points(Vec::<(i32, i32)>::new()), Err(Error::Empty));
+        assert_eq!(from_text("#\n#\n#\n"), Err(Error::Height(3)));
+        let err = from_text("##\n##\n##\n##\n##\n##\n").unwrap_err();
+        assert_eq!(
+            err,
+            Error::Unknown {
+                index: 0,
+                glyph: "##\n##\n##\n##\n##\n##".to_string()
+            }
+        );
+    }
+}
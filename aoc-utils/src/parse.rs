@@ -0,0 +1,840 @@
+//! Small parser combinators, with errors that say where parsing failed.
+//!
+//! A parser is anything implementing [`Parser`], which includes any function
+//! or closure that takes a `&mut Cursor` and returns a `Result<T, Error>`.
+//! Parsers for whole puzzle lines are usually plain functions that run other
+//! parsers in sequence with `?`:
+//!
+//! ```
+//! use aoc_utils::parse::{self, Cursor, Error, Parser, literal, separated, signed};
+//!
+//! fn point(cursor: &mut Cursor) -> Result<(i32, i32), Error> {
+//!     let x = signed().parse(cursor)?;
+//!     literal(",").parse(cursor)?;
+//!     let y = signed().parse(cursor)?;
+//!     Ok((x, y))
+//! }
+//!
+//! let path = parse::parse_all("0,1 -> 2,-3", separated(point, literal(" -> "))).unwrap();
+//! assert_eq!(path, [(0, 1), (2, -3)]);
+//!
+//! let err = parse::parse_lines("1,2\n3;4\n", point).unwrap_err();
+//! assert_eq!(err.to_string(), r#"line 2, column 2: expected ",", found ";4""#);
+//! ```
+//!
+//! When a parser fails without consuming any input, combinators like
+//! [`Parser::or`] and [`many`] backtrack and try something else. Once a
+//! parser has consumed input, its failure is final, so errors point at the
+//! actual problem rather than wherever the last alternative gave up.
+//!
+//! For lines that are just fields between fixed text, the [`scan!`] macro is
+//! a version of [`splits`](crate::splits) that returns errors instead of
+//! panicking, and [`#[derive(AocParse)]`](crate::AocParse) implements
+//! `FromStr` for a struct from a template like `"{x},{y}"`.
+//!
+//! [`scan!`]: crate::scan
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A position in some text being parsed.
+///
+/// This is also a [`char`] iterator over the rest of the text.
+#[derive(Clone, Debug)]
+pub struct Cursor<'a> {
+    source: &'a str,
+    pos: usize,
+    end: usize,
+}
+
+/// A line and column number, both starting at 1.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Position {
+    pub line: usize,
+
+    /// The column, counting characters, not bytes.
+    pub column: usize,
+}
+
+/// A parse error.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    pub position: Position,
+
+    /// What the parser was looking for.
+    pub expected: String,
+
+    /// What it found instead.
+    pub found: String,
+
+    /// What was being parsed, innermost first, from [`Parser::context`].
+    pub context: Vec<&'static str>,
+
+    /// Which of the input's [`Sections`] this is in, counting from 1.
+    pub section: Option<usize>,
+
+    /// The byte offset of `position`, for deciding whether to backtrack.
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(source: &'a str) -> Cursor<'a> {
+        Cursor {
+            source,
+            pos: 0,
+            end: source.len(),
+        }
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Return the text that hasn't been parsed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.pos..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.end
+    }
+
+    /// Return the byte offset of the cursor in the original text.
+    pub fn offset(&self) -> usize {
+        self.pos
+    }
+
+    /// Return the line and column of the cursor in the original text.
+    pub fn position(&self) -> Position {
+        let before = &self.source[..self.pos];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Position {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    /// Skip the next `bytes` bytes of text.
+    pub fn advance(&mut self, bytes: usize) {
+        assert!(bytes <= self.end - self.pos);
+        self.pos += bytes;
+    }
+
+    /// Return an error saying that we expected `expected` here.
+    pub fn error(&self, expected: impl fmt::Display) -> Error {
+        let found = match self.peek() {
+            None if self.end < self.source.len() => "end of line".to_string(),
+            None => "end of input".to_string(),
+            Some('\n') => "end of line".to_string(),
+            Some(_) => {
+                let text: String = self
+                    .rest()
+                    .chars()
+                    .take_while(|&ch| ch != '\n')
+                    .take(10)
+                    .collect();
+                format!("{text:?}")
+            }
+        };
+        self.error_found(expected, found)
+    }
+
+    fn error_found(&self, expected: impl fmt::Display, found: String) -> Error {
+        Error {
+            position: self.position(),
+            expected: expected.to_string(),
+            found,
+            context: vec![],
+            section: None,
+            offset: self.pos,
+        }
+    }
+
+    /// Return cursors for each line of the remaining text.
+    ///
+    /// Each line's cursor stops at the end of the line, but reports
+    /// positions relative to the whole text.
+    pub fn lines(&self) -> impl Iterator<Item = Cursor<'a>> + use<'a> {
+        let source = self.source;
+        let mut pos = self.pos;
+        self.rest().split_inclusive('\n').map(move |line| {
+            let start = pos;
+            pos += line.len();
+            Cursor {
+                source,
+                pos: start,
+                end: start + line.trim_end_matches(['\n', '\r']).len(),
+            }
+        })
+    }
+}
+
+impl Iterator for Cursor<'_> {
+    type Item = char;
+    fn next(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += ch.len_utf8();
+        Some(ch)
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+impl Error {
+    /// Note that this error occurred while parsing `what`.
+    pub fn in_context(mut self, what: &'static str) -> Error {
+        self.context.push(what);
+        self
+    }
+
+    /// Combine two errors from alternatives that failed at the same place.
+    fn or(self, other: Error) -> Error {
+        Error {
+            expected: format!("{} or {}", self.expected, other.expected),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(section) = self.section {
+            write!(f, "section {section}, ")?;
+        }
+        write!(
+            f,
+            "{}: expected {}, found {}",
+            self.position, self.expected, self.found
+        )?;
+        if let Some((innermost, outer)) = self.context.split_first() {
+            write!(f, " (in {innermost}")?;
+            for context in outer {
+                write!(f, ", in {context}")?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Something that can parse a value from a [`Cursor`].
+pub trait Parser<'a> {
+    type Output;
+
+    /// Parse a value from the text at `cursor`, and advance past it.
+    fn parse(&self, cursor: &mut Cursor<'a>) -> Result<Self::Output, Error>;
+
+    /// Return a parser that applies `f` to this parser's output.
+    fn map<U, F>(self, f: F) -> impl Parser<'a, Output = U>
+    where
+        Self: Sized,
+        F: Fn(Self::Output) -> U,
+    {
+        move |cursor: &mut Cursor<'a>| self.parse(cursor).map(&f)
+    }
+
+    /// Return a parser that tries this parser, and then `other` if this one
+    /// fails without consuming any input.
+    fn or<P>(self, other: P) -> impl Parser<'a, Output = Self::Output>
+    where
+        Self: Sized,
+        P: Parser<'a, Output = Self::Output>,
+    {
+        move |cursor: &mut Cursor<'a>| {
+            let start = cursor.clone();
+            match self.parse(cursor) {
+                Err(first) if first.offset == start.pos => {
+                    *cursor = start.clone();
+                    match other.parse(cursor) {
+                        Err(second) if second.offset == start.pos => Err(first.or(second)),
+                        result => result,
+                    }
+                }
+                result => result,
+            }
+        }
+    }
+
+    /// Return a parser whose errors mention that it was parsing `what`.
+    fn context(self, what: &'static str) -> impl Parser<'a, Output = Self::Output>
+    where
+        Self: Sized,
+    {
+        move |cursor: &mut Cursor<'a>| self.parse(cursor).map_err(|err| err.in_context(what))
+    }
+}
+
+impl<'a, T, F> Parser<'a> for F
+where
+    F: Fn(&mut Cursor<'a>) -> Result<T, Error>,
+{
+    type Output = T;
+    fn parse(&self, cursor: &mut Cursor<'a>) -> Result<T, Error> {
+        self(cursor)
+    }
+}
+
+/// Parse all of `text` with `parser`, allowing trailing whitespace.
+pub fn parse_all<'a, P: Parser<'a>>(text: &'a str, parser: P) -> Result<P::Output, Error> {
+    let mut cursor = Cursor::new(text);
+    let value = parser.parse(&mut cursor)?;
+    finish(&mut cursor)?;
+    Ok(value)
+}
+
+/// Parse each non-blank line of `text` with `parser`.
+///
+/// Each line must be parsed completely, except for trailing whitespace.
+pub fn parse_lines<'a, P: Parser<'a>>(text: &'a str, parser: P) -> Result<Vec<P::Output>, Error> {
+    each_line(&Cursor::new(text), &parser)
+}
+
+fn each_line<'a, P: Parser<'a>>(cursor: &Cursor<'a>, parser: &P) -> Result<Vec<P::Output>, Error> {
+    cursor
+        .lines()
+        .filter(|line| !line.rest().trim().is_empty())
+        .map(|mut line| {
+            let value = parser.parse(&mut line)?;
+            finish(&mut line)?;
+            Ok(value)
+        })
+        .collect()
+}
+
+/// The sections of some text, separated by blank lines, for parsing one
+/// after another.
+///
+/// Puzzle inputs often have a few sections of different kinds, like a list
+/// of rules followed by a list of messages. Each section can be parsed with
+/// its own parser:
+///
+/// ```
+/// use aoc_utils::parse::{Sections, literal, unsigned, Parser};
+///
+/// let text = "1-3\n5-7\n\n4\n2\n";
+/// let mut sections = Sections::new(text);
+/// let ranges = sections.parse_next_lines(|cursor: &mut aoc_utils::Cursor| {
+///     let start: u32 = unsigned().parse(cursor)?;
+///     literal("-").parse(cursor)?;
+///     Ok(start..=unsigned().parse(cursor)?)
+/// })?;
+/// let ids: Vec<u32> = sections.parse_next_lines(unsigned())?;
+/// sections.finish()?;
+/// assert_eq!(ranges, [1..=3, 5..=7]);
+/// assert_eq!(ids, [4, 2]);
+/// # Ok::<(), aoc_utils::parse::Error>(())
+/// ```
+///
+/// Errors say which section they're in, as well as the line and column.
+#[derive(Clone, Debug)]
+pub struct Sections<'a> {
+    sections: std::vec::IntoIter<Cursor<'a>>,
+
+    /// The number of sections we've started parsing.
+    parsed: usize,
+
+    /// A cursor at the end of the text, for reporting missing sections.
+    end: Cursor<'a>,
+}
+
+impl<'a> Sections<'a> {
+    pub fn new(text: &'a str) -> Sections<'a> {
+        let mut sections = vec![];
+        let mut current: Option<Cursor<'a>> = None;
+        for line in Cursor::new(text).lines() {
+            if line.rest().trim().is_empty() {
+                sections.extend(current.take());
+            } else {
+                match &mut current {
+                    Some(section) => section.end = line.end,
+                    None => current = Some(line),
+                }
+            }
+        }
+        sections.extend(current);
+
+        let mut end = Cursor::new(text);
+        end.advance(text.len());
+        Sections {
+            sections: sections.into_iter(),
+            parsed: 0,
+            end,
+        }
+    }
+
+    /// Return the number of sections not yet parsed.
+    pub fn remaining(&self) -> usize {
+        self.sections.len()
+    }
+
+    /// Parse the next section with `parser`, which must consume all of it
+    /// except for trailing whitespace.
+    pub fn parse_next<P: Parser<'a>>(&mut self, parser: P) -> Result<P::Output, Error> {
+        self.parse_section(&parser)
+    }
+
+    /// Parse each line of the next section with `parser`.
+    pub fn parse_next_lines<P: Parser<'a>>(&mut self, parser: P) -> Result<Vec<P::Output>, Error> {
+        let section = self.next_section()?;
+        each_line(&section, &parser).map_err(|err| self.in_section(err))
+    }
+
+    /// Parse each of the remaining sections with `parser`.
+    pub fn parse_rest<P: Parser<'a>>(&mut self, parser: P) -> Result<Vec<P::Output>, Error> {
+        let mut values = vec![];
+        while self.remaining() > 0 {
+            values.push(self.parse_section(&parser)?);
+        }
+        Ok(values)
+    }
+
+    /// Check that every section has been parsed.
+    pub fn finish(mut self) -> Result<(), Error> {
+        match self.sections.next() {
+            None => Ok(()),
+            Some(extra) => {
+                self.parsed += 1;
+                Err(self.in_section(extra.error("end of input")))
+            }
+        }
+    }
+
+    fn parse_section<P: Parser<'a>>(&mut self, parser: &P) -> Result<P::Output, Error> {
+        let mut section = self.next_section()?;
+        parser
+            .parse(&mut section)
+            .and_then(|value| {
+                finish(&mut section)?;
+                Ok(value)
+            })
+            .map_err(|err| self.in_section(err))
+    }
+
+    fn next_section(&mut self) -> Result<Cursor<'a>, Error> {
+        self.parsed += 1;
+        self.sections
+            .next()
+            .ok_or_else(|| self.end.error(format_args!("section {}", self.parsed)))
+    }
+
+    fn in_section(&self, err: Error) -> Error {
+        Error {
+            section: Some(self.parsed),
+            ..err
+        }
+    }
+}
+
+fn finish(cursor: &mut Cursor) -> Result<(), Error> {
+    let rest = cursor.rest();
+    cursor.advance(rest.len() - rest.trim_start().len());
+    if !cursor.is_empty() {
+        return Err(cursor.error("end of input"));
+    }
+    Ok(())
+}
+
+/// Return a parser that matches `lit` exactly.
+pub fn literal<'a>(lit: &'static str) -> impl Parser<'a, Output = ()> {
+    move |cursor: &mut Cursor<'a>| literal_str(cursor, lit)
+}
+
+/// Return a parser that skips any spaces and tabs, but not newlines.
+pub fn spaces<'a>() -> impl Parser<'a, Output = ()> {
+    |cursor: &mut Cursor<'a>| {
+        let rest = cursor.rest();
+        cursor.advance(rest.len() - rest.trim_start_matches([' ', '\t']).len());
+        Ok(())
+    }
+}
+
+/// Return a parser for a string of decimal digits.
+pub fn unsigned<'a, T>() -> impl Parser<'a, Output = T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    number(false)
+}
+
+/// Return a parser for a string of decimal digits, with an optional sign.
+pub fn signed<'a, T>() -> impl Parser<'a, Output = T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    number(true)
+}
+
+fn number<'a, T>(signed: bool) -> impl Parser<'a, Output = T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    move |cursor: &mut Cursor<'a>| {
+        let rest = cursor.rest();
+        let sign = usize::from(signed && rest.starts_with(['-', '+']));
+        let digits = rest[sign..]
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        if digits == 0 {
+            return Err(cursor.error("a number"));
+        }
+        let text = &rest[..sign + digits];
+        let value = text
+            .parse()
+            .map_err(|err| cursor.error_found(type_name::<T>(), format!("{text:?} ({err})")))?;
+        cursor.advance(text.len());
+        Ok(value)
+    }
+}
+
+/// Return a parser that tries `parser`, producing `None` if it fails
+/// without consuming any input.
+pub fn optional<'a, P: Parser<'a>>(parser: P) -> impl Parser<'a, Output = Option<P::Output>> {
+    move |cursor: &mut Cursor<'a>| {
+        let start = cursor.clone();
+        match parser.parse(cursor) {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if err.offset == start.pos => {
+                *cursor = start;
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Return a parser that applies `parser` as many times as it can, even none.
+pub fn many<'a, P: Parser<'a>>(parser: P) -> impl Parser<'a, Output = Vec<P::Output>> {
+    let parser = optional(parser);
+    move |cursor: &mut Cursor<'a>| {
+        let mut values = vec![];
+        while let Some(value) = parser.parse(cursor)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Return a parser for one or more `item`s, separated by `sep`.
+pub fn separated<'a, P, S>(item: P, sep: S) -> impl Parser<'a, Output = Vec<P::Output>>
+where
+    P: Parser<'a>,
+    S: Parser<'a>,
+{
+    let sep = optional(sep);
+    move |cursor: &mut Cursor<'a>| {
+        let mut items = vec![item.parse(cursor)?];
+        while sep.parse(cursor)?.is_some() {
+            items.push(item.parse(cursor)?);
+        }
+        Ok(items)
+    }
+}
+
+/// Return a parser for `parser` between the literals `open` and `close`.
+pub fn bracketed<'a, P: Parser<'a>>(
+    open: &'static str,
+    parser: P,
+    close: &'static str,
+) -> impl Parser<'a, Output = P::Output> {
+    let open = literal(open);
+    let close = literal(close);
+    move |cursor: &mut Cursor<'a>| {
+        open.parse(cursor)?;
+        let value = parser.parse(cursor)?;
+        close.parse(cursor)?;
+        Ok(value)
+    }
+}
+
+/// A field of text matched by [`scan!`](crate::scan).
+#[derive(Clone, Debug)]
+pub struct Field<'a> {
+    cursor: Cursor<'a>,
+}
+
+impl Field<'_> {
+    pub fn text(&self) -> &str {
+        self.cursor.rest()
+    }
+
+    /// Parse the field's text with `FromStr`.
+    ///
+    /// If `T`'s own parser returns an [`Error`], adjust its position to
+    /// refer to the text the field came from.
+    pub fn parse<T>(&self) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display + 'static,
+    {
+        let text = self.text();
+        text.parse().map_err(|err| {
+            if let Some(inner) = (&err as &dyn std::any::Any).downcast_ref::<Error>() {
+                let mut at = self.cursor.clone();
+                at.advance(inner.offset);
+                return Error {
+                    position: at.position(),
+                    offset: at.pos,
+                    ..inner.clone()
+                };
+            }
+            self.cursor
+                .error_found(type_name::<T>(), format!("{text:?} ({err})"))
+        })
+    }
+}
+
+/// Split `input` into `count` fields separated by the non-underscore
+/// sections of `template`, trimming any surrounding whitespace.
+///
+/// This is the machinery behind [`scan!`](crate::scan). It panics if
+/// `template` doesn't have `count` underscores.
+pub fn template<'a>(input: &'a str, template: &str, count: usize) -> Result<Vec<Field<'a>>, Error> {
+    let seps: Vec<&str> = template.split('_').collect();
+    assert_eq!(
+        seps.len(),
+        count + 1,
+        "template {template:?} should have {count} fields"
+    );
+    fields(input, &seps)
+}
+
+/// Split `input` into fields separated by `seps`, trimming any surrounding
+/// whitespace.
+///
+/// `input` must start with `seps[0]`, and each field runs up to the first
+/// occurrence of the next separator. If the last separator is empty, the
+/// last field runs to the end of `input`. This is the machinery behind
+/// [`template`] and `#[derive(AocParse)]`.
+pub fn fields<'a>(input: &'a str, seps: &[&str]) -> Result<Vec<Field<'a>>, Error> {
+    let mut cursor = Cursor::new(input);
+    let (first, seps) = seps
+        .split_first()
+        .expect("fields needs at least one separator");
+    literal_str(&mut cursor, first)?;
+
+    let mut fields = vec![];
+    for (i, sep) in seps.iter().enumerate() {
+        let rest = cursor.rest();
+        let len = if sep.is_empty() && i == seps.len() - 1 {
+            rest.len()
+        } else {
+            rest.find(sep)
+                .ok_or_else(|| cursor.error(format_args!("a field followed by {sep:?}")))?
+        };
+        let field = &rest[..len];
+        let leading = field.len() - field.trim_start().len();
+        let mut field_cursor = cursor.clone();
+        field_cursor.advance(leading);
+        field_cursor.end = field_cursor.pos + field.trim().len();
+        fields.push(Field {
+            cursor: field_cursor,
+        });
+        cursor.advance(len + sep.len());
+    }
+    finish(&mut cursor)?;
+    Ok(fields)
+}
+
+fn literal_str(cursor: &mut Cursor, lit: &str) -> Result<(), Error> {
+    if !cursor.rest().starts_with(lit) {
+        return Err(cursor.error(format_args!("{lit:?}")));
+    }
+    cursor.advance(lit.len());
+    Ok(())
+}
+
+/// Return the name of `T`, without its module path.
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Parse fields of text separated by fixed strings.
+///
+/// `scan!(input, template, T1, T2, ...)` splits `input` like
+/// [`splits`](crate::splits) does, with each underscore in `template` marking
+/// a field, parses each field as the corresponding type, and returns the
+/// results as a tuple. If `input` doesn't match `template`, or a field
+/// doesn't parse, it returns a [`parse::Error`](Error).
+///
+/// ```
+/// # use aoc_utils::scan;
+/// let line = "position=< 9,  1> velocity=< 0,  2>";
+/// let (px, py, vx, vy) = scan!(line, "position=<_,_> velocity=<_,_>", i32, i32, i32, i32)?;
+/// assert_eq!((px, py, vx, vy), (9, 1, 0, 2));
+///
+/// let err = scan!("x=1, y=two", "x=_, y=_", i32, i32).unwrap_err();
+/// assert_eq!(err.to_string(),
+///            r#"line 1, column 8: expected i32, found "two" (invalid digit found in string)"#);
+/// # Ok::<(), aoc_utils::parse::Error>(())
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $template:expr, $($ty:ty),+ $(,)?) => {
+        (|| {
+            let count = [$(stringify!($ty)),+].len();
+            let mut fields = $crate::parse::template($input, $template, count)?.into_iter();
+            ::std::result::Result::<_, $crate::parse::Error>::Ok((
+                $( fields.next().unwrap().parse::<$ty>()?, )+
+            ))
+        })()
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn light(cursor: &mut Cursor) -> Result<bool, Error> {
+        literal("#")
+            .map(|()| true)
+            .or(literal(".").map(|()| false))
+            .parse(cursor)
+    }
+
+    fn button(cursor: &mut Cursor) -> Result<Vec<u32>, Error> {
+        bracketed("(", separated(unsigned(), literal(",")), ")")
+            .context("button")
+            .parse(cursor)
+    }
+
+    fn machine(cursor: &mut Cursor) -> Result<(Vec<bool>, Vec<Vec<u32>>), Error> {
+        let lights = bracketed("[", many(light), "]").parse(cursor)?;
+        spaces().parse(cursor)?;
+        let buttons = many(|cursor: &mut Cursor| {
+            let button = button(cursor)?;
+            spaces().parse(cursor)?;
+            Ok(button)
+        })
+        .parse(cursor)?;
+        Ok((lights, buttons))
+    }
+
+    #[test]
+    fn test_combinators() {
+        let machines = parse_lines("[.#] (0) (1,2)\n\n[#] (1)  \n", machine).unwrap();
+        assert_eq!(
+            machines,
+            [
+                (vec![false, true], vec![vec![0], vec![1, 2]]),
+                (vec![true], vec![vec![1]])
+            ]
+        );
+
+        let err = parse_lines("[.#] (0)\n[#x] (1)\n", machine).unwrap_err();
+        assert_eq!(err.position, Position { line: 2, column: 3 });
+        assert_eq!(err.expected, r#""]""#);
+
+        let err = parse_lines("[.#] (0,)\n", machine).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"line 1, column 9: expected a number, found ")" (in button)"#
+        );
+
+        let err = parse_lines("[.#] (0) {3}\n", machine).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"line 1, column 10: expected end of input, found "{3}""#
+        );
+
+        let err = parse_all("(1,2", button).unwrap_err();
+        assert_eq!(err.found, "end of input");
+        let err = parse_lines("(1,2\n", button).unwrap_err();
+        assert_eq!(err.found, "end of line");
+    }
+
+    #[test]
+    fn test_sections() {
+        let text = "[#.] (1)\n\n\n1\n2\n\n3\n\n4\n5\n";
+        let mut sections = Sections::new(text);
+        assert_eq!(sections.remaining(), 4);
+        let (lights, _) = sections.parse_next(machine).unwrap();
+        assert_eq!(lights, [true, false]);
+        let numbers = sections
+            .parse_rest(separated(unsigned::<u32>(), literal("\n")))
+            .unwrap();
+        assert_eq!(numbers, [vec![1, 2], vec![3], vec![4, 5]]);
+        sections.finish().unwrap();
+
+        let mut sections = Sections::new(text);
+        sections.parse_next(machine).unwrap();
+        sections.parse_next_lines(unsigned::<u32>()).unwrap();
+        let err = sections.finish().unwrap_err();
+        assert_eq!(err.section, Some(3));
+        assert_eq!(
+            err.to_string(),
+            r#"section 3, line 7, column 1: expected end of input, found "3""#
+        );
+
+        let mut sections = Sections::new("1\n2\n\n3\nx\n");
+        sections.parse_next_lines(unsigned::<u32>()).unwrap();
+        let err = sections.parse_next_lines(unsigned::<u32>()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"section 2, line 5, column 1: expected a number, found "x""#
+        );
+        let err = sections.parse_next(unsigned::<u32>()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 6, column 1: expected section 3, found end of input"
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(parse_all("-12", signed::<i32>()), Ok(-12));
+        assert_eq!(parse_all("+12", signed::<i32>()), Ok(12));
+        assert!(parse_all("-12", unsigned::<u32>()).is_err());
+        let err = parse_all("300", unsigned::<u8>()).unwrap_err();
+        assert_eq!(err.expected, "u8");
+        assert_eq!(
+            err.found,
+            r#""300" (number too large to fit in target type)"#
+        );
+        let err = parse_all("x", signed::<i64>()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"line 1, column 1: expected a number, found "x""#
+        );
+    }
+
+    #[test]
+    fn test_cursor() {
+        let mut cursor = Cursor::new("ab\ncdé\n");
+        assert_eq!(cursor.next(), Some('a'));
+        assert_eq!(cursor.peek(), Some('b'));
+        let lines: Vec<String> = cursor.lines().map(|line| line.rest().to_string()).collect();
+        assert_eq!(lines, ["b", "cdé"]);
+        cursor.advance(4);
+        assert_eq!(cursor.position(), Position { line: 2, column: 3 });
+        assert_eq!(cursor.by_ref().collect::<String>(), "é\n");
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn test_scan() {
+        let (name, x, y) = scan!("pos=<alice: 1, -2>", "pos=<_:_,_>", String, i32, i32).unwrap();
+        assert_eq!((name.as_str(), x, y), ("alice", 1, -2));
+
+        let (a, rest) = scan!("1 -> the rest", "_ -> _", u8, String).unwrap();
+        assert_eq!((a, rest.as_str()), (1, "the rest"));
+
+        let err = scan!("pos=<1 2>", "pos=<_,_>", i32, i32).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"line 1, column 6: expected a field followed by ",", found "1 2>""#
+        );
+        let err = scan!("pos=<1,2> extra", "pos=<_,_>", i32, i32).unwrap_err();
+        assert_eq!(err.position.column, 11);
+        assert!(scan!("p=<1,2>", "pos=<_,_>", i32, i32).is_err());
+    }
+}
@@ -0,0 +1,299 @@
+//! Printing grids in the terminal, with colors and highlighted cells.
+//!
+//! A [`Pretty`] displays a grid given its dimensions and a function that
+//! says how to draw each cell, so it works with any representation of the
+//! grid:
+//!
+//! ```
+//! use aoc_utils::pretty::{Color, Glyph, Pretty};
+//!
+//! let map = ["..........", "....#.....", "....#.....", "..........", ".........."];
+//! let path = [(3, 4), (3, 5), (2, 5), (1, 5)];
+//! let pretty = Pretty::new((5, 10), |(row, col)| match map[row].as_bytes()[col] {
+//!     b'#' => Glyph::new('#', Color::Gray),
+//!     _ => '.'.into(),
+//! })
+//! .highlight(path)
+//! .crop('.')
+//! .color(false);
+//! assert_eq!(pretty.to_string(), "#.\n#.\n..\n");
+//! ```
+//!
+//! Highlighted cells are drawn with a colored background, which makes it
+//! easy to show a path through a map, or the cells that changed in the last
+//! step of a simulation.
+//!
+//! Colors are only written when standard output is a terminal, and the
+//! `NO_COLOR` environment variable isn't set; otherwise, the grid is printed
+//! as plain text, without highlights.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::IsTerminal;
+use std::ops::Range;
+
+/// A color for a character or a highlight.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Return the SGR parameters selecting this color, where `base` is 30
+    /// for the foreground, or 40 for the background.
+    fn sgr(self, base: u8) -> String {
+        let basic = |n: u8| (base + n).to_string();
+        match self {
+            Color::Black => basic(0),
+            Color::Red => basic(1),
+            Color::Green => basic(2),
+            Color::Yellow => basic(3),
+            Color::Blue => basic(4),
+            Color::Magenta => basic(5),
+            Color::Cyan => basic(6),
+            Color::White => basic(7),
+            Color::Gray => (base + 60).to_string(),
+            Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+        }
+    }
+}
+
+/// How to draw a single grid cell.
+///
+/// The terminal viewer in the `tui` module draws cells with these too.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Glyph {
+    pub ch: char,
+    pub color: Option<Color>,
+}
+
+impl Glyph {
+    pub fn new(ch: char, color: Color) -> Glyph {
+        Glyph {
+            ch,
+            color: Some(color),
+        }
+    }
+}
+
+impl From<char> for Glyph {
+    fn from(ch: char) -> Glyph {
+        Glyph { ch, color: None }
+    }
+}
+
+/// Return true if grids printed to standard output should use color.
+pub fn use_color() -> bool {
+    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+/// A grid, ready to be displayed.
+pub struct Pretty<F> {
+    /// The grid's dimensions, as `(rows, columns)`.
+    dim: (usize, usize),
+
+    /// The function that says how to draw the cell at `(row, column)`.
+    glyph: F,
+
+    /// The rows and columns to show, if not the whole grid.
+    window: Option<(Range<usize>, Range<usize>)>,
+
+    /// Highlighted cells, and their background colors.
+    highlights: HashMap<(usize, usize), Color>,
+
+    /// If set, trim rows and columns at the edges that are entirely this
+    /// character, and have no highlights.
+    blank: Option<char>,
+
+    /// Whether to write ANSI color escapes.
+    color: bool,
+}
+
+impl<F> Pretty<F>
+where
+    F: Fn((usize, usize)) -> Glyph,
+{
+    /// Return a `Pretty` for a grid with dimensions `dim`, given as `(rows,
+    /// columns)`, whose cells are drawn by `glyph`.
+    ///
+    /// By default, the whole grid is shown, in color if [`use_color`]
+    /// returns true.
+    pub fn new(dim: (usize, usize), glyph: F) -> Self {
+        Pretty {
+            dim,
+            glyph,
+            window: None,
+            highlights: HashMap::new(),
+            blank: None,
+            color: use_color(),
+        }
+    }
+
+    /// Highlight `points`, given as `(row, column)` pairs, in yellow.
+    pub fn highlight<I>(self, points: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        self.highlight_with(points, Color::Yellow)
+    }
+
+    /// Highlight `points` in `color`.
+    ///
+    /// Later highlights take precedence over earlier ones.
+    pub fn highlight_with<I>(mut self, points: I, color: Color) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        self.highlights
+            .extend(points.into_iter().map(|point| (point, color)));
+        self
+    }
+
+    /// Show only the given rows and columns of the grid.
+    pub fn window(mut self, rows: Range<usize>, columns: Range<usize>) -> Self {
+        self.window = Some((rows, columns));
+        self
+    }
+
+    /// Leave out rows and columns at the edges that are entirely `blank`,
+    /// and have no highlighted cells.
+    pub fn crop(mut self, blank: char) -> Self {
+        self.blank = Some(blank);
+        self
+    }
+
+    /// Write colors and highlights if `color` is true, or plain text if
+    /// it's false.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Return the rows and columns we should actually display.
+    fn bounds(&self) -> (Range<usize>, Range<usize>) {
+        let (rows, columns) = self
+            .window
+            .clone()
+            .unwrap_or((0..self.dim.0, 0..self.dim.1));
+        let Some(blank) = self.blank else {
+            return (rows, columns);
+        };
+
+        let mut occupied = rows
+            .flat_map(|row| columns.clone().map(move |col| (row, col)))
+            .filter(|&point| {
+                (self.glyph)(point).ch != blank || self.highlights.contains_key(&point)
+            });
+        let Some(first) = occupied.next() else {
+            return (0..0, 0..0);
+        };
+        let (min, max) = occupied.fold((first, first), |(min, max), (row, col)| {
+            (
+                (min.0.min(row), min.1.min(col)),
+                (max.0.max(row), max.1.max(col)),
+            )
+        });
+        (min.0..max.0 + 1, min.1..max.1 + 1)
+    }
+}
+
+impl<F> fmt::Display for Pretty<F>
+where
+    F: Fn((usize, usize)) -> Glyph,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rows, columns) = self.bounds();
+        for row in rows {
+            // The foreground and background colors currently in effect.
+            let mut current = (None, None);
+            for col in columns.clone() {
+                let glyph = (self.glyph)((row, col));
+                if self.color {
+                    let style = (glyph.color, self.highlights.get(&(row, col)).copied());
+                    if style != current {
+                        if current != (None, None) {
+                            f.write_str("\x1b[0m")?;
+                        }
+                        if let Some(fg) = style.0 {
+                            write!(f, "\x1b[{}m", fg.sgr(30))?;
+                        }
+                        if let Some(bg) = style.1 {
+                            write!(f, "\x1b[{}m", bg.sgr(40))?;
+                        }
+                        current = style;
+                    }
+                }
+                write!(f, "{}", glyph.ch)?;
+            }
+            if current != (None, None) {
+                f.write_str("\x1b[0m")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn glyph((row, col): (usize, usize)) -> Glyph {
+        if row == col {
+            Glyph::new('\\', Color::Red)
+        } else {
+            '.'.into()
+        }
+    }
+
+    #[test]
+    fn test_plain() {
+        let pretty = Pretty::new((3, 4), glyph).highlight([(0, 3)]).color(false);
+        assert_eq!(pretty.to_string(), "\\...\n.\\..\n..\\.\n");
+
+        let pretty = pretty.window(1..3, 0..2);
+        assert_eq!(pretty.to_string(), ".\\\n..\n");
+    }
+
+    #[test]
+    fn test_crop() {
+        let pretty = Pretty::new((5, 5), |(row, col)| {
+            if (1..3).contains(&row) && col == 2 {
+                '#'.into()
+            } else {
+                ' '.into()
+            }
+        })
+        .color(false);
+        assert_eq!(pretty.crop(' ').to_string(), "#\n#\n");
+
+        let pretty = Pretty::new((5, 5), |_| ' '.into()).color(false);
+        let pretty = pretty.highlight([(3, 3)]).crop(' ');
+        assert_eq!(pretty.to_string(), " \n");
+
+        let pretty = Pretty::new((5, 5), |_| ' '.into()).crop(' ');
+        assert_eq!(pretty.to_string(), "");
+    }
+
+    #[test]
+    fn test_color() {
+        let pretty = Pretty::new((2, 3), glyph)
+            .highlight([(0, 0), (0, 1)])
+            .highlight_with([(1, 2)], Color::Rgb(0, 0, 128))
+            .color(true);
+        assert_eq!(
+            pretty.to_string(),
+            "\x1b[31m\x1b[43m\\\x1b[0m\x1b[43m.\x1b[0m.\n\
+             .\x1b[31m\\\x1b[0m\x1b[48;2;0;0;128m.\x1b[0m\n"
+        );
+    }
+}
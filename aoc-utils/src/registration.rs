@@ -0,0 +1,355 @@
+//! Aligning point clouds seen from different vantage points.
+//!
+//! Suppose several scanners each report the positions of the points they can
+//! see, relative to their own position and orientation, and that the scanners'
+//! ranges overlap. If we can find enough points two scanners agree on, we can
+//! work out how one scanner's coordinate system relates to the other's. Doing
+//! this repeatedly lets us bring every scanner's reports into a single frame.
+//!
+//! Scanners are assumed to be rotated only by multiples of a right angle, as
+//! generated by [`Matrix::rotations`].
+
+use crate::linear::{Matrix, Vector};
+use num_traits::{One, Zero};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A transformation from one scanner's coordinate system to another's.
+///
+/// A point `p` in the first system is at `rotation * p + translation` in the
+/// second. Since the first scanner is at the origin of its own coordinate
+/// system, `translation` is also its position in the second.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Alignment<T, const N: usize> {
+    pub rotation: Matrix<T, N>,
+    pub translation: Vector<T, N>,
+
+    /// The number of points the two scanners agree on under this alignment.
+    pub overlap: usize,
+}
+
+impl<T, const N: usize> Alignment<T, N>
+where
+    T: Copy + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    /// Return the alignment that leaves everything where it is.
+    pub fn identity() -> Self {
+        Alignment {
+            rotation: Matrix::identity(),
+            translation: Vector::zero(),
+            overlap: 0,
+        }
+    }
+
+    pub fn apply(&self, point: Vector<T, N>) -> Vector<T, N> {
+        self.rotation * point + self.translation
+    }
+
+    /// Return the alignment that applies `inner` first, and then `self`.
+    ///
+    /// The result's `overlap` is `inner`'s, since that's the most recent
+    /// connection that was made.
+    pub fn compose(&self, inner: &Self) -> Self {
+        Alignment {
+            rotation: self.rotation * inner.rotation,
+            translation: self.apply(inner.translation),
+            overlap: inner.overlap,
+        }
+    }
+}
+
+/// Return every alignment of `moving` that matches at least `min_overlap` of
+/// its points with points in `fixed`.
+///
+/// Each alignment maps `moving`'s coordinates into `fixed`'s. They are sorted
+/// by decreasing overlap; alignments with equal overlap are ordered by their
+/// rotation's position in [`Matrix::rotations`], and then by translation, so
+/// the result is the same from run to run. Both slices are assumed to have no
+/// duplicate points.
+pub fn align<T, const N: usize>(
+    fixed: &[Vector<T, N>],
+    moving: &[Vector<T, N>],
+    min_overlap: usize,
+) -> Vec<Alignment<T, N>>
+where
+    T: Copy + Ord + Hash + Zero + One + Neg<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let mut alignments = vec![];
+    for rotation in Matrix::rotations() {
+        // Compare every point in `fixed` with every rotated point in `moving`.
+        // If some translation lines up `min_overlap` pairs, that's a match.
+        // Since neither set has duplicates, each point of `moving` can match
+        // at most one point in `fixed` under any given translation, so the
+        // count for a translation is exactly the number of points it aligns.
+        let mut counts: HashMap<Vector<T, N>, usize> = HashMap::new();
+        for &m in moving {
+            let rotated = rotation * m;
+            for &f in fixed {
+                *counts.entry(f - rotated).or_insert(0) += 1;
+            }
+        }
+
+        // `HashMap` iteration order varies from run to run, so put this
+        // rotation's matches in order by translation.
+        let start = alignments.len();
+        alignments.extend(counts.into_iter().filter_map(|(translation, overlap)| {
+            (overlap >= min_overlap).then_some(Alignment {
+                rotation,
+                translation,
+                overlap,
+            })
+        }));
+        alignments[start..].sort_by_key(|a| a.translation);
+    }
+
+    // This sort is stable, so ties stay in rotation and translation order.
+    alignments.sort_by_key(|a| std::cmp::Reverse(a.overlap));
+    alignments
+}
+
+/// The result of bringing many point clouds into a single frame.
+#[derive(Debug)]
+pub struct Assembly<T, const N: usize> {
+    /// For each cloud, the alignment mapping its coordinates into the first
+    /// cloud's. The `translation` of each is that scanner's position.
+    pub alignments: Vec<Alignment<T, N>>,
+
+    /// The union of all the clouds' points, in the first cloud's coordinates.
+    pub points: HashSet<Vector<T, N>>,
+}
+
+/// An error indicating that some clouds couldn't be aligned with the first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Disconnected {
+    /// The indices of the clouds we couldn't place.
+    pub unplaced: Vec<usize>,
+}
+
+impl fmt::Display for Disconnected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "couldn't align clouds {:?} with the others",
+            self.unplaced
+        )
+    }
+}
+
+impl std::error::Error for Disconnected {}
+
+/// Bring all of `clouds` into the coordinate system of `clouds[0]`.
+///
+/// Two clouds are considered connected if some alignment matches at least
+/// `min_overlap` of their points. Every cloud must be connected to the first,
+/// possibly through a chain of others.
+pub fn assemble<T, const N: usize>(
+    clouds: &[Vec<Vector<T, N>>],
+    min_overlap: usize,
+) -> Result<Assembly<T, N>, Disconnected>
+where
+    T: Copy + Ord + Hash + Zero + One + Neg<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    if clouds.is_empty() {
+        return Ok(Assembly {
+            alignments: vec![],
+            points: HashSet::new(),
+        });
+    }
+
+    // Distances between points are unaffected by rotation and translation, so
+    // two clouds that share `min_overlap` points must also share at least as
+    // many pairwise distances as there are pairs of those points. Checking
+    // this first lets us skip most hopeless pairs cheaply.
+    let fingerprints: Vec<Vec<T>> = clouds.iter().map(|cloud| fingerprint(cloud)).collect();
+    let min_shared = min_overlap * min_overlap.saturating_sub(1) / 2;
+
+    let mut alignments: Vec<Option<Alignment<T, N>>> = vec![None; clouds.len()];
+    alignments[0] = Some(Alignment::identity());
+    let mut pending = VecDeque::from([0]);
+    while let Some(placed) = pending.pop_front() {
+        let to_first = alignments[placed].unwrap();
+        for next in 0..clouds.len() {
+            if alignments[next].is_some()
+                || count_shared(&fingerprints[placed], &fingerprints[next]) < min_shared
+            {
+                continue;
+            }
+
+            if let Some(best) = align(&clouds[placed], &clouds[next], min_overlap).first() {
+                alignments[next] = Some(to_first.compose(best));
+                pending.push_back(next);
+            }
+        }
+    }
+
+    let unplaced: Vec<usize> = (0..clouds.len())
+        .filter(|&i| alignments[i].is_none())
+        .collect();
+    if !unplaced.is_empty() {
+        return Err(Disconnected { unplaced });
+    }
+
+    let alignments: Vec<Alignment<T, N>> = alignments.into_iter().flatten().collect();
+    let points = clouds
+        .iter()
+        .zip(&alignments)
+        .flat_map(|(cloud, alignment)| cloud.iter().map(|&p| alignment.apply(p)))
+        .collect();
+
+    Ok(Assembly { alignments, points })
+}
+
+/// Return the sorted squared distances between all pairs of points in `cloud`.
+fn fingerprint<T, const N: usize>(cloud: &[Vector<T, N>]) -> Vec<T>
+where
+    T: Copy + Ord + Zero + Sub<Output = T> + Mul<Output = T>,
+{
+    let mut distances: Vec<T> = cloud
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)| cloud[..i].iter().map(move |&b| a.distance_squared(b)))
+        .collect();
+    distances.sort();
+    distances
+}
+
+/// Return the size of the multiset intersection of two sorted vectors.
+fn count_shared<T: Ord>(a: &[T], b: &[T]) -> usize {
+    let (mut i, mut j) = (0, 0);
+    let mut count = 0;
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                count += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    type V3 = Vector<i32, 3>;
+
+    /// Generate `n` distinct, scattered points within a 2000-unit cube
+    /// centered on `center`.
+    fn cloud(seed: u64, center: V3, n: usize) -> Vec<V3> {
+        let mut state = seed;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 33) % 2001) as i32 - 1000
+        };
+        let mut points = HashSet::new();
+        while points.len() < n {
+            points.insert(center + Vector([next(), next(), next()]));
+        }
+        points.into_iter().collect()
+    }
+
+    /// Return what a scanner at `position`, rotated by `rotation`, would
+    /// report for `points`.
+    fn view(points: &[V3], rotation: Matrix<i32, 3>, position: V3) -> Vec<V3> {
+        let inverse = rotation.transpose();
+        points.iter().map(|&p| inverse * (p - position)).collect()
+    }
+
+    #[test]
+    fn test_align() {
+        let points = cloud(1, Vector([0, 0, 0]), 20);
+        let rotation = Matrix::rotations()[17];
+        let position = Vector([100, -200, 300]);
+
+        // The moving scanner only sees the first 15 points, plus 5 of its own.
+        let mut seen = view(&points[..15], rotation, position);
+        seen.extend(view(&cloud(2, Vector([5000, 0, 0]), 5), rotation, position));
+
+        let found = align(&points, &seen, 12);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].rotation, rotation);
+        assert_eq!(found[0].translation, position);
+        assert_eq!(found[0].overlap, 15);
+
+        assert!(align(&points, &seen, 16).is_empty());
+    }
+
+    #[test]
+    fn test_align_ties() {
+        // A cloud with some symmetry, so that many alignments match equally
+        // many points.
+        let points: Vec<V3> = vec![
+            Vector([1, 0, 0]),
+            Vector([-1, 0, 0]),
+            Vector([0, 2, 0]),
+            Vector([0, -2, 0]),
+            Vector([0, 0, 3]),
+        ];
+        let rotations = Matrix::<i32, 3>::rotations();
+        let key = |a: &Alignment<i32, 3>| {
+            let index = rotations.iter().position(|&r| r == a.rotation);
+            (std::cmp::Reverse(a.overlap), index, a.translation)
+        };
+        let found = align(&points, &points, 1);
+        assert!(found.windows(2).all(|pair| key(&pair[0]) < key(&pair[1])));
+        assert_eq!(found[0].rotation, Matrix::identity());
+        assert_eq!(found[0].overlap, 5);
+        assert_eq!(found, align(&points, &points, 1));
+    }
+
+    #[test]
+    fn test_assemble() {
+        // A chain of regions, each overlapping the next.
+        let regions: Vec<Vec<V3>> = (0..4)
+            .map(|i| cloud(i + 10, Vector([i as i32 * 1500, 0, 0]), 25))
+            .collect();
+        let rotations = Matrix::<i32, 3>::rotations();
+        let positions = [
+            Vector([0, 0, 0]),
+            Vector([1400, 10, -20]),
+            Vector([3100, -50, 40]),
+            Vector([4400, 30, 30]),
+        ];
+        let orientations = [rotations[0], rotations[5], rotations[13], rotations[22]];
+
+        // Scanner `i` sees region `i` and half of region `i + 1`, so each
+        // neighboring pair shares at least twelve points.
+        let clouds: Vec<Vec<V3>> = (0..4)
+            .map(|i| {
+                let mut visible = regions[i].clone();
+                if i + 1 < regions.len() {
+                    visible.extend_from_slice(&regions[i + 1][..12]);
+                }
+                view(&visible, orientations[i], positions[i])
+            })
+            .collect();
+
+        // Hand the clouds over out of order, so the chain isn't trivial.
+        let order = [1, 3, 0, 2];
+        let shuffled: Vec<Vec<V3>> = order.iter().map(|&i| clouds[i].clone()).collect();
+        let assembly = assemble(&shuffled, 12).unwrap();
+        let relative_to_first = |p: V3| orientations[1].transpose() * (p - positions[1]);
+        for (k, &i) in order.iter().enumerate() {
+            assert_eq!(
+                assembly.alignments[k].translation,
+                relative_to_first(positions[i])
+            );
+        }
+        assert_eq!(assembly.points.len(), 100);
+
+        let mut cut = shuffled.clone();
+        cut[1] = cloud(99, Vector([0, 0, 0]), 10);
+        assert_eq!(
+            assemble(&cut, 12).unwrap_err(),
+            Disconnected { unplaced: vec![1] }
+        );
+    }
+}
@@ -0,0 +1,357 @@
+//! Drawing grids and point sets to PNG files.
+//!
+//! This module is only available with the `render` feature.
+//!
+//! A [`Raster`] covers a rectangle of cells, in whatever coordinate system the
+//! puzzle uses, and maps it onto a bitmap. When the rectangle is too large to
+//! draw one pixel per cell, each pixel covers a `scale` by `scale` square of
+//! cells, and its color is the average of the colors painted on those cells,
+//! with unpainted cells counting as the background. This makes it practical
+//! to draw coordinate spaces hundreds of thousands of cells across: even a
+//! single painted cell leaves a trace, and a region's density shows as its
+//! brightness.
+//!
+//! Markers and paths are drawn on top at full strength, one pixel per cell, so
+//! they don't fade away when scaled down.
+//!
+//! Coordinates are always `(row, column)` pairs, like the indices of an
+//! [`Array2`].
+
+use crate::intersection::Intersection;
+use ndarray::Array2;
+use std::io::Write as _;
+use std::ops::Range;
+use std::path::Path;
+
+pub use image::Rgb;
+
+/// A bitmap onto which we can paint cells, runs of cells, markers and paths.
+pub struct Raster {
+    /// The first row and column of the area we cover.
+    origin: (i64, i64),
+
+    /// The dimensions of the output bitmap: (rows, columns).
+    size: (usize, usize),
+
+    /// The number of cells along each side of the square each pixel covers.
+    scale: u64,
+
+    /// The color of unpainted cells.
+    background: Rgb<u8>,
+
+    /// For each pixel, the sum of the colors painted on the cells it covers,
+    /// weighted by the number of cells painted that color.
+    ///
+    /// Pixels appear in row-major order.
+    totals: Vec<[u64; 3]>,
+
+    /// For each pixel, the number of its cells that have been painted.
+    painted: Vec<u64>,
+
+    /// For each pixel, the color of any marker or path drawn over it.
+    overlay: Vec<Option<Rgb<u8>>>,
+}
+
+impl Raster {
+    /// Return a `Raster` covering `rows` and `columns`, with each pixel
+    /// covering a `scale` by `scale` square of cells.
+    pub fn new(rows: Range<i64>, columns: Range<i64>, scale: u64) -> Raster {
+        assert!(scale > 0);
+        let pixels = |range: &Range<i64>| (range.end - range.start).max(0) as u64;
+        let size = (
+            pixels(&rows).div_ceil(scale) as usize,
+            pixels(&columns).div_ceil(scale) as usize,
+        );
+        let len = size.0 * size.1;
+        Raster {
+            origin: (rows.start, columns.start),
+            size,
+            scale,
+            background: Rgb([0, 0, 0]),
+            totals: vec![[0; 3]; len],
+            painted: vec![0; len],
+            overlay: vec![None; len],
+        }
+    }
+
+    /// Return a `Raster` covering `rows` and `columns`, scaled down just
+    /// enough that neither side of the bitmap is longer than `max_size`
+    /// pixels.
+    pub fn fit(rows: Range<i64>, columns: Range<i64>, max_size: usize) -> Raster {
+        let longest = (rows.end - rows.start)
+            .max(columns.end - columns.start)
+            .max(1) as u64;
+        let scale = longest.div_ceil(max_size as u64);
+        Raster::new(rows, columns, scale)
+    }
+
+    /// Return a `Raster` showing `grid`, with `color` giving each element's
+    /// color, and no more than `max_size` pixels on a side.
+    pub fn from_grid<T, F>(grid: &Array2<T>, max_size: usize, mut color: F) -> Raster
+    where
+        F: FnMut(&T) -> Rgb<u8>,
+    {
+        let (rows, columns) = grid.dim();
+        let mut raster = Raster::fit(0..rows as i64, 0..columns as i64, max_size);
+        for ((row, column), elt) in grid.indexed_iter() {
+            raster.paint((row as i64, column as i64), color(elt));
+        }
+        raster
+    }
+
+    /// Return a `Raster` just large enough to cover `points`, with each point
+    /// painted `color`, and no more than `max_size` pixels on a side.
+    pub fn from_points<I>(points: I, max_size: usize, color: Rgb<u8>) -> Raster
+    where
+        I: IntoIterator<Item = (i64, i64)>,
+        I::IntoIter: Clone,
+    {
+        let points = points.into_iter();
+        let bounds = |axis: fn(&(i64, i64)) -> i64| {
+            let min = points.clone().map(|p| axis(&p)).min().unwrap_or(0);
+            let max = points.clone().map(|p| axis(&p)).max().unwrap_or(-1);
+            min..max + 1
+        };
+        let mut raster = Raster::fit(bounds(|p| p.0), bounds(|p| p.1), max_size);
+        for point in points {
+            raster.paint(point, color);
+        }
+        raster
+    }
+
+    /// Set the color of unpainted cells. The default is black.
+    pub fn background(mut self, color: Rgb<u8>) -> Self {
+        self.background = color;
+        self
+    }
+
+    /// Return the dimensions of the bitmap, as `(rows, columns)`.
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    /// Return the number of cells along each side of a pixel.
+    pub fn scale(&self) -> u64 {
+        self.scale
+    }
+
+    /// Paint the cell at `(row, column)` `color`.
+    pub fn paint(&mut self, (row, column): (i64, i64), color: Rgb<u8>) {
+        self.paint_rect(row..row + 1, column..column + 1, color);
+    }
+
+    /// Paint every cell in the given `rows` and `columns` `color`.
+    ///
+    /// This takes time proportional to the number of pixels affected, not the
+    /// number of cells, so it's the way to fill large areas.
+    pub fn paint_rect(&mut self, rows: Range<i64>, columns: Range<i64>, color: Rgb<u8>) {
+        let (Some(rows), Some(columns)) = (self.offsets(rows, 0), self.offsets(columns, 1)) else {
+            return;
+        };
+        for rows in divide_range(rows, self.scale) {
+            let height = rows.end - rows.start;
+            let pixel_row = (rows.start / self.scale) as usize;
+            for columns in divide_range(columns.clone(), self.scale) {
+                let width = columns.end - columns.start;
+                let pixel = pixel_row * self.size.1 + (columns.start / self.scale) as usize;
+                let area = width * height;
+                for (total, component) in self.totals[pixel].iter_mut().zip(color.0) {
+                    *total += area * component as u64;
+                }
+                self.painted[pixel] += area;
+            }
+        }
+    }
+
+    /// Draw a marker over the pixel containing `(row, column)`.
+    ///
+    /// Markers replace whatever was painted underneath, regardless of scale.
+    pub fn mark(&mut self, (row, column): (i64, i64), color: Rgb<u8>) {
+        if let Some(pixel) = self.pixel((row, column)) {
+            self.overlay[pixel.0 * self.size.1 + pixel.1] = Some(color);
+        }
+    }
+
+    /// Draw a path through `points`, marking each point and the straight
+    /// lines between successive points.
+    ///
+    /// For example, this could show a route found by [`astar`], given the
+    /// nodes along the path.
+    ///
+    /// [`astar`]: crate::astar::astar
+    pub fn path<I>(&mut self, points: I, color: Rgb<u8>)
+    where
+        I: IntoIterator<Item = (i64, i64)>,
+    {
+        let mut previous: Option<(i64, i64)> = None;
+        for point in points {
+            let here = self.unclipped_pixel(point);
+            let from = previous.unwrap_or(here);
+            // Step along the line in pixel space, so long lines cost
+            // time proportional to their length on screen.
+            let steps = (here.0 - from.0).abs().max((here.1 - from.1).abs());
+            for step in 0..=steps {
+                let lerp = |a: i64, b: i64| a + (b - a) * step / steps.max(1);
+                let (row, column) = (lerp(from.0, here.0), lerp(from.1, here.1));
+                if (0..self.size.0 as i64).contains(&row)
+                    && (0..self.size.1 as i64).contains(&column)
+                {
+                    self.overlay[row as usize * self.size.1 + column as usize] = Some(color);
+                }
+            }
+            previous = Some(here);
+        }
+    }
+
+    /// Produce the final bitmap.
+    pub fn into_image(self) -> image::RgbImage {
+        let cells_per_pixel = self.scale * self.scale;
+        let pixels: Vec<u8> = self
+            .totals
+            .iter()
+            .zip(&self.painted)
+            .zip(&self.overlay)
+            .flat_map(|((totals, &painted), overlay)| {
+                if let Some(color) = overlay {
+                    return color.0;
+                }
+                let unpainted = cells_per_pixel.saturating_sub(painted);
+                let mut pixel = [0; 3];
+                for ((out, total), background) in
+                    pixel.iter_mut().zip(totals).zip(self.background.0)
+                {
+                    let sum = total + unpainted * background as u64;
+                    *out = u8::try_from(sum / cells_per_pixel.max(painted)).unwrap();
+                }
+                pixel
+            })
+            .collect();
+        image::RgbImage::from_vec(self.size.1 as u32, self.size.0 as u32, pixels).unwrap()
+    }
+
+    /// Write the bitmap to `path` as a PNG file.
+    pub fn save(self, path: &Path) -> anyhow::Result<()> {
+        let image = self.into_image();
+        let stream = std::fs::File::create(path)?;
+        let mut stream = std::io::BufWriter::new(stream);
+        image.write_to(&mut stream, image::ImageFormat::Png)?;
+        stream.flush()?;
+        Ok(())
+    }
+
+    /// Return `range`'s offsets from our origin along `axis`, clipped to
+    /// the area we cover.
+    fn offsets(&self, range: Range<i64>, axis: usize) -> Option<Range<u64>> {
+        let (origin, size) = match axis {
+            0 => (self.origin.0, self.size.0),
+            _ => (self.origin.1, self.size.1),
+        };
+        let covered = origin..origin + (size as u64 * self.scale) as i64;
+        let range = range.intersection(covered);
+        (range.start < range.end)
+            .then(|| (range.start - origin) as u64..(range.end - origin) as u64)
+    }
+
+    /// Return the pixel containing `(row, column)`, if we cover it.
+    fn pixel(&self, point: (i64, i64)) -> Option<(usize, usize)> {
+        let (row, column) = self.unclipped_pixel(point);
+        ((0..self.size.0 as i64).contains(&row) && (0..self.size.1 as i64).contains(&column))
+            .then_some((row as usize, column as usize))
+    }
+
+    /// Return the pixel that would contain `(row, column)`, if the bitmap
+    /// extended that far.
+    fn unclipped_pixel(&self, (row, column): (i64, i64)) -> (i64, i64) {
+        let scale = self.scale as i64;
+        (
+            (row - self.origin.0).div_euclid(scale),
+            (column - self.origin.1).div_euclid(scale),
+        )
+    }
+}
+
+/// Split `range` up into a series of ranges, splitting at each multiple of `scale`.
+///
+/// Examples:
+///
+/// - `divide_range(5..35, 10)` splits the range at each multiple of `10`,
+///   producing the ranges `5..10`, `10..20`, `20..30`, and `30..35`.
+///
+/// - `divide_range(5..7, 10)` produces only the range `5..7`.
+///
+/// - `divide_range(9..20, 10)` produces the ranges `9..10` and `10..20`.
+fn divide_range(range: Range<u64>, scale: u64) -> impl Iterator<Item = Range<u64>> {
+    let mut start = range.start - range.start % scale;
+    std::iter::from_fn(move || {
+        let next = start + scale;
+        let chunk = (start..next).intersection(range.clone());
+        start = next;
+        (chunk.start < chunk.end).then_some(chunk)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_divide_range() {
+        assert_eq!(
+            divide_range(5..35, 10).collect::<Vec<_>>(),
+            vec![5..10, 10..20, 20..30, 30..35]
+        );
+        assert_eq!(divide_range(5..7, 10).collect::<Vec<_>>(), vec![5..7]);
+        assert_eq!(
+            divide_range(9..20, 10).collect::<Vec<_>>(),
+            vec![9..10, 10..20]
+        );
+    }
+
+    #[test]
+    fn test_averaging() {
+        // Four pixels, each covering a 10x10 square, starting at (-5, 100).
+        let mut raster = Raster::new(-5..15, 100..120, 10).background(Rgb([0, 0, 200]));
+        assert_eq!(raster.size(), (2, 2));
+
+        // Fill the top left pixel, and half of the top right pixel.
+        raster.paint_rect(-5..5, 100..115, Rgb([100, 0, 0]));
+        // Paint a single cell in the bottom left.
+        raster.paint((14, 109), Rgb([200, 0, 0]));
+        // Mark the bottom right.
+        raster.mark((10, 119), Rgb([1, 2, 3]));
+        // Painting outside the raster is ignored.
+        raster.paint_rect(100..200, 100..200, Rgb([255, 255, 255]));
+        raster.mark((100, 100), Rgb([255, 255, 255]));
+
+        let image = raster.into_image();
+        assert_eq!(image.dimensions(), (2, 2));
+        assert_eq!(image.get_pixel(0, 0), &Rgb([100, 0, 0]));
+        assert_eq!(image.get_pixel(1, 0), &Rgb([50, 0, 100]));
+        assert_eq!(image.get_pixel(0, 1), &Rgb([2, 0, 198]));
+        assert_eq!(image.get_pixel(1, 1), &Rgb([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_grid_and_path() {
+        let grid = Array2::from_shape_fn((3, 4), |(row, column)| row * column);
+        let mut raster = Raster::from_grid(&grid, 100, |&n| Rgb([n as u8 * 10; 3]));
+        assert_eq!(raster.scale(), 1);
+        raster.path([(0, 0), (0, 2), (2, 2)], Rgb([255, 0, 0]));
+
+        let image = raster.into_image();
+        assert_eq!(image.dimensions(), (4, 3));
+        assert_eq!(image.get_pixel(3, 2), &Rgb([60; 3]));
+        assert_eq!(image.get_pixel(3, 1), &Rgb([30; 3]));
+        for (row, column) in [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)] {
+            assert_eq!(image.get_pixel(column, row), &Rgb([255, 0, 0]));
+        }
+        assert_eq!(image.get_pixel(1, 1), &Rgb([10; 3]));
+    }
+
+    #[test]
+    fn test_fit() {
+        let raster = Raster::from_points([(0, 0), (999, 4999)], 100, Rgb([255; 3]));
+        assert_eq!(raster.scale(), 50);
+        assert_eq!(raster.size(), (20, 100));
+    }
+}
@@ -0,0 +1,694 @@
+//! A command-line driver for registered [`Solution`](crate::solution::Solution)s.
+//!
+//! A binary passes [`main`] the list of days it knows about. The command line
+//! then selects which to run:
+//!
+//! ```text
+//! aoc [verify [--record] | bench [--save-baseline NAME] [--baseline NAME] [--time SECS]]
+//!     [--input PATH] [--mem-limit SIZE] [-v | --verbose]... [YEAR [DAY [PART]]]
+//! ```
+//!
+//! Each day's input is read from its usual place in the inputs directory; see
+//! [`input`](crate::input). Days with no input there are skipped, unless
+//! they were selected individually. Passing `--input` supplies the input from
+//! `PATH` instead, or from standard input if `PATH` is `-`; in that case, the
+//! selection must narrow things down to a single day.
+//!
+//! Inputs missing from the inputs directory are looked for next in the
+//! per-user cache. If the `http` feature is enabled and the `AOC_SESSION`
+//! environment variable holds an Advent of Code session cookie, inputs
+//! missing from there too are downloaded into the cache; see
+//! [`fetch`](crate::fetch).
+//!
+//! In `verify` mode, the runner compares each answer with the one recorded
+//! in the year's answers file (see [`answers`](crate::answers)), and reports
+//! whether it passed, failed, or is new. With `--record`, new answers are
+//! added to the file. Verifying fails if any answer differs from the record.
+//!
+//! In `bench` mode, the runner times each selected day repeatedly for about
+//! `--time` seconds (three, by default), and reports statistics on how long
+//! parsing and each part took. This also runs any alternative implementations
+//! of the selected days, and checks that they all agree on the answers. Times
+//! can be saved with `--save-baseline`, and compared with a saved baseline
+//! using `--baseline`; see [`bench`](crate::bench).
+//!
+//! If the binary's global allocator is [`alloc::Counting`], the runner also
+//! reports how much memory parsing and each part used: the peak number of
+//! bytes allocated at once, and the number and total size of allocations.
+//! Passing `--mem-limit` with a size like `512M` or `2G` aborts the run if a
+//! solution tries to allocate more than that, saying which part was running.
+//!
+//! Each `-v` or `--verbose` flag prints more of what the solutions log, and
+//! `-v` alone prints how long each day's parsing and parts took as they
+//! finish. Flags can be combined, as in `-vv`; see [`trace`](crate::trace).
+
+use crate::alloc::{self, Bytes, Usage};
+use crate::answers::{Answers, Verdict};
+use crate::bench::{self, Baseline, Measurement, Summary};
+use crate::fetch::{Fetcher, Store};
+use crate::input::{self, Inputs, Source};
+use crate::solution::{Day, Part, Run};
+use anyhow::{Context, anyhow, bail};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// What the command line asked for.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Options {
+    pub mode: Mode,
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub input: Source,
+
+    /// When verifying, record answers we have no record of.
+    pub record: bool,
+
+    /// When benchmarking, the baseline to save times to.
+    pub save_baseline: Option<String>,
+
+    /// When benchmarking, the baseline to compare times against.
+    pub baseline: Option<String>,
+
+    /// When benchmarking, how long to spend on each day.
+    pub bench_time: Option<Duration>,
+
+    /// The most memory solutions may have allocated at once.
+    pub mem_limit: Option<usize>,
+
+    /// The number of `-v` flags given.
+    pub verbosity: u8,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Mode {
+    /// Print each day's answers.
+    #[default]
+    Run,
+
+    /// Check answers against the recorded ones.
+    Verify,
+
+    /// Time each day, and its alternative implementations.
+    Bench,
+}
+
+impl Options {
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> anyhow::Result<Options> {
+        let mut options = Options::default();
+        let mut positional = vec![];
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" => {
+                    let path = args.next().context("--input needs a path")?;
+                    options.input = Source::from_arg(&path);
+                }
+                "--record" => options.record = true,
+                "--save-baseline" => {
+                    options.save_baseline =
+                        Some(args.next().context("--save-baseline needs a name")?);
+                }
+                "--baseline" => {
+                    options.baseline = Some(args.next().context("--baseline needs a name")?);
+                }
+                "--time" => {
+                    let secs = args.next().context("--time needs a number of seconds")?;
+                    let secs: f64 = secs
+                        .parse()
+                        .with_context(|| format!("bad number of seconds: {secs:?}"))?;
+                    options.bench_time = Some(
+                        Duration::try_from_secs_f64(secs)
+                            .with_context(|| format!("bad number of seconds: {secs}"))?,
+                    );
+                }
+                "--mem-limit" => {
+                    let size = args.next().context("--mem-limit needs a size")?;
+                    options.mem_limit = Some(alloc::parse_size(&size)?);
+                }
+                "--verbose" => options.verbosity = options.verbosity.saturating_add(1),
+                _ if arg.len() > 1
+                    && arg.starts_with('-')
+                    && arg[1..].bytes().all(|b| b == b'v') =>
+                {
+                    let count = u8::try_from(arg.len() - 1).unwrap_or(u8::MAX);
+                    options.verbosity = options.verbosity.saturating_add(count);
+                }
+                _ if arg.starts_with("--") => bail!("unrecognized option: {arg}"),
+                "verify" if positional.is_empty() => options.mode = Mode::Verify,
+                "bench" if positional.is_empty() => options.mode = Mode::Bench,
+                _ => positional.push(arg),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        if let Some(year) = positional.next() {
+            options.year = Some(
+                year.parse()
+                    .with_context(|| format!("bad year: {year:?}"))?,
+            );
+        }
+        if let Some(day) = positional.next() {
+            options.day = Some(day.parse().with_context(|| format!("bad day: {day:?}"))?);
+        }
+        if let Some(part) = positional.next() {
+            options.part = Some(part.parse()?);
+        }
+        if let Some(extra) = positional.next() {
+            bail!("unexpected argument: {extra:?}");
+        }
+        if options.record && options.mode != Mode::Verify {
+            bail!("--record only makes sense with 'verify'");
+        }
+        let bench_options = options.save_baseline.is_some()
+            || options.baseline.is_some()
+            || options.bench_time.is_some();
+        if bench_options && options.mode != Mode::Bench {
+            bail!("--save-baseline, --baseline, and --time only make sense with 'bench'");
+        }
+
+        Ok(options)
+    }
+
+    /// Return the days in `days` that these options select.
+    ///
+    /// Alternative implementations are only selected for benchmarking.
+    pub fn select<'d>(&self, days: &'d [Day]) -> Vec<&'d Day> {
+        days.iter()
+            .filter(|d| self.year.is_none_or(|year| d.year == year))
+            .filter(|d| self.day.is_none_or(|day| d.day == day))
+            .filter(|d| d.variant.is_none() || self.mode == Mode::Bench)
+            .collect()
+    }
+
+    /// Return the parts these options select.
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+/// Run the days selected by the process's command-line arguments.
+pub fn main(days: &[Day]) -> ExitCode {
+    match run(days, std::env::args().skip(1)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run<I: IntoIterator<Item = String>>(days: &[Day], args: I) -> anyhow::Result<()> {
+    let options = Options::from_args(args)?;
+    crate::trace::init(options.verbosity);
+    let puzzles = group_by_puzzle(options.select(days));
+    if puzzles.is_empty() {
+        bail!("no solutions match the selection");
+    }
+    if puzzles.len() > 1 && options.input != Source::Default {
+        bail!(
+            "{} solutions match the selection, but there is only one input; \
+             please choose a single year and day",
+            puzzles.len()
+        );
+    }
+
+    if options.mode == Mode::Verify && options.input != Source::Default {
+        bail!("answers are recorded for the usual inputs, so 'verify' can't use --input");
+    }
+    if options.mem_limit.is_some() {
+        if !alloc::installed() {
+            bail!("--mem-limit needs the binary to use aoc_utils::alloc::Counting");
+        }
+        alloc::set_limit(options.mem_limit);
+    }
+
+    let inputs = Inputs::default();
+    let store = input_store()?;
+    let mut verifier = Verifier::new(inputs.clone(), options.record);
+    let mut bencher = Bencher::new(&options)?;
+    let mut failures = 0;
+    for puzzle in &puzzles {
+        let day = puzzle[0];
+        let result = load_input(day, &inputs, store.as_ref(), &options).and_then(|input| {
+            match options.mode {
+                Mode::Run => print_run(day, &run_day(day, &input, &options)?),
+                Mode::Verify => verifier.check(day, &run_day(day, &input, &options)?)?,
+                Mode::Bench => bencher.bench(puzzle, &input, &options.parts())?,
+            }
+            Ok(())
+        });
+        match result {
+            Ok(()) => {}
+            Err(err) if puzzles.len() == 1 => return Err(err),
+            Err(err) => match err.downcast_ref::<input::Error>() {
+                Some(input::Error::Missing { path, .. }) => {
+                    eprintln!("{day}: skipped, no input at {}", path.display());
+                }
+                _ => {
+                    eprintln!("error: {err:#}");
+                    failures += 1;
+                }
+            },
+        }
+    }
+
+    match options.mode {
+        Mode::Run => {}
+        Mode::Verify => verifier.finish()?,
+        Mode::Bench => bencher.finish()?,
+    }
+    if failures > 0 {
+        bail!("{failures} of {} days failed", puzzles.len());
+    }
+    Ok(())
+}
+
+/// Group `days` by the puzzle they solve, keeping them in order.
+fn group_by_puzzle(days: Vec<&Day>) -> Vec<Vec<&Day>> {
+    let mut puzzles: Vec<Vec<&Day>> = vec![];
+    for day in days {
+        match puzzles
+            .iter_mut()
+            .find(|p| (p[0].year, p[0].day) == (day.year, day.day))
+        {
+            Some(puzzle) => puzzle.push(day),
+            None => puzzles.push(vec![day]),
+        }
+    }
+    puzzles
+}
+
+fn load_input(
+    day: &Day,
+    inputs: &Inputs,
+    store: Option<&InputStore>,
+    options: &Options,
+) -> anyhow::Result<String> {
+    match (inputs.load(day.year, day.day, &options.input), store) {
+        (Err(err @ input::Error::Missing { .. }), Some(store)) => {
+            if !store.is_cached(day.year, day.day) {
+                if store.fetcher().is_none() {
+                    return Err(err.into());
+                }
+                eprintln!("{day}: fetching input");
+            }
+            store.get(day.year, day.day)
+        }
+        (result, _) => Ok(result?),
+    }
+}
+
+fn run_day(day: &Day, input: &str, options: &Options) -> anyhow::Result<Run> {
+    day.run(input, &options.parts())
+        .with_context(|| anyhow!("{day}: couldn't parse input"))
+}
+
+/// The per-user input cache, and a way to fetch inputs it lacks, if we have
+/// one.
+type InputStore = Store<Option<Box<dyn Fetcher>>>;
+
+/// Return the per-user input cache, if there is one.
+fn input_store() -> anyhow::Result<Option<InputStore>> {
+    let fetcher = http_fetcher();
+    match crate::fetch::default_cache_dir() {
+        Ok(dir) => Ok(Some(Store::new(dir, fetcher))),
+        // Without a place to put them, we can't fetch inputs, but we can
+        // still run from the inputs directory.
+        Err(_) if fetcher.is_none() => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Return a fetcher that downloads inputs, if we're able to.
+#[cfg(feature = "http")]
+fn http_fetcher() -> Option<Box<dyn Fetcher>> {
+    let fetcher = crate::fetch::HttpFetcher::from_env()?;
+    Some(Box::new(fetcher))
+}
+
+#[cfg(not(feature = "http"))]
+fn http_fetcher() -> Option<Box<dyn Fetcher>> {
+    None
+}
+
+fn print_run(day: &Day, run: &Run) {
+    println!(
+        "{day}: parsed in {}",
+        format_costs(run.parse, run.parse_usage)
+    );
+    for answer in &run.answers {
+        println!(
+            "{day} part {}: {}  ({})",
+            answer.part,
+            answer.value,
+            format_costs(answer.elapsed, answer.usage)
+        );
+    }
+}
+
+/// Describe the time and memory used by some phase of a solution.
+fn format_costs(elapsed: Duration, usage: Option<Usage>) -> String {
+    let mut costs = format_duration(elapsed);
+    if let Some(usage) = usage {
+        costs += &format!(
+            ", peak {}, {} allocations totaling {}",
+            Bytes(usage.peak),
+            usage.allocations,
+            Bytes(usage.bytes)
+        );
+    }
+    costs
+}
+
+/// Checks answers against each year's recorded answers.
+struct Verifier {
+    inputs: Inputs,
+    record: bool,
+    years: BTreeMap<u16, Answers>,
+    passed: usize,
+    failed: usize,
+    new: usize,
+}
+
+impl Verifier {
+    fn new(inputs: Inputs, record: bool) -> Self {
+        Verifier {
+            inputs,
+            record,
+            years: BTreeMap::new(),
+            passed: 0,
+            failed: 0,
+            new: 0,
+        }
+    }
+
+    fn check(&mut self, day: &Day, run: &Run) -> anyhow::Result<()> {
+        let answers = match self.years.entry(day.year) {
+            std::collections::btree_map::Entry::Occupied(entry) => entry.into_mut(),
+            std::collections::btree_map::Entry::Vacant(entry) => {
+                entry.insert(Answers::load(self.inputs.answers_path(day.year))?)
+            }
+        };
+
+        println!(
+            "{day}: parsed in {}",
+            format_costs(run.parse, run.parse_usage)
+        );
+        for answer in &run.answers {
+            let status = match answers.check(day.day, answer.part, &answer.value) {
+                Verdict::Pass => {
+                    self.passed += 1;
+                    "ok".to_string()
+                }
+                Verdict::Fail { expected } => {
+                    self.failed += 1;
+                    format!("FAILED: got {}, expected {expected}", answer.value)
+                }
+                Verdict::New if self.record => {
+                    self.new += 1;
+                    answers.record(day.day, answer.part, &answer.value);
+                    format!("new, recorded {}", answer.value)
+                }
+                Verdict::New => {
+                    self.new += 1;
+                    format!("new: {}", answer.value)
+                }
+            };
+            println!(
+                "{day} part {}: {status}  ({})",
+                answer.part,
+                format_costs(answer.elapsed, answer.usage)
+            );
+        }
+        Ok(())
+    }
+
+    /// Save any recorded answers, print a summary, and report failure if
+    /// any answers didn't match.
+    fn finish(mut self) -> anyhow::Result<()> {
+        for answers in self.years.values_mut() {
+            answers.save()?;
+        }
+        println!(
+            "{} passed, {} failed, {} new",
+            self.passed, self.failed, self.new
+        );
+        if self.failed > 0 {
+            bail!("{} answers differ from the recorded ones", self.failed);
+        }
+        Ok(())
+    }
+}
+
+/// Benchmarks days, and compares them with a saved baseline.
+struct Bencher {
+    config: bench::Config,
+    dir: PathBuf,
+    baseline: Option<(String, Baseline)>,
+    save: Option<(String, Baseline)>,
+}
+
+impl Bencher {
+    fn new(options: &Options) -> anyhow::Result<Self> {
+        let mut config = bench::Config::default();
+        if let Some(time) = options.bench_time {
+            config.time = time;
+        }
+        let dir = bench::default_dir();
+        let baseline = match &options.baseline {
+            Some(name) => Some((name.clone(), Baseline::load(&dir, name)?)),
+            None => None,
+        };
+        let save = match &options.save_baseline {
+            Some(name) => Some((name.clone(), Baseline::load_or_default(&dir, name)?)),
+            None => None,
+        };
+        Ok(Bencher {
+            config,
+            dir,
+            baseline,
+            save,
+        })
+    }
+
+    /// Benchmark `days`, which all solve the same puzzle, on `input`.
+    fn bench(&mut self, days: &[&Day], input: &str, parts: &[Part]) -> anyhow::Result<()> {
+        let mut measurements = vec![];
+        for day in days {
+            let measurement = bench::measure(day, input, parts, &self.config)
+                .with_context(|| anyhow!("{day}: couldn't parse input"))?;
+            self.print(&measurement);
+            if let Some((_, save)) = &mut self.save {
+                save.record(&measurement);
+            }
+            measurements.push(measurement);
+        }
+
+        let disagreements = bench::disagreements(&measurements);
+        if let Some(first) = disagreements.first() {
+            bail!("{}: {first}", days[0]);
+        }
+        if days.len() > 1 {
+            println!("{}: all {} implementations agree", days[0], days.len());
+        }
+        Ok(())
+    }
+
+    fn print(&self, measurement: &Measurement) {
+        println!("{}: {} samples", measurement.day, measurement.parse.samples);
+        self.print_phase(measurement, None, &measurement.parse);
+        for (part, summary) in &measurement.parts {
+            self.print_phase(measurement, Some(*part), summary);
+        }
+    }
+
+    fn print_phase(&self, measurement: &Measurement, phase: Option<Part>, summary: &Summary) {
+        let label = match phase {
+            None => "parse ".to_string(),
+            Some(part) => format!("part {part}"),
+        };
+        let mut line = format!(
+            "  {label}  median {:>9}  mean {:>9} ± {:>9}  range {} .. {}",
+            format_duration(summary.median),
+            format_duration(summary.mean),
+            format_duration(summary.stddev),
+            format_duration(summary.min),
+            format_duration(summary.max),
+        );
+        if let Some((name, baseline)) = &self.baseline {
+            match baseline.median(measurement, phase) {
+                Some(old) => {
+                    let change = bench::percent_change(old, summary.median);
+                    line += &format!("  ({change:+.1}% vs {name})");
+                }
+                None => line += &format!("  (not in {name})"),
+            }
+        }
+        println!("{line}");
+    }
+
+    fn finish(self) -> anyhow::Result<()> {
+        if let Some((name, save)) = &self.save {
+            save.save(&self.dir, name)?;
+            println!("saved baseline {name:?} in {}", self.dir.display());
+        }
+        Ok(())
+    }
+}
+
+/// Format `duration` with a unit suited to its size.
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{secs:.2}s")
+    } else if secs >= 1e-3 {
+        format!("{:.2}ms", secs * 1e3)
+    } else {
+        format!("{:.2}µs", secs * 1e6)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::test::{Arithmetic, Christmas};
+
+    fn args(args: &str) -> anyhow::Result<Options> {
+        Options::from_args(args.split_whitespace().map(str::to_string))
+    }
+
+    #[test]
+    fn test_options() {
+        assert_eq!(args("").unwrap(), Options::default());
+        assert_eq!(
+            args("2021 16 2 --input foo.txt").unwrap(),
+            Options {
+                year: Some(2021),
+                day: Some(16),
+                part: Some(Part::Two),
+                input: Source::File("foo.txt".into()),
+                ..Options::default()
+            }
+        );
+        let options = args("verify --record 2021").unwrap();
+        assert_eq!(options.mode, Mode::Verify);
+        assert!(options.record);
+        assert_eq!(options.year, Some(2021));
+        assert!(args("2021 verify").is_err());
+        assert!(args("--record").is_err());
+
+        let options = args("bench 2021 18 --baseline main --time 0.5").unwrap();
+        assert_eq!(options.mode, Mode::Bench);
+        assert_eq!(options.baseline.as_deref(), Some("main"));
+        assert_eq!(options.bench_time, Some(Duration::from_millis(500)));
+        assert!(args("bench --time -1").is_err());
+        assert!(args("bench --time").is_err());
+        assert!(args("--save-baseline main").is_err());
+        assert_eq!(
+            args("2025 10 --mem-limit 512M").unwrap().mem_limit,
+            Some(512 << 20)
+        );
+        assert!(args("--mem-limit lots").is_err());
+        assert_eq!(args("--input -").unwrap().input, Source::Stdin);
+        assert!(args("2021 sixteen").is_err());
+        assert!(args("2021 16 3").is_err());
+        assert!(args("2021 16 1 extra").is_err());
+        assert!(args("--input").is_err());
+        assert!(args("--loud").is_err());
+        assert_eq!(args("-v 2021 --verbose -vv").unwrap().verbosity, 4);
+        assert!(args("-vx").is_err());
+    }
+
+    #[test]
+    fn test_select() {
+        let days = [
+            Day::new::<Arithmetic>(2018, 1),
+            Day::new::<Arithmetic>(2021, 1),
+            Day::new::<Christmas>(2021, 25).without_part2(),
+            Day::new::<Arithmetic>(2021, 1).as_variant("fast"),
+        ];
+        let selected = |a: &str| -> Vec<String> {
+            args(a)
+                .unwrap()
+                .select(&days)
+                .iter()
+                .map(|d| d.to_string())
+                .collect()
+        };
+        assert_eq!(selected("").len(), 3);
+        assert_eq!(selected("2021"), vec!["2021 day 1", "2021 day 25"]);
+        assert_eq!(selected("2021 25"), vec!["2021 day 25"]);
+        assert!(selected("2025").is_empty());
+        assert_eq!(
+            selected("bench 2021"),
+            vec!["2021 day 1", "2021 day 25", "2021 day 1 (fast)"]
+        );
+
+        let options = args("bench").unwrap();
+        let puzzles: Vec<Vec<String>> = group_by_puzzle(options.select(&days))
+            .iter()
+            .map(|p| p.iter().map(|d| d.to_string()).collect())
+            .collect();
+        assert_eq!(
+            puzzles,
+            vec![
+                vec!["2018 day 1"],
+                vec!["2021 day 1", "2021 day 1 (fast)"],
+                vec!["2021 day 25"]
+            ]
+        );
+
+        assert!(run(&days, ["2019".to_string()]).is_err());
+        let err = run(&days, ["2021", "--input", "-"].map(String::from)).unwrap_err();
+        assert!(err.to_string().contains("only one input"));
+    }
+
+    #[test]
+    fn test_load_input() {
+        let dir = std::env::temp_dir().join(format!("aoc-load-input-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let cache = dir.join("cache");
+        std::fs::create_dir_all(cache.join("2021")).unwrap();
+        std::fs::write(cache.join("2021/day01.txt"), "1\n2\n").unwrap();
+
+        // With no fetcher, inputs are still read from the cache.
+        let inputs = Inputs::new(dir.join("inputs"));
+        let store: InputStore = Store::new(&cache, None);
+        let options = Options::default();
+        let day = Day::new::<Arithmetic>(2021, 1);
+        let input = load_input(&day, &inputs, Some(&store), &options).unwrap();
+        assert_eq!(input, "1\n2\n");
+
+        // Inputs that are nowhere to be found are reported as missing from
+        // the inputs directory, so that `run` can skip them.
+        let day = Day::new::<Arithmetic>(2021, 2);
+        let err = load_input(&day, &inputs, Some(&store), &options).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<input::Error>(),
+            Some(input::Error::Missing { day: 2, .. })
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_millis(2500)), "2.50s");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_nanos(1500)), "1.50µs");
+        let usage = Usage {
+            peak: 3 << 20,
+            allocations: 12,
+            bytes: 5 << 20,
+        };
+        assert_eq!(
+            format_costs(Duration::from_millis(2500), Some(usage)),
+            "2.50s, peak 3.00 MiB, 12 allocations totaling 5.00 MiB"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+//! Tests generated from sample inputs and their expected answers.
+//!
+//! A sample input can carry the answers it should produce in a sidecar file,
+//! named by appending `.expected` to the sample's name. For example,
+//! `src/sample/day11` might come with `src/sample/day11.expected`:
+//!
+//! ```toml
+//! part1 = 1656
+//! part2 = 195
+//! ```
+//!
+//! A sample's file name must start with `day` and the puzzle's day number,
+//! like `day11`, `day12.small`, or `day-02a.test`; that picks the solution
+//! to check it against. Parts with no expected answer aren't checked.
+//!
+//! A crate whose root has a `solutions()` function returning its [`Day`]s
+//! can get one test per sample per part by calling `write_tests` from the
+//! small `aoc-samples` crate in its build script, with `aoc-samples` as a
+//! build dependency:
+//!
+//! ```ignore
+//! fn main() {
+//!     aoc_samples::write_tests("src/sample").unwrap();
+//! }
+//! ```
+//!
+//! and then using [`sample_tests!`](crate::sample_tests) to include them:
+//!
+//! ```ignore
+//! #[cfg(test)]
+//! mod samples {
+//!     aoc_utils::sample_tests!();
+//! }
+//! ```
+
+use crate::answers::parse_parts;
+use crate::solution::{Day, Part};
+use anyhow::{Context, anyhow};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+pub use aoc_samples::{day_of, expected_path};
+
+/// A sample input, with the answers it should produce.
+#[derive(Debug)]
+pub struct Sample {
+    pub path: PathBuf,
+    pub day: u8,
+    pub input: String,
+    pub expected: BTreeMap<Part, String>,
+}
+
+impl Sample {
+    pub fn load<P: Into<PathBuf>>(path: P) -> anyhow::Result<Sample> {
+        let path = path.into();
+        let day = day_of(&path)
+            .ok_or_else(|| anyhow!("can't tell which day {} is for", path.display()))?;
+        let input = std::fs::read_to_string(&path)
+            .with_context(|| format!("couldn't read sample {}", path.display()))?;
+        let expected_path = expected_path(&path);
+        let expected = std::fs::read_to_string(&expected_path)
+            .with_context(|| format!("couldn't read {}", expected_path.display()))?;
+        let expected = parse_parts(expected.parse()?)
+            .with_context(|| format!("bad expected answers in {}", expected_path.display()))?;
+        Ok(Sample {
+            path,
+            day,
+            input,
+            expected,
+        })
+    }
+}
+
+/// Check that `days` gives the expected answer to `part` for the sample at
+/// `path`, and panic if it doesn't.
+///
+/// If `days` has several implementations of the sample's puzzle, check all
+/// of those that solve `part`. This is what the tests generated by
+/// `aoc_samples::write_tests` call.
+pub fn check(days: &[Day], path: &str, part: Part) {
+    let sample = Sample::load(path).unwrap_or_else(|err| panic!("{err:#}"));
+    let expected = sample
+        .expected
+        .get(&part)
+        .unwrap_or_else(|| panic!("{path}: no expected answer for part {part}"));
+    let candidates: Vec<&Day> = days
+        .iter()
+        .filter(|day| day.day == sample.day && day.parts().contains(&part))
+        .collect();
+    if candidates.is_empty() {
+        panic!(
+            "{path}: no solution registered for day {} part {part}",
+            sample.day
+        );
+    }
+    for day in candidates {
+        let run = day
+            .run(&sample.input, &[part])
+            .unwrap_or_else(|err| panic!("{path}: {day} couldn't parse sample: {err:#}"));
+        assert_eq!(
+            run.answers[0].value, *expected,
+            "{path}: wrong answer to {day} part {part}"
+        );
+    }
+}
+
+/// Include the tests that `aoc_samples::write_tests` generated in the build
+/// script.
+#[macro_export]
+macro_rules! sample_tests {
+    () => {
+        include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::test::Arithmetic;
+
+    #[test]
+    fn test_check() {
+        let dir = std::env::temp_dir().join(format!("aoc-samples-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("day01"), "2 3 7\n").unwrap();
+        std::fs::write(dir.join("day01.expected"), "part1 = 12\npart2 = \"42\"\n").unwrap();
+
+        let days = [
+            Day::new::<Arithmetic>(2000, 1),
+            Day::new::<Arithmetic>(2000, 1).as_variant("again"),
+        ];
+        let path = dir.join("day01");
+        check(&days, path.to_str().unwrap(), Part::One);
+        check(&days, path.to_str().unwrap(), Part::Two);
+
+        std::fs::write(dir.join("day01.expected"), "part1 = 13\n").unwrap();
+        let result = std::panic::catch_unwind(|| check(&days, path.to_str().unwrap(), Part::One));
+        assert!(result.is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,302 @@
+//! A common interface to puzzle solutions.
+//!
+//! Each year's solutions were written against a different harness. Implementing
+//! [`Solution`] for a puzzle and registering it as a [`Day`] lets the
+//! [`runner`](crate::runner) run any of them the same way.
+
+use crate::alloc::{self, Phase, Usage};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// A solution to both parts of a puzzle.
+///
+/// Both parts share the result of parsing the input once, so parsing time can
+/// be reported separately.
+pub trait Solution {
+    /// The parsed puzzle input.
+    type Input;
+    type Answer1: fmt::Display;
+    type Answer2: fmt::Display;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    /// Return the answer to part two.
+    ///
+    /// Day 25 puzzles have no part two. Solutions for those can set
+    /// `Answer2` to [`Infallible`](std::convert::Infallible), mark the part
+    /// unreachable, and register with [`Day::without_part2`].
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> anyhow::Result<Part> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => anyhow::bail!("puzzle part should be 1 or 2, not {s:?}"),
+        }
+    }
+}
+
+/// A registered solution for a particular puzzle.
+///
+/// This erases the [`Solution`]'s types, so days with different inputs and
+/// answers can be kept in one list.
+#[derive(Clone)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+
+    /// If this is an alternative implementation of a puzzle that already has
+    /// a solution, this is its name.
+    pub variant: Option<&'static str>,
+    parts: &'static [Part],
+    solve: fn(&Day, &str, &[Part]) -> anyhow::Result<Run>,
+}
+
+/// The results of running a [`Day`] on some input.
+#[derive(Debug)]
+pub struct Run {
+    /// The time taken to parse the input.
+    pub parse: Duration,
+
+    /// The memory used to parse the input, if the program's global allocator
+    /// is [`alloc::Counting`].
+    pub parse_usage: Option<Usage>,
+    pub answers: Vec<Answer>,
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub elapsed: Duration,
+    pub usage: Option<Usage>,
+}
+
+impl Day {
+    pub fn new<S: Solution>(year: u16, day: u8) -> Day {
+        Day {
+            year,
+            day,
+            variant: None,
+            parts: &Part::ALL,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Mark this day as having no part two.
+    pub fn without_part2(self) -> Day {
+        Day {
+            parts: &[Part::One],
+            ..self
+        }
+    }
+
+    /// Mark this day as an alternative implementation named `name`.
+    ///
+    /// The runner only runs alternative implementations when benchmarking,
+    /// to compare them with the usual one.
+    pub fn as_variant(self, name: &'static str) -> Day {
+        Day {
+            variant: Some(name),
+            ..self
+        }
+    }
+
+    /// Return the parts this day has solutions for.
+    pub fn parts(&self) -> &'static [Part] {
+        self.parts
+    }
+
+    /// Parse `input`, and then solve each of `parts` that this day has.
+    pub fn run(&self, input: &str, parts: &[Part]) -> anyhow::Result<Run> {
+        let parts: Vec<Part> = parts
+            .iter()
+            .copied()
+            .filter(|part| self.parts.contains(part))
+            .collect();
+        (self.solve)(self, input, &parts)
+    }
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Day")
+            .field("year", &self.year)
+            .field("day", &self.day)
+            .field("variant", &self.variant)
+            .field("parts", &self.parts)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)?;
+        if let Some(variant) = self.variant {
+            write!(f, " ({variant})")?;
+        }
+        Ok(())
+    }
+}
+
+fn solve<S: Solution>(day: &Day, input: &str, parts: &[Part]) -> anyhow::Result<Run> {
+    // Open the tracing spans outside the measured closures, so that the
+    // subscriber's own allocations aren't charged to the solution.
+    let span = tracing::info_span!(
+        "day",
+        year = day.year,
+        day = day.day,
+        variant = tracing::field::Empty
+    );
+    if let Some(variant) = day.variant {
+        span.record("variant", variant);
+    }
+    let _day = span.entered();
+
+    let ((input, parse), parse_usage) = tracing::info_span!("parse").in_scope(|| {
+        alloc::measure(day, Phase::Parse, || {
+            let start = Instant::now();
+            (S::parse(input), start.elapsed())
+        })
+    });
+    let input = input?;
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let span = tracing::info_span!("part", %part);
+            let ((value, elapsed), usage) = span.in_scope(|| {
+                alloc::measure(day, Phase::Part(part), || {
+                    let start = Instant::now();
+                    let value = match part {
+                        Part::One => S::part1(&input).to_string(),
+                        Part::Two => S::part2(&input).to_string(),
+                    };
+                    (value, start.elapsed())
+                })
+            });
+            Answer {
+                part,
+                value,
+                elapsed,
+                usage,
+            }
+        })
+        .collect();
+
+    Ok(Run {
+        parse,
+        parse_usage,
+        answers,
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use std::convert::Infallible;
+
+    /// Sum and multiply the numbers on each line.
+    pub(crate) struct Arithmetic;
+
+    impl Solution for Arithmetic {
+        type Input = Vec<i64>;
+        type Answer1 = i64;
+        type Answer2 = i64;
+
+        fn parse(input: &str) -> anyhow::Result<Vec<i64>> {
+            Ok(input
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Vec<i64>) -> i64 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Vec<i64>) -> i64 {
+            input.iter().product()
+        }
+    }
+
+    pub(crate) struct Christmas;
+
+    impl Solution for Christmas {
+        type Input = usize;
+        type Answer1 = usize;
+        type Answer2 = Infallible;
+
+        fn parse(input: &str) -> anyhow::Result<usize> {
+            Ok(input.len())
+        }
+
+        fn part1(input: &usize) -> usize {
+            *input
+        }
+
+        fn part2(_input: &usize) -> Infallible {
+            unreachable!("day 25 has no part two")
+        }
+    }
+
+    fn values(run: &Run) -> Vec<(Part, &str)> {
+        run.answers
+            .iter()
+            .map(|answer| (answer.part, answer.value.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_run() {
+        let day = Day::new::<Arithmetic>(2000, 1);
+        assert_eq!(day.to_string(), "2000 day 1");
+        let run = day.run("2 3\n7\n", &Part::ALL).unwrap();
+        assert_eq!(values(&run), vec![(Part::One, "12"), (Part::Two, "42")]);
+        let run = day.run("2 3\n7\n", &[Part::Two]).unwrap();
+        assert_eq!(values(&run), vec![(Part::Two, "42")]);
+
+        let err = day.run("2 three", &Part::ALL).unwrap_err();
+        assert_eq!(err.to_string(), "invalid digit found in string");
+
+        let day = Day::new::<Arithmetic>(2000, 1).as_variant("fast");
+        assert_eq!(day.to_string(), "2000 day 1 (fast)");
+
+        let day = Day::new::<Christmas>(2000, 25).without_part2();
+        assert_eq!(day.parts(), &[Part::One]);
+        let run = day.run("snow", &Part::ALL).unwrap();
+        assert_eq!(values(&run), vec![(Part::One, "4")]);
+    }
+
+    #[test]
+    fn test_part() {
+        assert_eq!("1".parse::<Part>().unwrap(), Part::One);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+}
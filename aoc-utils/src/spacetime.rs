@@ -0,0 +1,167 @@
+//! Space-time diagrams of one-dimensional cellular automata.
+//!
+//! A [`SpaceTime`] collects the successive generations of a row of cells,
+//! each generation given as the position of its first cell and the cells'
+//! states. The diagram stacks the generations one per line, aligned on their
+//! absolute positions, so patterns that repeat show up as stripes, and
+//! patterns that drift show up as diagonals:
+//!
+//! ```
+//! use aoc_utils::spacetime::SpaceTime;
+//!
+//! let mut diagram = SpaceTime::new();
+//! diagram.push(0, &[true, true, false, true]);
+//! diagram.push(1, &[true, true, false, true]);
+//! diagram.push(2, &[true, true, false, true]);
+//! assert_eq!(diagram.to_string(), "##.#..\n.##.#.\n..##.#\n");
+//! ```
+//!
+//! Solutions that record a diagram save it to the file named by the
+//! `AOC_SPACETIME` environment variable, if it's set; see [`requested`].
+
+use anyhow::bail;
+use std::fmt;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Return the file named by the `AOC_SPACETIME` environment variable, if any,
+/// to which solutions should write their space-time diagrams.
+pub fn requested() -> Option<PathBuf> {
+    std::env::var_os("AOC_SPACETIME")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// The history of a one-dimensional automaton.
+#[derive(Clone, Debug, Default)]
+pub struct SpaceTime {
+    /// Each generation, as the position of its first cell and the cells'
+    /// states.
+    rows: Vec<(i64, Vec<bool>)>,
+}
+
+impl SpaceTime {
+    pub fn new() -> SpaceTime {
+        SpaceTime::default()
+    }
+
+    /// Add a generation whose first cell is at `origin`.
+    pub fn push(&mut self, origin: i64, cells: &[bool]) {
+        self.rows.push((origin, cells.to_vec()));
+    }
+
+    /// Return the number of generations recorded.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Return the range of positions holding live cells in any generation.
+    pub fn bounds(&self) -> Range<i64> {
+        let live = self.rows.iter().flat_map(|(origin, cells)| {
+            let first = cells.iter().position(|&cell| cell)?;
+            let last = cells.iter().rposition(|&cell| cell)?;
+            Some((origin + first as i64, origin + last as i64 + 1))
+        });
+        live.reduce(|a, b| (a.0.min(b.0), a.1.max(b.1)))
+            .map_or(0..0, |(start, end)| start..end)
+    }
+
+    /// Draw the diagram on a [`Raster`], with live cells in `color` on a
+    /// black background, and no more than `max_size` pixels on a side.
+    ///
+    /// [`Raster`]: crate::render::Raster
+    #[cfg(feature = "render")]
+    pub fn to_raster(
+        &self,
+        max_size: usize,
+        color: crate::render::Rgb<u8>,
+    ) -> crate::render::Raster {
+        let rows = 0..self.rows.len() as i64;
+        let mut raster = crate::render::Raster::fit(rows, self.bounds(), max_size);
+        for (generation, (origin, cells)) in self.rows.iter().enumerate() {
+            for (i, &cell) in cells.iter().enumerate() {
+                if cell {
+                    raster.paint((generation as i64, origin + i as i64), color);
+                }
+            }
+        }
+        raster
+    }
+
+    /// Write the diagram to `path`: as a PNG image if the name ends with
+    /// `.png`, and as text otherwise.
+    ///
+    /// Writing PNG files requires the `render` feature.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("png"))
+        {
+            #[cfg(feature = "render")]
+            return self
+                .to_raster(2000, crate::render::Rgb([80, 220, 80]))
+                .save(path);
+            #[cfg(not(feature = "render"))]
+            bail!("writing PNG files requires the `render` feature");
+        }
+        if self.rows.is_empty() {
+            bail!("no generations recorded");
+        }
+        std::fs::write(path, self.to_string())?;
+        Ok(())
+    }
+}
+
+/// Show live cells as `#` and dead cells as `.`, covering only the range of
+/// positions that are ever alive.
+impl fmt::Display for SpaceTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bounds = self.bounds();
+        for (origin, cells) in &self.rows {
+            for position in bounds.clone() {
+                let live = usize::try_from(position - origin)
+                    .ok()
+                    .and_then(|i| cells.get(i))
+                    .is_some_and(|&cell| cell);
+                f.write_str(if live { "#" } else { "." })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut diagram = SpaceTime::new();
+        assert_eq!(diagram.bounds(), 0..0);
+        diagram.push(-3, &[false, true, false]);
+        diagram.push(5, &[false, false]);
+        diagram.push(0, &[false, false, true, false]);
+        assert_eq!(diagram.bounds(), -2..3);
+        assert_eq!(diagram.len(), 3);
+        assert_eq!(diagram.to_string(), "#....\n.....\n....#\n");
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn test_raster() {
+        let mut diagram = SpaceTime::new();
+        for generation in 0..10 {
+            diagram.push(generation, &[true, true]);
+        }
+        let raster = diagram.to_raster(100, crate::render::Rgb([255, 255, 255]));
+        assert_eq!(raster.size(), (10, 11));
+        let image = raster.into_image();
+        assert_eq!(image.get_pixel(3, 3).0, [255, 255, 255]);
+        assert_eq!(image.get_pixel(2, 3).0, [0, 0, 0]);
+    }
+}
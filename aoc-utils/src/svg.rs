@@ -0,0 +1,351 @@
+//! Writing geometric diagrams as SVG files.
+//!
+//! An [`Svg`] collects polylines, polygons, rectangles and labeled points, in
+//! whatever coordinates the puzzle uses, and writes them as an SVG document
+//! whose `viewBox` just fits them all, with a little margin. The document is
+//! sized for the screen regardless of how large the coordinates are, and
+//! line widths, point sizes and label text are scaled to match, so a diagram
+//! of a shape a hundred thousand units across is as legible as one ten units
+//! across.
+//!
+//! As in SVG, `x` increases to the right, and `y` increases downwards.
+//!
+//! ```
+//! use aoc_utils::svg::{Style, Svg};
+//!
+//! let mut svg = Svg::new();
+//! svg.polygon([(7, 1), (11, 1), (11, 7), (9, 7), (9, 5), (2, 5), (2, 3), (7, 3)],
+//!             Style::stroke("black").and_fill("lightgray"));
+//! svg.rect((2, 3), (9, 5), Style::fill("green").opacity(0.5));
+//! svg.point((7, 1), Some("start"), Style::fill("red"));
+//! assert!(svg.to_string().contains(r#"viewBox="1.55 0.55 9.9 6.9""#));
+//! ```
+
+use num_traits::AsPrimitive;
+use std::fmt::{self, Write as _};
+use std::path::Path;
+
+/// How to draw a shape.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Style {
+    /// The color of the shape's outline, if it has one.
+    pub stroke: Option<String>,
+
+    /// The color of the shape's interior, if it has one.
+    pub fill: Option<String>,
+
+    /// The width of the outline, in pixels on the screen.
+    pub width: f64,
+
+    /// The opacity of the whole shape, from 0 to 1.
+    pub opacity: f64,
+}
+
+impl Style {
+    /// Return a style that outlines shapes in `color`, with no fill.
+    pub fn stroke(color: &str) -> Style {
+        Style {
+            stroke: Some(color.to_string()),
+            ..Style::default()
+        }
+    }
+
+    /// Return a style that fills shapes with `color`, with no outline.
+    pub fn fill(color: &str) -> Style {
+        Style {
+            fill: Some(color.to_string()),
+            ..Style::default()
+        }
+    }
+
+    /// Fill shapes with `color` as well.
+    pub fn and_fill(mut self, color: &str) -> Style {
+        self.fill = Some(color.to_string());
+        self
+    }
+
+    /// Set the outline width, in pixels.
+    pub fn width(mut self, width: f64) -> Style {
+        self.width = width;
+        self
+    }
+
+    /// Set the opacity, from 0 to 1.
+    pub fn opacity(mut self, opacity: f64) -> Style {
+        self.opacity = opacity;
+        self
+    }
+}
+
+impl Default for Style {
+    fn default() -> Style {
+        Style {
+            stroke: None,
+            fill: None,
+            width: 1.0,
+            opacity: 1.0,
+        }
+    }
+}
+
+/// A shape in a diagram.
+#[derive(Clone, Debug)]
+enum Shape {
+    Polyline(Vec<(f64, f64)>),
+    Polygon(Vec<(f64, f64)>),
+    Rect {
+        min: (f64, f64),
+        max: (f64, f64),
+    },
+    Point {
+        at: (f64, f64),
+        label: Option<String>,
+    },
+}
+
+/// A diagram under construction.
+#[derive(Clone, Debug, Default)]
+pub struct Svg {
+    shapes: Vec<(Shape, Style)>,
+}
+
+/// The longer side of the SVG document, in pixels.
+const SIZE: f64 = 800.0;
+
+impl Svg {
+    pub fn new() -> Svg {
+        Svg::default()
+    }
+
+    /// Draw line segments connecting `points`.
+    pub fn polyline<I, T>(&mut self, points: I, style: Style) -> &mut Self
+    where
+        I: IntoIterator<Item = (T, T)>,
+        T: AsPrimitive<f64>,
+    {
+        self.add(Shape::Polyline(coords(points)), style)
+    }
+
+    /// Draw the closed polygon whose vertices are `points`.
+    pub fn polygon<I, T>(&mut self, points: I, style: Style) -> &mut Self
+    where
+        I: IntoIterator<Item = (T, T)>,
+        T: AsPrimitive<f64>,
+    {
+        self.add(Shape::Polygon(coords(points)), style)
+    }
+
+    /// Draw the rectangle with opposite corners at `a` and `b`.
+    pub fn rect<T: AsPrimitive<f64>>(&mut self, a: (T, T), b: (T, T), style: Style) -> &mut Self {
+        let (a, b) = ((a.0.as_(), a.1.as_()), (b.0.as_(), b.1.as_()));
+        let min = (a.0.min(b.0), a.1.min(b.1));
+        let max = (a.0.max(b.0), a.1.max(b.1));
+        self.add(Shape::Rect { min, max }, style)
+    }
+
+    /// Draw a dot at `at`, with an optional label beside it.
+    pub fn point<T: AsPrimitive<f64>>(
+        &mut self,
+        at: (T, T),
+        label: Option<&str>,
+        style: Style,
+    ) -> &mut Self {
+        let at = (at.0.as_(), at.1.as_());
+        let label = label.map(str::to_string);
+        self.add(Shape::Point { at, label }, style)
+    }
+
+    fn add(&mut self, shape: Shape, style: Style) -> &mut Self {
+        self.shapes.push((shape, style));
+        self
+    }
+
+    /// Return the smallest rectangle containing all our shapes, as its
+    /// top left and bottom right corners.
+    fn bounds(&self) -> Option<((f64, f64), (f64, f64))> {
+        let mut points = self.shapes.iter().flat_map(|(shape, _)| match shape {
+            Shape::Polyline(points) | Shape::Polygon(points) => points.clone(),
+            Shape::Rect { min, max } => vec![*min, *max],
+            Shape::Point { at, .. } => vec![*at],
+        });
+        let first = points.next()?;
+        Some(points.fold((first, first), |(min, max), (x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        }))
+    }
+
+    /// Write the diagram to `path`.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.to_string())?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min, max) = self.bounds().unwrap_or(((0.0, 0.0), (1.0, 1.0)));
+        let longest = (max.0 - min.0).max(max.1 - min.1).max(1.0);
+        let margin = longest * 0.05;
+        let view = (
+            tidy(min.0 - margin),
+            tidy(min.1 - margin),
+            tidy(max.0 - min.0 + 2.0 * margin),
+            tidy(max.1 - min.1 + 2.0 * margin),
+        );
+
+        // Size the document to fit on the screen, and figure out how large a
+        // pixel is in our coordinates.
+        let pixel = view.2.max(view.3) / SIZE;
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+            (view.2 / pixel).round(),
+            (view.3 / pixel).round(),
+            view.0,
+            view.1,
+            view.2,
+            view.3
+        )?;
+
+        for (shape, style) in &self.shapes {
+            let style = attributes(style);
+            match shape {
+                Shape::Polyline(points) => {
+                    writeln!(f, r#"  <polyline points="{}"{style}/>"#, point_list(points))?
+                }
+                Shape::Polygon(points) => {
+                    writeln!(f, r#"  <polygon points="{}"{style}/>"#, point_list(points))?
+                }
+                Shape::Rect { min, max } => writeln!(
+                    f,
+                    r#"  <rect x="{}" y="{}" width="{}" height="{}"{style}/>"#,
+                    min.0,
+                    min.1,
+                    max.0 - min.0,
+                    max.1 - min.1
+                )?,
+                Shape::Point { at, label } => {
+                    let radius = tidy(3.0 * pixel);
+                    writeln!(
+                        f,
+                        r#"  <circle cx="{}" cy="{}" r="{radius}"{style}/>"#,
+                        at.0, at.1
+                    )?;
+                    if let Some(label) = label {
+                        writeln!(
+                            f,
+                            r#"  <text x="{}" y="{}" font-size="{}" font-family="sans-serif">{}</text>"#,
+                            tidy(at.0 + 2.0 * radius),
+                            tidy(at.1 + radius),
+                            tidy(12.0 * pixel),
+                            escape(label)
+                        )?;
+                    }
+                }
+            }
+        }
+
+        writeln!(f, "</svg>")
+    }
+}
+
+/// Round `n` to three decimal places, to keep computed sizes and
+/// positions readable.
+fn tidy(n: f64) -> f64 {
+    (n * 1000.0).round() / 1000.0
+}
+
+fn coords<I, T>(points: I) -> Vec<(f64, f64)>
+where
+    I: IntoIterator<Item = (T, T)>,
+    T: AsPrimitive<f64>,
+{
+    points
+        .into_iter()
+        .map(|(x, y)| (x.as_(), y.as_()))
+        .collect()
+}
+
+fn point_list(points: &[(f64, f64)]) -> String {
+    let mut list = String::new();
+    for (i, (x, y)) in points.iter().enumerate() {
+        let sep = if i == 0 { "" } else { " " };
+        write!(list, "{sep}{x},{y}").unwrap();
+    }
+    list
+}
+
+/// Return `style` as SVG attributes, with a leading space.
+fn attributes(style: &Style) -> String {
+    let mut attrs = String::new();
+    let fill = style.fill.as_deref().unwrap_or("none");
+    write!(attrs, r#" fill="{}""#, escape(fill)).unwrap();
+    if let Some(stroke) = &style.stroke {
+        // Keep the stroke width in screen pixels, however the
+        // coordinates are scaled.
+        write!(
+            attrs,
+            r#" stroke="{}" stroke-width="{}" vector-effect="non-scaling-stroke""#,
+            escape(stroke),
+            style.width
+        )
+        .unwrap();
+    }
+    if style.opacity != 1.0 {
+        write!(attrs, r#" opacity="{}""#, style.opacity).unwrap();
+    }
+    attrs
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_svg() {
+        let mut svg = Svg::new();
+        svg.polyline(
+            [(0, 0), (100, 0), (100, 50)],
+            Style::stroke("blue").width(2.0),
+        )
+        .rect((80.0, 40.0), (20.0, 10.0), Style::fill("gray").opacity(0.5))
+        .point((100u64, 50u64), Some("a < b & c"), Style::fill("red"));
+        let text = svg.to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines[0],
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="800" height="436" viewBox="-5 -5 110 60">"#
+        );
+        assert_eq!(
+            lines[1],
+            r#"  <polyline points="0,0 100,0 100,50" fill="none" stroke="blue" stroke-width="2" vector-effect="non-scaling-stroke"/>"#
+        );
+        assert_eq!(
+            lines[2],
+            r#"  <rect x="20" y="10" width="60" height="30" fill="gray" opacity="0.5"/>"#
+        );
+        assert!(lines[3].starts_with(r#"  <circle cx="100" cy="50" r="0.413" fill="red""#));
+        assert!(lines[4].ends_with(">a &lt; b &amp; c</text>"));
+        assert_eq!(lines[5], "</svg>");
+    }
+
+    #[test]
+    fn test_empty() {
+        let text = Svg::new().to_string();
+        assert!(text.contains(r#"viewBox="-0.05 -0.05 1.1 1.1""#));
+    }
+}
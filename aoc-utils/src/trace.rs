@@ -0,0 +1,73 @@
+//! Logging and tracing for solutions.
+//!
+//! Solutions report what they're doing with the [`tracing`] crate's macros,
+//! which this crate re-exports, or with the `log` crate's, whose records are
+//! passed along to the same place. Nothing is printed until [`init`]
+//! installs a subscriber; the [`runner`] does this itself, using the
+//! verbosity given by its `-v` flags.
+//!
+//! The runner and [`Solution`] machinery open spans for each day, and for
+//! parsing and each part within it. When a span closes at a level we're
+//! printing, we note how long it was open, so `-v` shows timings for each
+//! phase of each day. Each line starts with the time since the program
+//! started:
+//!
+//! ```text
+//!    0.039949183s  INFO day{year=2021 day=12}:part{part=2}: close time.busy=36.4ms time.idle=9.62µs
+//! ```
+//!
+//! Setting the `RUST_LOG` environment variable overrides the verbosity
+//! flags, with the usual syntax: `RUST_LOG=aoc_utils::astar=trace` shows
+//! every node the A* search visits, and nothing else.
+//!
+//! [`runner`]: crate::runner
+//! [`Solution`]: crate::solution::Solution
+//! [`tracing`]: https://docs.rs/tracing
+
+use std::io::IsTerminal;
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::fmt::time::Uptime;
+
+/// Return the most detailed level to print at `verbosity`: warnings and
+/// errors only by default, and then info, debug and trace messages.
+pub fn level(verbosity: u8) -> tracing::Level {
+    match verbosity {
+        0 => tracing::Level::WARN,
+        1 => tracing::Level::INFO,
+        2 => tracing::Level::DEBUG,
+        _ => tracing::Level::TRACE,
+    }
+}
+
+/// Print events and span timings to standard error, as detailed as
+/// `verbosity` asks for, unless `RUST_LOG` says otherwise.
+///
+/// If a subscriber has already been installed, leave it in place.
+pub fn init(verbosity: u8) {
+    let filter = match std::env::var("RUST_LOG") {
+        Ok(directives) if !directives.is_empty() => EnvFilter::new(directives),
+        _ => EnvFilter::new(level(verbosity).as_str()),
+    };
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_target(verbosity >= 2)
+        .with_timer(Uptime::default())
+        .with_ansi(std::io::stderr().is_terminal())
+        .with_writer(std::io::stderr)
+        .try_init();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level(0), tracing::Level::WARN);
+        assert_eq!(level(1), tracing::Level::INFO);
+        assert_eq!(level(3), tracing::Level::TRACE);
+        assert_eq!(level(9), tracing::Level::TRACE);
+    }
+}
@@ -0,0 +1,517 @@
+//! An interactive terminal viewer for grid simulations.
+//!
+//! This module is only available with the `tui` feature.
+//!
+//! A puzzle whose state is a grid that changes step by step can implement
+//! [`Simulation`], and then hand its initial state to [`view`]. The viewer
+//! draws the grid in the terminal, and lets you step forwards and backwards
+//! through the simulation, play it as an animation, and move a cursor around
+//! to inspect individual cells. The viewer keeps a history of past states,
+//! so stepping backwards is instant.
+//!
+//! Keys:
+//!
+//! - `n`, `.` or Enter: step forward
+//! - `b` or `,`: step back
+//! - space: play or pause
+//! - `+` and `-`: play faster or slower
+//! - arrow keys or `h`, `j`, `k`, `l`: move the cursor
+//! - `q` or Escape: quit
+//!
+//! Cells are drawn with the same [`Glyph`]s and [`Color`]s as
+//! [`Pretty`](crate::pretty::Pretty) uses, so a simulation's state can also
+//! be printed with `Pretty::new(state.size(), |p| state.cell(p))`.
+//!
+//! Since the runner times a [`Solution`]'s parsing and parts, the viewer
+//! belongs in a separate binary, like 2018's `day-13-view`, not in those.
+//!
+//! [`Solution`]: crate::solution::Solution
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{self, Attribute, Print, ResetColor, SetAttribute, SetForegroundColor};
+use crossterm::{cursor, execute, queue, terminal};
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::panic;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub use crate::pretty::{Color, Glyph};
+
+/// A grid simulation that the viewer can display and step through.
+///
+/// The viewer keeps past states by cloning them, so `Clone` should be
+/// reasonably cheap.
+pub trait Simulation: Clone {
+    /// Return the dimensions of the grid, as `(rows, columns)`.
+    fn size(&self) -> (usize, usize);
+
+    /// Return how to draw the cell at `(row, column)`.
+    ///
+    /// If an entity (a unit, a cart) occupies the cell, this should show the
+    /// entity rather than the terrain underneath.
+    fn cell(&self, point: (usize, usize)) -> Glyph;
+
+    /// Describe the contents of the cell at `(row, column)` in more detail,
+    /// for the inspection line under the grid.
+    fn describe(&self, point: (usize, usize)) -> String;
+
+    /// Advance the simulation by one step. Return false if the simulation is
+    /// over.
+    ///
+    /// The state after the final step is still shown; `step` just won't be
+    /// called again.
+    fn step(&mut self) -> bool;
+
+    /// Return a summary of the state, for the status line.
+    fn status(&self) -> String {
+        String::new()
+    }
+}
+
+/// Terminal colors for the viewer, matching the ones [`Pretty`] prints.
+///
+/// [`Pretty`]: crate::pretty::Pretty
+impl From<Color> for style::Color {
+    fn from(color: Color) -> style::Color {
+        match color {
+            Color::Black => style::Color::Black,
+            Color::Red => style::Color::DarkRed,
+            Color::Green => style::Color::DarkGreen,
+            Color::Yellow => style::Color::DarkYellow,
+            Color::Blue => style::Color::DarkBlue,
+            Color::Magenta => style::Color::DarkMagenta,
+            Color::Cyan => style::Color::DarkCyan,
+            Color::White => style::Color::Grey,
+            Color::Gray => style::Color::DarkGrey,
+            Color::Rgb(r, g, b) => style::Color::Rgb { r, g, b },
+        }
+    }
+}
+
+/// Run the viewer on `initial`, with the default settings.
+pub fn view<S: Simulation>(initial: S) -> io::Result<()> {
+    Viewer::new(initial).run()
+}
+
+/// The viewer's state: the history of the simulation, and what we're
+/// showing of it.
+pub struct Viewer<S> {
+    /// Recent states of the simulation, oldest first.
+    history: VecDeque<S>,
+
+    /// The most states we'll keep in `history`.
+    capacity: usize,
+
+    /// The step number of `history[0]`.
+    first_step: usize,
+
+    /// The index in `history` of the state we're showing.
+    position: usize,
+
+    /// True if the last state in `history` is the end of the simulation.
+    finished: bool,
+
+    /// True if we're stepping forward automatically.
+    playing: bool,
+
+    /// How long to wait between steps while playing.
+    delay: Duration,
+
+    /// The cell under the cursor, as `(row, column)`.
+    cursor: (usize, usize),
+
+    /// The cell shown in the top left corner of the terminal.
+    scroll: (usize, usize),
+}
+
+impl<S: Simulation> Viewer<S> {
+    /// Return a viewer starting at `initial`.
+    ///
+    /// By default, the viewer remembers 1000 states, and plays ten steps a
+    /// second.
+    pub fn new(initial: S) -> Self {
+        Viewer {
+            history: VecDeque::from([initial]),
+            capacity: 1000,
+            first_step: 0,
+            position: 0,
+            finished: false,
+            playing: false,
+            delay: Duration::from_millis(100),
+            cursor: (0, 0),
+            scroll: (0, 0),
+        }
+    }
+
+    /// Remember up to `capacity` states for stepping backwards.
+    pub fn history(mut self, capacity: usize) -> Self {
+        assert!(capacity > 0);
+        self.capacity = capacity;
+        self
+    }
+
+    /// Wait `delay` between steps while playing.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Return the state being shown.
+    pub fn current(&self) -> &S {
+        &self.history[self.position]
+    }
+
+    /// Return the number of steps taken to reach the state being shown.
+    pub fn step_number(&self) -> usize {
+        self.first_step + self.position
+    }
+
+    /// Show the next state, running the simulation if we haven't seen it
+    /// yet. Return false if there are no more states.
+    pub fn forward(&mut self) -> bool {
+        if self.position + 1 < self.history.len() {
+            self.position += 1;
+            return true;
+        }
+        if self.finished {
+            return false;
+        }
+
+        let mut next = self.current().clone();
+        self.finished = !next.step();
+        self.history.push_back(next);
+        if self.history.len() > self.capacity {
+            self.history.pop_front();
+            self.first_step += 1;
+        }
+        self.position = self.history.len() - 1;
+        true
+    }
+
+    /// Show the previous state. Return false if we don't have it.
+    pub fn back(&mut self) -> bool {
+        if self.position == 0 {
+            return false;
+        }
+        self.position -= 1;
+        true
+    }
+
+    /// Move the cursor by `(rows, columns)`, staying on the grid.
+    pub fn move_cursor(&mut self, (rows, columns): (isize, isize)) {
+        let (height, width) = self.current().size();
+        let clamp = |n: usize, delta: isize, limit: usize| {
+            n.saturating_add_signed(delta).min(limit.saturating_sub(1))
+        };
+        self.cursor = (
+            clamp(self.cursor.0, rows, height),
+            clamp(self.cursor.1, columns, width),
+        );
+    }
+
+    /// Respond to `key`. Return false if the user asked to quit.
+    pub fn handle(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return true;
+        }
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char('n') | KeyCode::Char('.') | KeyCode::Enter => {
+                self.playing = false;
+                self.forward();
+            }
+            KeyCode::Char('b') | KeyCode::Char(',') | KeyCode::Backspace => {
+                self.playing = false;
+                self.back();
+            }
+            KeyCode::Char(' ') => self.playing = !self.playing,
+            KeyCode::Char('+') | KeyCode::Char('=') => self.delay /= 2,
+            KeyCode::Char('-') => self.delay = (self.delay * 2).min(Duration::from_secs(5)),
+            KeyCode::Up | KeyCode::Char('k') => self.move_cursor((-1, 0)),
+            KeyCode::Down | KeyCode::Char('j') => self.move_cursor((1, 0)),
+            KeyCode::Left | KeyCode::Char('h') => self.move_cursor((0, -1)),
+            KeyCode::Right | KeyCode::Char('l') => self.move_cursor((0, 1)),
+            _ => {}
+        }
+        true
+    }
+
+    /// Take over the terminal and let the user explore the simulation until
+    /// they quit.
+    ///
+    /// The terminal is put back the way it was however this returns, even if
+    /// the simulation panics.
+    pub fn run(mut self) -> io::Result<()> {
+        let _terminal = Terminal::enter()?;
+        self.event_loop(&mut io::stdout())
+    }
+
+    fn event_loop(&mut self, out: &mut impl Write) -> io::Result<()> {
+        loop {
+            let (width, height) = terminal::size()?;
+            self.draw(out, width as usize, height as usize)?;
+
+            if self.playing && !event::poll(self.delay)? {
+                if !self.forward() {
+                    self.playing = false;
+                }
+                continue;
+            }
+            if let Event::Key(key) = event::read()?
+                && !self.handle(key)
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Scroll so that the cursor is visible in a view `rows` by `columns`
+    /// cells in size.
+    fn scroll_to_cursor(&mut self, rows: usize, columns: usize) {
+        let scroll = |scroll: usize, cursor: usize, visible: usize| {
+            if cursor < scroll {
+                cursor
+            } else if cursor >= scroll + visible {
+                cursor + 1 - visible
+            } else {
+                scroll
+            }
+        };
+        self.scroll = (
+            scroll(self.scroll.0, self.cursor.0, rows.max(1)),
+            scroll(self.scroll.1, self.cursor.1, columns.max(1)),
+        );
+    }
+
+    fn draw(&mut self, out: &mut impl Write, width: usize, height: usize) -> io::Result<()> {
+        // Leave three lines at the bottom for the status, inspection and help.
+        let grid_rows = height.saturating_sub(3);
+        self.scroll_to_cursor(grid_rows, width);
+
+        let state = self.current();
+        let (rows, columns) = state.size();
+        for line in 0..grid_rows {
+            queue!(out, cursor::MoveTo(0, line as u16))?;
+            let row = self.scroll.0 + line;
+            if row < rows {
+                let mut color = None;
+                for column in self.scroll.1..columns.min(self.scroll.1 + width) {
+                    let cell = state.cell((row, column));
+                    if cell.color != color {
+                        match cell.color {
+                            Some(c) => queue!(out, SetForegroundColor(c.into()))?,
+                            None => queue!(out, ResetColor)?,
+                        }
+                        color = cell.color;
+                    }
+                    if (row, column) == self.cursor {
+                        queue!(
+                            out,
+                            SetAttribute(Attribute::Reverse),
+                            Print(cell.ch),
+                            SetAttribute(Attribute::NoReverse)
+                        )?;
+                    } else {
+                        queue!(out, Print(cell.ch))?;
+                    }
+                }
+                queue!(out, ResetColor)?;
+            }
+            queue!(out, terminal::Clear(terminal::ClearType::UntilNewLine))?;
+        }
+
+        let mode = if self.playing {
+            format!("playing, {}ms/step", self.delay.as_millis())
+        } else if self.finished && self.position + 1 == self.history.len() {
+            "finished".to_string()
+        } else {
+            "paused".to_string()
+        };
+        let lines = [
+            format!("step {} ({mode})  {}", self.step_number(), state.status()),
+            format!(
+                "({}, {}): {}",
+                self.cursor.0,
+                self.cursor.1,
+                state.describe(self.cursor)
+            ),
+            "n: step  b: back  space: play/pause  +/-: speed  arrows: move  q: quit".to_string(),
+        ];
+        for (i, text) in lines.iter().enumerate() {
+            let text: String = text.chars().take(width).collect();
+            queue!(
+                out,
+                cursor::MoveTo(0, (grid_rows + i) as u16),
+                Print(text),
+                terminal::Clear(terminal::ClearType::UntilNewLine)
+            )?;
+        }
+        out.flush()
+    }
+}
+
+type PanicHook = Box<dyn Fn(&panic::PanicHookInfo<'_>) + Send + Sync + 'static>;
+
+/// The terminal in raw mode, showing the alternate screen. Dropping this
+/// restores it.
+struct Terminal {
+    /// The panic hook that was in place before ours.
+    previous_hook: Option<Arc<PanicHook>>,
+}
+
+impl Terminal {
+    fn enter() -> io::Result<Terminal> {
+        // A panic message printed in raw mode on the alternate screen would
+        // be garbled and then vanish, so restore the terminal first.
+        let previous_hook = Arc::new(panic::take_hook());
+        let hook = previous_hook.clone();
+        panic::set_hook(Box::new(move |info| {
+            restore();
+            hook(info);
+        }));
+        let terminal = Terminal {
+            previous_hook: Some(previous_hook),
+        };
+
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(terminal)
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        restore();
+
+        // We can't change hooks while panicking, but then ours has already
+        // run, and the process is probably on its way out anyway.
+        if !std::thread::panicking()
+            && let Some(previous) = self.previous_hook.take()
+        {
+            drop(panic::take_hook());
+            if let Ok(previous) = Arc::try_unwrap(previous) {
+                panic::set_hook(previous);
+            }
+        }
+    }
+}
+
+/// Put the terminal back in its usual state, as best we can.
+fn restore() {
+    let _ = execute!(
+        io::stdout(),
+        ResetColor,
+        cursor::Show,
+        terminal::LeaveAlternateScreen
+    );
+    let _ = terminal::disable_raw_mode();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A counter that runs from zero to three, drawn as a row of digits.
+    #[derive(Clone)]
+    struct Counter(usize);
+
+    impl Simulation for Counter {
+        fn size(&self) -> (usize, usize) {
+            (2, 5)
+        }
+
+        fn cell(&self, (_, column): (usize, usize)) -> Glyph {
+            if column == self.0 { '*' } else { '.' }.into()
+        }
+
+        fn describe(&self, point: (usize, usize)) -> String {
+            format!("{:?}", self.cell(point))
+        }
+
+        fn step(&mut self) -> bool {
+            self.0 += 1;
+            self.0 < 3
+        }
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_history() {
+        let mut viewer = Viewer::new(Counter(0)).history(2);
+        assert!(!viewer.back());
+        assert!(viewer.forward());
+        assert!(viewer.forward());
+        assert_eq!(viewer.current().0, 2);
+        assert_eq!(viewer.step_number(), 2);
+
+        // We only remember two states.
+        assert!(viewer.back());
+        assert_eq!(viewer.step_number(), 1);
+        assert!(!viewer.back());
+
+        // Replaying from history doesn't run the simulation again.
+        assert!(viewer.forward());
+        assert_eq!(viewer.current().0, 2);
+
+        // Step 3 is the last.
+        assert!(viewer.forward());
+        assert_eq!(viewer.current().0, 3);
+        assert!(!viewer.forward());
+        assert_eq!(viewer.step_number(), 3);
+    }
+
+    #[test]
+    fn test_keys() {
+        let mut viewer = Viewer::new(Counter(0));
+        assert!(viewer.handle(key(KeyCode::Char('n'))));
+        assert!(viewer.handle(key(KeyCode::Char('n'))));
+        assert!(viewer.handle(key(KeyCode::Char('b'))));
+        assert_eq!(viewer.step_number(), 1);
+
+        assert!(viewer.handle(key(KeyCode::Char(' '))));
+        assert!(viewer.playing);
+        assert!(viewer.handle(key(KeyCode::Char('n'))));
+        assert!(!viewer.playing);
+
+        for _ in 0..10 {
+            viewer.handle(key(KeyCode::Right));
+        }
+        viewer.handle(key(KeyCode::Down));
+        viewer.handle(key(KeyCode::Char('h')));
+        assert_eq!(viewer.cursor, (1, 3));
+        assert_eq!(
+            viewer.current().describe(viewer.cursor),
+            r#"Glyph { ch: '.', color: None }"#
+        );
+
+        assert!(!viewer.handle(key(KeyCode::Char('q'))));
+    }
+
+    #[test]
+    fn test_scroll() {
+        let mut viewer = Viewer::new(Counter(0));
+        viewer.move_cursor((1, 4));
+        viewer.scroll_to_cursor(1, 2);
+        assert_eq!(viewer.scroll, (1, 3));
+        viewer.move_cursor((-1, -2));
+        viewer.scroll_to_cursor(1, 2);
+        assert_eq!(viewer.scroll, (0, 2));
+    }
+
+    #[test]
+    fn test_pretty() {
+        let state = Counter(2);
+        let pretty = crate::pretty::Pretty::new(state.size(), |p| state.cell(p)).color(false);
+        assert_eq!(pretty.to_string(), "..*..\n..*..\n");
+        assert_eq!(
+            style::Color::from(Color::Rgb(1, 2, 3)),
+            style::Color::Rgb { r: 1, g: 2, b: 3 }
+        );
+        assert_eq!(style::Color::from(Color::Gray), style::Color::DarkGrey);
+    }
+}
@@ -0,0 +1,28 @@
+//! Run any registered puzzle solution from any year.
+//!
+//! See `aoc_utils::runner` for the command-line syntax.
+
+use aoc_utils::solution::Day;
+use std::process::ExitCode;
+
+#[global_allocator]
+static ALLOC: aoc_utils::alloc::Counting = aoc_utils::alloc::Counting;
+
+fn solutions() -> Vec<Day> {
+    let mut days = vec![];
+    days.extend(advent_of_code_2018::solutions());
+    days.extend(aoc2021::solutions());
+    days.push(aoc2025_day3::day());
+    days.push(day4::day());
+    days.push(day5::day());
+    days.push(day6::day());
+    days.push(day7::day());
+    days.push(day8::day());
+    days.push(day9::day());
+    days.push(day10::day());
+    days
+}
+
+fn main() -> ExitCode {
+    aoc_utils::runner::main(&solutions())
+}